@@ -14,7 +14,7 @@ pub fn main() {
         let state = game_generator().generate();
         let mut game = Game { state, player };
 
-        let first_exit_id = match game.state.current_room_exits().get(0) {
+        let first_exit_id = match game.state.current_room_exits().first() {
             Some(it) => *it,
             None => return,
         };
@@ -24,7 +24,7 @@ pub fn main() {
         };
 
         game.handle_action(&Action::ExitRoom(exit_room)).unwrap();
-        let npc_id = match game.state.current_room().npc_positions.get(0) {
+        let npc_id = match game.state.current_room().npc_positions.first() {
             Some(it) => it.npc.id,
             None => return,
         };
@@ -53,6 +53,6 @@ pub fn main() {
         };
 
         let serialized_new_game = serde_json::to_string(&serialized_game).unwrap();
-        println!("{}", &serialized_new_game);
+        println!("{}", serialized_new_game);
     }
 }
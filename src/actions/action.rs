@@ -14,9 +14,14 @@ pub enum Action {
     AttackNpc(super::AttackNpc),
     CastSpellOnNpc(super::CastSpellOnNpc),
     CastSpellOnPlayer(super::CastSpellOnPlayer),
+    ChoosePerk(super::ChoosePerk),
+    CombineItems(super::CombineItems),
+    DisarmTrap(super::DisarmTrap),
     ExitRoom(super::ExitRoom),
+    IdentifyItem(super::IdentifyItem),
     InspectFixture(super::InspectFixture),
     InspectNpc(super::InspectNpc),
+    InteractWithFixture(super::InteractWithFixture),
     LookAtFixture(super::LookAtFixture),
     LookAtNpc(super::LookAtNpc),
     LootFixture(super::LootFixture),
@@ -24,7 +29,13 @@ pub enum Action {
     MovePlayerItem(super::MovePlayerItem),
     OpenFixture(super::OpenFixture),
     OpenFixtureHiddenCompartment(super::OpenFixtureHiddenCompartment),
+    PickLock(super::PickLock),
+    RepairPlayerItem(super::RepairPlayerItem),
+    Rest(super::Rest),
+    SearchRoom(super::SearchRoom),
     SellPlayerItem(super::SellPlayerItem),
     UseItemOnPlayer(super::UseItemOnPlayer),
     ThrowItemAtNpc(super::ThrowItemAtNpc),
+    TravelToRoom(super::TravelToRoom),
+    UnlockWithKey(super::UnlockWithKey),
 }
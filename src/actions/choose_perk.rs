@@ -0,0 +1,21 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Object;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+use crate::components::Perk;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object))]
+/// Choose one of the perks offered by the oldest pending level up.
+pub struct ChoosePerk {
+    pub perk: Perk,
+}
@@ -0,0 +1,16 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Object;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+/// Combine items from the player's inventory using one of the known recipes.
+/// Every item given is used up in making the new one.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct CombineItems {
+    pub item_ids: Vec<String>,
+}
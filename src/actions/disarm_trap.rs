@@ -0,0 +1,23 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Object;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+/// Try to disarm a trap that's known to be on a fixture or an exit in the
+/// current room. A clumsy attempt springs the trap.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct DisarmTrap {
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub fixture_id: Option<String>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub exit_id: Option<String>,
+}
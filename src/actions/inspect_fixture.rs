@@ -19,4 +19,7 @@ pub struct InspectFixture {
     pub fixture_id: String,
     /// Attempt to discover any hidden compartments and its contents.
     pub discover_hidden_compartment: bool,
+    /// Attempt to discover whether the fixture is trapped.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub discover_trap: bool,
 }
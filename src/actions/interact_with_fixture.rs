@@ -0,0 +1,22 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Object;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+use crate::components::rooms::FixtureInteraction;
+
+/// Rotate, push or activate a fixture that's part of the room's puzzle.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct InteractWithFixture {
+    pub fixture_id: String,
+    pub interaction: FixtureInteraction,
+}
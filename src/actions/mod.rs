@@ -2,9 +2,14 @@ pub mod action;
 pub mod attack_npc;
 pub mod cast_spell_on_npc;
 pub mod cast_spell_on_player;
+pub mod choose_perk;
+pub mod combine_items;
+pub mod disarm_trap;
 pub mod exit_room;
+pub mod identify_item;
 pub mod inspect_fixture;
 pub mod inspect_npc;
+pub mod interact_with_fixture;
 pub mod look_at_fixture;
 pub mod look_at_npc;
 pub mod loot_fixture;
@@ -12,16 +17,27 @@ pub mod loot_npc;
 pub mod move_player_item;
 pub mod open_fixture;
 pub mod open_fixture_hidden_compartment;
+pub mod pick_lock;
+pub mod repair_player_item;
+pub mod rest;
+pub mod search_room;
 pub mod sell_player_item;
 mod throw_item_at_npc;
+pub mod travel_to_room;
+pub mod unlock_with_key;
 pub mod use_item_on_player;
 
 pub use {
     action::Action, attack_npc::AttackNpc, cast_spell_on_npc::CastSpellOnNpc,
-    cast_spell_on_player::CastSpellOnPlayer, exit_room::ExitRoom, inspect_fixture::InspectFixture,
-    inspect_npc::InspectNpc, look_at_fixture::LookAtFixture, look_at_npc::LookAtNpc,
-    loot_fixture::LootFixture, loot_npc::LootNpc, move_player_item::MovePlayerItem,
-    open_fixture::OpenFixture, open_fixture_hidden_compartment::OpenFixtureHiddenCompartment,
+    cast_spell_on_player::CastSpellOnPlayer, choose_perk::ChoosePerk, combine_items::CombineItems,
+    disarm_trap::DisarmTrap, exit_room::ExitRoom, identify_item::IdentifyItem,
+    inspect_fixture::InspectFixture, inspect_npc::InspectNpc,
+    interact_with_fixture::InteractWithFixture, look_at_fixture::LookAtFixture,
+    look_at_npc::LookAtNpc, loot_fixture::LootFixture, loot_npc::LootNpc,
+    move_player_item::MovePlayerItem, open_fixture::OpenFixture,
+    open_fixture_hidden_compartment::OpenFixtureHiddenCompartment, pick_lock::PickLock,
+    repair_player_item::RepairPlayerItem, rest::Rest, search_room::SearchRoom,
     sell_player_item::SellPlayerItem, throw_item_at_npc::ThrowItemAtNpc,
+    travel_to_room::TravelToRoom, unlock_with_key::UnlockWithKey,
     use_item_on_player::UseItemOnPlayer,
 };
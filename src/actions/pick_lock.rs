@@ -0,0 +1,19 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Object;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+/// Try to pick the lock on a fixture or an exit in the current room. A failed
+/// attempt makes enough noise to draw an attack from anyone nearby.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct PickLock {
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub fixture_id: Option<String>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub exit_id: Option<String>,
+}
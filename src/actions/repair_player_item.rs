@@ -0,0 +1,21 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Object;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+/// Repair one of the player's items, either at a fixture in the current room
+/// or with a repair kit the player is carrying. Fixtures restore the item fully,
+/// kits restore a set amount per use.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct RepairPlayerItem {
+    pub item_id: String,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub fixture_id: Option<String>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub repair_kit_id: Option<String>,
+}
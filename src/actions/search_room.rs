@@ -0,0 +1,22 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Object;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+/// Search the walls and floor of the current room for any hidden exits.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct SearchRoom {
+    /// Take the time to search every corner, more likely to find something
+    /// but anyone in the room gets a free attack.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub thorough: bool,
+}
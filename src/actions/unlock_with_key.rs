@@ -0,0 +1,20 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Object;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+/// Unlock a fixture or an exit in the current room with a key the player
+/// is carrying.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct UnlockWithKey {
+    pub key_id: String,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub fixture_id: Option<String>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub exit_id: Option<String>,
+}
@@ -0,0 +1,96 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::{Enum, Object};
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+/// Scores run from 1 to 6, with 3 being an unremarkable member of any species.
+const AVERAGE_SCORE: i32 = 3;
+const MAX_SCORE: i32 = 6;
+const DODGE_CHANCE_PER_AGILITY: i32 = 5;
+const CARRYING_CAPACITY_PER_STRENGTH: i32 = 5;
+
+#[derive(Clone, Debug, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum Attribute {
+    Strength,
+    Agility,
+    Wits,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "openapi", derive(Object), oai(rename = "Attributes"))]
+pub struct Attributes {
+    pub strength: i32,
+    pub agility: i32,
+    pub wits: i32,
+}
+
+impl Default for Attributes {
+    fn default() -> Self {
+        Self {
+            strength: AVERAGE_SCORE,
+            agility: AVERAGE_SCORE,
+            wits: AVERAGE_SCORE,
+        }
+    }
+}
+
+impl Attributes {
+    pub fn score(&self, attribute: &Attribute) -> i32 {
+        match *attribute {
+            Attribute::Strength => self.strength,
+            Attribute::Agility => self.agility,
+            Attribute::Wits => self.wits,
+        }
+    }
+
+    pub fn can_increase(&self, attribute: &Attribute) -> bool {
+        self.score(attribute) < MAX_SCORE
+    }
+
+    pub fn increase(&mut self, attribute: &Attribute) {
+        let score = match *attribute {
+            Attribute::Strength => &mut self.strength,
+            Attribute::Agility => &mut self.agility,
+            Attribute::Wits => &mut self.wits,
+        };
+        *score = (*score + 1).min(MAX_SCORE);
+    }
+
+    /// Added to the damage of every weapon attack.
+    pub fn strength_modifier(&self) -> i32 {
+        self.strength - AVERAGE_SCORE
+    }
+
+    /// Added to inspection and searching rolls.
+    pub fn wits_modifier(&self) -> i32 {
+        self.wits - AVERAGE_SCORE
+    }
+
+    /// Added to lock picking rolls.
+    pub fn agility_modifier(&self) -> i32 {
+        self.agility - AVERAGE_SCORE
+    }
+
+    /// Extra weight that can be carried on top of what the character's size allows.
+    pub fn carrying_capacity_bonus(&self) -> u32 {
+        (self.strength.max(0) * CARRYING_CAPACITY_PER_STRENGTH) as u32
+    }
+
+    /// Extra percent chance to dodge on top of whatever the character already
+    /// has. Only above average agility helps.
+    pub fn dodge_chance(&self) -> i32 {
+        self.agility_modifier().max(0) * DODGE_CHANCE_PER_AGILITY
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Enum;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+use super::{items::ItemType, worlds::FINAL_DEPTH, Species};
+
+/// The unique NPCs that guard the way down, each only ever shows up once.
+#[derive(Clone, Debug, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum BossKind {
+    GrimjawTheGlutton,
+    TheDrownedMatron,
+    TheHollowKing,
+}
+
+impl BossKind {
+    pub fn title(&self) -> &'static str {
+        match *self {
+            BossKind::GrimjawTheGlutton => "Grimjaw the Glutton",
+            BossKind::TheDrownedMatron => "The Drowned Matron",
+            BossKind::TheHollowKing => "The Hollow King",
+        }
+    }
+
+    pub fn lair_name(&self) -> &'static str {
+        match *self {
+            BossKind::GrimjawTheGlutton => "Grimjaw's Larder",
+            BossKind::TheDrownedMatron => "The Flooded Nursery",
+            BossKind::TheHollowKing => "The Hollow Throne",
+        }
+    }
+
+    pub fn species(&self) -> Species {
+        match *self {
+            BossKind::GrimjawTheGlutton => Species::Ogre,
+            BossKind::TheDrownedMatron => Species::Frogkin,
+            BossKind::TheHollowKing => Species::Phantom,
+        }
+    }
+
+    /// The boss always carries one of these, far better than anything else
+    /// found on their floor.
+    pub fn loot_type(&self) -> ItemType {
+        match *self {
+            BossKind::GrimjawTheGlutton => ItemType::Morningstar,
+            BossKind::TheDrownedMatron => ItemType::Cloak,
+            BossKind::TheHollowKing => ItemType::GreatSword,
+        }
+    }
+
+    /// The shallowest floor the boss can be found on.
+    pub fn depth(&self) -> u32 {
+        match *self {
+            BossKind::GrimjawTheGlutton => 3,
+            BossKind::TheDrownedMatron => 6,
+            BossKind::TheHollowKing => FINAL_DEPTH,
+        }
+    }
+
+    /// Beating the final boss is the end of the run.
+    pub fn is_final(&self) -> bool {
+        matches!(*self, BossKind::TheHollowKing)
+    }
+}
+
+#[derive(Clone, Debug, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum BossPhase {
+    /// Fights like anyone else would.
+    Wary,
+    /// Below half health the boss stops holding back and attacks every turn.
+    Enraged,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct Boss {
+    pub kind: BossKind,
+    pub phase: BossPhase,
+}
+
+impl Boss {
+    pub fn is_enraged(&self) -> bool {
+        matches!(self.phase, BossPhase::Enraged)
+    }
+}
@@ -7,14 +7,18 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use super::{
-    damage::AttackEffect,
-    items::CharacterItem,
+    damage::{AttackEffect, DamageModifier, DamageType, Resistance, ResistanceSource},
+    items::{CharacterItem, Curse, Enchantment},
     spells::{
         LearnedSpell, {SpellMemory, SpellMemoryView},
     },
     LifeModifier, Species, {Effects, EffectsView}, {Inventory, InventoryView}, {Stats, StatsView},
 };
 
+/// Characters can push past their carrying capacity by half again, but are
+/// encumbered while they do.
+const MAX_CARRIED_WEIGHT_PERCENT: u32 = 150;
+
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
@@ -40,6 +44,10 @@ impl Character {
         self.inventory.find_item(item_id)
     }
 
+    pub fn find_item_mut(&mut self, item_id: &Uuid) -> Option<&mut CharacterItem> {
+        self.inventory.find_item_mut(item_id)
+    }
+
     pub fn find_spell(&self, spell_id: &Uuid) -> Option<&LearnedSpell> {
         self.spell_memory.find_spell(spell_id)
     }
@@ -102,6 +110,26 @@ impl Character {
         self.stats.health.current = 0;
     }
 
+    pub fn carried_weight(&self) -> u32 {
+        self.inventory.weight()
+    }
+
+    pub fn carrying_capacity(&self) -> u32 {
+        self.stats.height.carrying_capacity() + self.stats.attributes.carrying_capacity_bonus()
+    }
+
+    pub fn max_carried_weight(&self) -> u32 {
+        self.carrying_capacity() * MAX_CARRIED_WEIGHT_PERCENT / 100
+    }
+
+    pub fn is_encumbered(&self) -> bool {
+        self.carried_weight() > self.carrying_capacity()
+    }
+
+    pub fn can_carry(&self, additional_weight: u32) -> bool {
+        self.carried_weight() + additional_weight <= self.max_carried_weight()
+    }
+
     pub fn no_weapons_readied(&self) -> bool {
         self.inventory.readied_weapons().is_empty()
     }
@@ -122,22 +150,25 @@ impl Character {
         self.inventory.strongest_non_readied_weapon()
     }
 
+    /// Rolls every readied weapon and adds the character's strength.
     pub fn attack(&self) -> i32 {
         let mut rng = rand::thread_rng();
 
-        self.inventory
+        let weapon_attack: i32 = self
+            .inventory
             .equipment
             .iter()
             .filter(|character_item| character_item.at_the_ready)
             .map(|character_item| {
                 character_item
                     .item
-                    .attack
-                    .as_ref()
+                    .usable_attack()
                     .map(|attack| attack.attack_roll(&mut rng))
                     .unwrap_or_default()
             })
-            .sum()
+            .sum();
+
+        weapon_attack + self.stats.attributes.strength_modifier()
     }
 
     pub fn attack_effects(&self) -> Vec<AttackEffect> {
@@ -145,17 +176,91 @@ impl Character {
             .equipment
             .iter()
             .filter(|character_item| character_item.is_at_the_ready())
-            .flat_map(|character_item| {
-                character_item
-                    .item
-                    .attack
-                    .as_ref()
-                    .map(|attack| attack.effects.clone())
-                    .unwrap_or_default()
+            .flat_map(|character_item| character_item.item.attack_effects())
+            .collect()
+    }
+
+    /// Whether any readied, unbroken weapon carries the enchantment.
+    pub fn has_readied_enchantment(&self, enchantment: &Enchantment) -> bool {
+        self.inventory
+            .readied_weapons()
+            .iter()
+            .any(|character_item| {
+                !character_item.item.is_broken() && character_item.item.has_enchantment(enchantment)
             })
+    }
+
+    /// The readied items that carry the curse.
+    pub fn readied_items_with_curse(&self, curse: &Curse) -> Vec<CharacterItem> {
+        self.inventory
+            .equipment
+            .iter()
+            .filter(|character_item| {
+                character_item.is_at_the_ready() && character_item.item.has_curse(curse)
+            })
+            .cloned()
             .collect()
     }
 
+    /// The type of damage dealt by the first readied weapon, or blunt for bare hands.
+    pub fn damage_type(&self) -> DamageType {
+        self.inventory
+            .readied_weapons()
+            .iter()
+            .find_map(|character_item| character_item.item.damage_type())
+            .unwrap_or_default()
+    }
+
+    /// Every resistance and vulnerability the character has to a damage type,
+    /// along with where it comes from.
+    pub fn damage_modifiers(&self, damage_type: &DamageType) -> Vec<DamageModifier> {
+        let mut modifiers: Vec<DamageModifier> = Vec::new();
+
+        if let Some(resistance) = self.species.resistance_to(damage_type) {
+            modifiers.push(DamageModifier {
+                source: ResistanceSource::Species,
+                resistance,
+            });
+        }
+
+        if let Some(resistance) = self
+            .life_modifier
+            .as_ref()
+            .and_then(|life_modifier| life_modifier.resistance_to(damage_type))
+        {
+            modifiers.push(DamageModifier {
+                source: ResistanceSource::LifeModifier,
+                resistance,
+            });
+        }
+
+        for resistance in self
+            .inventory
+            .equipped_wearables()
+            .iter()
+            .filter(|character_item| !character_item.item.is_broken())
+            .filter_map(|character_item| character_item.item.material.as_ref())
+            .filter_map(|material| material.resistance_to(damage_type))
+        {
+            let modifier = DamageModifier {
+                source: ResistanceSource::Armour,
+                resistance,
+            };
+            if !modifiers.contains(&modifier) {
+                modifiers.push(modifier);
+            }
+        }
+
+        if self.current_effects.covered_in_oil && damage_type == &DamageType::Fire {
+            modifiers.push(DamageModifier {
+                source: ResistanceSource::Effect,
+                resistance: Resistance::Vulnerable,
+            });
+        }
+
+        modifiers
+    }
+
     pub fn defense(&self) -> i32 {
         self.inventory
             .equipment
@@ -163,8 +268,7 @@ impl Character {
             .map(|character_item| {
                 character_item
                     .item
-                    .defense
-                    .as_ref()
+                    .usable_defense()
                     .map(|defense| defense.damage_resistance)
                     .unwrap_or_default()
             })
@@ -10,17 +10,71 @@ use serde::{Deserialize, Serialize};
 
 use strum_macros::EnumIter;
 
-#[derive(Clone, Debug)]
+use super::Dice;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
-#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(from = "SavedAttack")
+)]
 #[cfg_attr(feature = "openapi", derive(Object))]
 pub struct Attack {
-    pub num_rolls: usize,
-    pub modifier: i32,
+    pub dice: Dice,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub damage_type: DamageType,
     #[cfg_attr(feature = "serialization", serde(default))]
     pub effects: Vec<AttackEffect>,
 }
 
+/// Attacks used to be saved as a number of d6 rolls and a modifier, so that
+/// shape is still read alongside the dice expression.
+#[cfg(feature = "serialization")]
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SavedAttack {
+    Dice {
+        dice: Dice,
+        #[serde(default)]
+        damage_type: DamageType,
+        #[serde(default)]
+        effects: Vec<AttackEffect>,
+    },
+    Rolls {
+        num_rolls: usize,
+        modifier: i32,
+        #[serde(default)]
+        effects: Vec<AttackEffect>,
+    },
+}
+
+#[cfg(feature = "serialization")]
+impl From<SavedAttack> for Attack {
+    fn from(attack: SavedAttack) -> Self {
+        match attack {
+            SavedAttack::Dice {
+                dice,
+                damage_type,
+                effects,
+            } => Self {
+                dice,
+                damage_type,
+                effects,
+            },
+            SavedAttack::Rolls {
+                num_rolls,
+                modifier,
+                effects,
+            } => Self {
+                dice: Dice::d6(num_rolls, modifier),
+                damage_type: DamageType::default(),
+                effects,
+            },
+        }
+    }
+}
+
 #[derive(Clone, Debug, EnumIter, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(
@@ -38,7 +92,7 @@ pub enum AttackEffect {
 
 impl Attack {
     pub fn attack_roll(&self, rng: &mut ThreadRng) -> i32 {
-        let roll = crate::utils::rolls::roll_d6(rng, self.num_rolls, self.modifier);
+        let roll = self.dice.roll(rng);
         if self
             .effects
             .iter()
@@ -51,7 +105,7 @@ impl Attack {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
 #[cfg_attr(feature = "openapi", derive(Object))]
@@ -67,3 +121,114 @@ pub struct Health {
     pub current: i32,
     pub max: i32,
 }
+
+#[derive(Clone, Debug, Default, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum DamageType {
+    #[default]
+    Blunt,
+    Piercing,
+    Slashing,
+    Acid,
+    Fire,
+    Lightning,
+    Poison,
+}
+
+impl DamageType {
+    /// Only physical damage is soaked up by armour.
+    pub fn is_physical(&self) -> bool {
+        matches!(
+            *self,
+            DamageType::Blunt | DamageType::Piercing | DamageType::Slashing
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum Resistance {
+    Immune,
+    Resistant,
+    Vulnerable,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum ResistanceSource {
+    Armour,
+    Effect,
+    LifeModifier,
+    Species,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct DamageModifier {
+    pub source: ResistanceSource,
+    pub resistance: Resistance,
+}
+
+/// A step by step account of how a hit turned into the damage that was dealt.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct DamageCalculation {
+    pub damage_type: DamageType,
+    pub base_damage: i32,
+    pub armour: i32,
+    pub is_critical: bool,
+    pub modifiers: Vec<DamageModifier>,
+    pub final_damage: i32,
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use super::{Attack, AttackEffect, DamageType};
+    use crate::components::Dice;
+
+    #[test]
+    fn attacks_saved_as_d6_rolls_still_load() {
+        let attack: Attack =
+            serde_json::from_str(r#"{"num_rolls": 2, "modifier": 1, "effects": ["sharp"]}"#)
+                .unwrap();
+
+        assert_eq!(attack.dice, Dice::d6(2, 1));
+        assert_eq!(attack.damage_type, DamageType::default());
+        assert_eq!(attack.effects, vec![AttackEffect::Sharp]);
+    }
+
+    #[test]
+    fn attacks_round_trip_as_dice_expressions() {
+        let attack = Attack {
+            dice: Dice::new(1, 8, -1),
+            damage_type: DamageType::default(),
+            effects: Vec::new(),
+        };
+        let json = serde_json::to_string(&attack).unwrap();
+
+        assert!(json.contains(r#""dice":"1d8-1""#));
+        assert_eq!(serde_json::from_str::<Attack>(&json).unwrap(), attack);
+    }
+}
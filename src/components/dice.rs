@@ -0,0 +1,273 @@
+use std::{fmt::Display, str::FromStr};
+
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseResult, ToJSON, Type},
+    Object,
+};
+use rand::{prelude::ThreadRng, Rng};
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+use crate::errors::Error;
+
+const MAX_NUM_DICE: usize = 100;
+const MAX_SIDES: u32 = 1000;
+const MAX_MODIFIER: i32 = 1000;
+
+/// A dice expression such as `2d6+1`, `1d20` or `3d4-2`.
+/// Serializes to and from that same expression, and shows up as a string in
+/// the OpenAPI schema.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(try_from = "SavedDice", into = "String")
+)]
+pub struct Dice {
+    pub num_dice: usize,
+    pub sides: u32,
+    pub modifier: i32,
+}
+
+/// Dice used to be saved as a number of d6 rolls and a modifier, so that
+/// shape is still read alongside the expression.
+#[cfg(feature = "serialization")]
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SavedDice {
+    Expression(String),
+    Rolls { num_rolls: usize, modifier: i32 },
+}
+
+/// The spread of a roll, so views can show what to expect from it.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct DiceRange {
+    pub min: i32,
+    pub max: i32,
+    pub mean: f32,
+}
+
+impl Dice {
+    pub const fn new(num_dice: usize, sides: u32, modifier: i32) -> Self {
+        Self {
+            num_dice,
+            sides,
+            modifier,
+        }
+    }
+
+    pub fn d6(num_dice: usize, modifier: i32) -> Self {
+        Self::new(num_dice, 6, modifier)
+    }
+
+    /// Rolls every die and adds the modifier, never going below 0.
+    pub fn roll(&self, rng: &mut ThreadRng) -> i32 {
+        let roll: i32 = (0..self.num_dice)
+            .map(|_| rng.gen_range(1..=self.sides.max(1)) as i32)
+            .sum();
+        0.max(roll + self.modifier)
+    }
+
+    pub fn min(&self) -> i32 {
+        0.max(self.num_dice_i32().saturating_add(self.modifier))
+    }
+
+    pub fn max(&self) -> i32 {
+        let sides = i32::try_from(self.sides).unwrap_or(i32::MAX);
+        0.max(
+            self.num_dice_i32()
+                .saturating_mul(sides)
+                .saturating_add(self.modifier),
+        )
+    }
+
+    /// Like rolls, the mean never goes below 0.
+    pub fn mean(&self) -> f32 {
+        let mean = self.num_dice as f32 * (self.sides as f32 + 1.0) / 2.0 + self.modifier as f32;
+        mean.max(0.0)
+    }
+
+    pub fn range(&self) -> DiceRange {
+        DiceRange {
+            min: self.min(),
+            max: self.max(),
+            mean: self.mean(),
+        }
+    }
+
+    fn num_dice_i32(&self) -> i32 {
+        i32::try_from(self.num_dice).unwrap_or(i32::MAX)
+    }
+}
+
+impl Display for Dice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}d{}", self.num_dice, self.sides)?;
+        if self.modifier != 0 {
+            write!(f, "{:+}", self.modifier)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Dice {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidDiceError(s.to_string());
+        let expression: String = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_lowercase();
+
+        let (num_dice, rest) = expression.split_once('d').ok_or_else(invalid)?;
+        let num_dice = if num_dice.is_empty() {
+            1
+        } else {
+            num_dice.parse::<usize>().map_err(|_| invalid())?
+        };
+
+        let (sides, modifier) = match rest.find(['+', '-']) {
+            Some(index) => {
+                let (sides, modifier) = rest.split_at(index);
+                (sides, modifier.parse::<i32>().map_err(|_| invalid())?)
+            }
+            None => (rest, 0),
+        };
+        let sides = sides.parse::<u32>().map_err(|_| invalid())?;
+
+        if sides == 0
+            || sides > MAX_SIDES
+            || num_dice > MAX_NUM_DICE
+            || modifier.abs() > MAX_MODIFIER
+        {
+            return Err(invalid());
+        }
+
+        Ok(Self::new(num_dice, sides, modifier))
+    }
+}
+
+impl TryFrom<String> for Dice {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[cfg(feature = "serialization")]
+impl TryFrom<SavedDice> for Dice {
+    type Error = Error;
+
+    fn try_from(value: SavedDice) -> Result<Self, Self::Error> {
+        match value {
+            SavedDice::Expression(expression) => expression.parse(),
+            SavedDice::Rolls {
+                num_rolls,
+                modifier,
+            } => Ok(Self::d6(num_rolls, modifier)),
+        }
+    }
+}
+
+impl From<Dice> for String {
+    fn from(dice: Dice) -> Self {
+        dice.to_string()
+    }
+}
+
+#[cfg(feature = "openapi")]
+impl Type for Dice {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> std::borrow::Cow<'static, str> {
+        "string(dice)".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema::new_with_format("string", "dice")))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+#[cfg(feature = "openapi")]
+impl ParseFromJSON for Dice {
+    fn parse_from_json(value: Option<serde_json::Value>) -> ParseResult<Self> {
+        match value.unwrap_or_default() {
+            serde_json::Value::String(expression) => expression.parse().map_err(ParseError::custom),
+            value => Err(ParseError::expected_type(value)),
+        }
+    }
+}
+
+#[cfg(feature = "openapi")]
+impl ToJSON for Dice {
+    fn to_json(&self) -> Option<serde_json::Value> {
+        Some(serde_json::Value::String(self.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Dice;
+
+    #[test]
+    fn parses_and_prints_expressions() {
+        for expression in ["2d6+1", "1d20", "3d4-2"] {
+            let dice: Dice = expression.parse().unwrap();
+            assert_eq!(dice.to_string(), expression);
+        }
+
+        assert_eq!("d8".parse::<Dice>().unwrap(), Dice::new(1, 8, 0));
+        assert!("2x6".parse::<Dice>().is_err());
+        assert!("2d0".parse::<Dice>().is_err());
+        assert!("1000d6".parse::<Dice>().is_err());
+        assert!("2d6+99999".parse::<Dice>().is_err());
+    }
+
+    #[test]
+    fn reports_range_and_mean() {
+        let dice = Dice::new(3, 4, -2);
+        assert_eq!(dice.min(), 1);
+        assert_eq!(dice.max(), 10);
+        assert_eq!(dice.mean(), 5.5);
+        assert_eq!(Dice::new(1, 4, -10).mean(), 0.0);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn reads_dice_saved_as_d6_rolls() {
+        let dice: Dice = serde_json::from_str(r#"{"num_rolls": 3, "modifier": -1}"#).unwrap();
+        assert_eq!(dice, Dice::d6(3, -1));
+
+        let dice: Dice = serde_json::from_str(r#""2d8+2""#).unwrap();
+        assert_eq!(dice, Dice::new(2, 8, 2));
+    }
+}
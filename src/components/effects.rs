@@ -21,6 +21,11 @@ pub struct Effects {
     pub resurrection_aura: bool,
     #[cfg_attr(feature = "serialization", serde(default))]
     pub covered_in_oil: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub bleeding: Option<Bleeding>,
+    /// A stunned character loses their next attack.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub stunned: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -39,6 +44,8 @@ pub struct EffectsView {
     pub knows_has_resurrection_aura: bool,
     pub poison: Option<Poison>,
     pub covered_in_oil: bool,
+    pub bleeding: Option<Bleeding>,
+    pub stunned: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -48,3 +55,11 @@ pub struct Poison {
     pub damage: i32,
     pub duration: i32,
 }
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct Bleeding {
+    pub damage: i32,
+    pub duration: i32,
+}
@@ -8,9 +8,9 @@ use uuid::Uuid;
 
 use crate::components::{
     items::{
-        Descriptor, {FixtureItem, FixtureItemView},
+        Descriptor, Item, {FixtureItem, FixtureItemView},
     },
-    Material, Size,
+    Lock, LockDifficulty, Material, Size, Trap,
 };
 
 use super::FixtureType;
@@ -37,9 +37,20 @@ pub struct Fixture {
     pub open: bool,
     pub has_hidden_compartment: bool,
     pub hidden_compartment_open: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub lock: Option<Lock>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub trap: Option<Trap>,
 }
 
 impl Fixture {
+    pub fn is_locked(&self) -> bool {
+        self.lock
+            .as_ref()
+            .map(|lock| lock.locked)
+            .unwrap_or_default()
+    }
+
     pub fn remove_item(&mut self, item_id: &Uuid) -> Option<FixtureItem> {
         let index = self
             .items
@@ -53,6 +64,14 @@ impl Fixture {
             None => None,
         }
     }
+
+    /// Weapon racks have what's needed to keep weapons in fighting shape.
+    pub fn can_repair(&self, item: &Item) -> bool {
+        match self.fixture_type {
+            FixtureType::WeaponRack => item.is_weapon(),
+            _ => false,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -77,9 +96,18 @@ pub struct FixtureView {
     pub open: bool,
     pub can_be_opened: bool,
     pub hidden_compartment_open: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub is_locked: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub lock_difficulty: Option<LockDifficulty>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub has_trap: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub knows_if_trap: bool,
 }
 
 #[derive(Clone, Debug, Default)]
 pub struct FixtureViewArgs {
     pub knows_has_hidden_compartment: bool,
+    pub knows_has_trap: bool,
 }
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 
 use crate::components::{
+    rooms::FixtureInteraction,
     {BuiltWithMaterial, Material}, {Tag, Tagged},
 };
 
@@ -147,3 +148,26 @@ impl BuiltWithMaterial for FixtureType {
         }
     }
 }
+
+impl FixtureType {
+    /// How the fixture can be moved when it's part of a puzzle.
+    pub fn puzzle_interaction(&self) -> Option<FixtureInteraction> {
+        match *self {
+            FixtureType::Pillar => Some(FixtureInteraction::Push),
+            FixtureType::StatueTentacledMonstrosity => Some(FixtureInteraction::Activate),
+            FixtureType::StatueWarrior => Some(FixtureInteraction::Rotate),
+            _ => None,
+        }
+    }
+
+    /// Fixtures big enough to hide the workings of a secret door, inspecting
+    /// them closely can turn up a hidden exit in the room.
+    pub fn can_conceal_exit(&self) -> bool {
+        matches!(
+            *self,
+            FixtureType::Pillar
+                | FixtureType::StatueTentacledMonstrosity
+                | FixtureType::StatueWarrior
+        )
+    }
+}
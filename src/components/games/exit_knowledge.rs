@@ -0,0 +1,13 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct ExitKnowledge {
+    pub discovered: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub knows_has_trap: bool,
+}
@@ -8,4 +8,6 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
 pub struct FixtureKnowledge {
     pub knows_has_hidden_compartment: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub knows_has_trap: bool,
 }
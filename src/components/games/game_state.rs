@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[cfg(feature = "bevy_components")]
 use bevy_ecs::prelude::Component;
@@ -9,14 +9,15 @@ use uuid::Uuid;
 use crate::{
     components::{
         fixtures::FixtureViewArgs,
-        rooms::{Room, RoomView},
-        worlds::World,
-        CharacterViewArgs, NonPlayerViewArgs,
+        items::{Artifact, ItemViewArgs},
+        rooms::{Exit, Room, RoomView},
+        worlds::{ExitMap, World, WorldMap},
+        BossKind, CharacterViewArgs, NonPlayerViewArgs, PlayerCharacter, PlayerCharacterView,
     },
-    systems::view::room::view,
+    systems::view::{player::check_with_item_args, room::view, world_map},
 };
 
-use super::{CharacterKnowledge, FixtureKnowledge, Statistics};
+use super::{CharacterKnowledge, ExitKnowledge, FixtureKnowledge, ItemKnowledge, Statistics};
 
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
@@ -34,12 +35,30 @@ pub struct GameState {
     #[cfg_attr(feature = "serialization", serde(default))]
     pub player_fixture_knowledge: HashMap<Uuid, FixtureKnowledge>,
     #[cfg_attr(feature = "serialization", serde(default))]
+    pub player_item_knowledge: HashMap<Uuid, ItemKnowledge>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub player_exit_knowledge: HashMap<Uuid, ExitKnowledge>,
+    #[cfg_attr(feature = "serialization", serde(default))]
     pub player_statistics: HashMap<Uuid, Statistics>,
     #[cfg_attr(feature = "serialization", serde(default))]
     pub danger_level: u32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub spawned_artifacts: Vec<Artifact>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub encountered_bosses: Vec<BossKind>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub defeated_bosses: Vec<BossKind>,
+    /// How many turns have gone by in the world since the game started.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub turn: u32,
 }
 
 impl GameState {
+    /// Beating the final boss is what ends a run.
+    pub fn final_boss_defeated(&self) -> bool {
+        self.defeated_bosses.iter().any(|boss| boss.is_final())
+    }
+
     pub fn npc_knowledge(&self, npc_id: &Uuid) -> CharacterKnowledge {
         self.player_npc_knowledge
             .get(npc_id)
@@ -54,6 +73,47 @@ impl GameState {
             .unwrap_or_default()
     }
 
+    pub fn item_knowledge(&self, item_id: &Uuid) -> ItemKnowledge {
+        self.player_item_knowledge
+            .get(item_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn set_item_knowledge(&mut self, item_id: Uuid, knowledge: ItemKnowledge) {
+        self.player_item_knowledge.insert(item_id, knowledge);
+    }
+
+    pub fn item_view_args<'a>(
+        &self,
+        item_ids: impl Iterator<Item = &'a Uuid>,
+    ) -> HashMap<Uuid, ItemViewArgs> {
+        item_ids
+            .map(|item_id| {
+                (
+                    *item_id,
+                    ItemViewArgs {
+                        identified: self.player_knows_all
+                            || self.item_knowledge(item_id).identified,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    pub fn view_player(&self, player: &PlayerCharacter) -> PlayerCharacterView {
+        let item_args = self.item_view_args(
+            player
+                .character
+                .inventory
+                .equipment
+                .iter()
+                .map(|character_item| &character_item.item.id),
+        );
+
+        check_with_item_args(player.clone(), &item_args)
+    }
+
     pub fn set_npc_knowledge(&mut self, npc_id: Uuid, knowledge: CharacterKnowledge) {
         self.player_npc_knowledge.insert(npc_id, knowledge);
     }
@@ -84,10 +144,64 @@ impl GameState {
         self.player_fixture_knowledge.insert(fixture_id, knowledge);
     }
 
+    pub fn exit_knowledge(&self, exit_id: &Uuid) -> ExitKnowledge {
+        self.player_exit_knowledge
+            .get(exit_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn set_exit_knowledge(&mut self, exit_id: Uuid, knowledge: ExitKnowledge) {
+        self.player_exit_knowledge.insert(exit_id, knowledge);
+    }
+
+    /// Hidden exits stay out of sight until the player has found them.
+    pub fn knows_exit(&self, exit: &Exit) -> bool {
+        !exit.hidden || self.player_knows_all || self.exit_knowledge(&exit.id).discovered
+    }
+
+    /// The exits to take to get back to a room the player has already been in,
+    /// only going through rooms they've visited and exits they can use.
+    pub fn path_to_room(&self, room_id: &Uuid) -> Option<Vec<Uuid>> {
+        if !self.rooms_seen.contains(room_id) {
+            return None;
+        }
+
+        self.world
+            .shortest_path(self.current_room_id, *room_id, |exit_map| {
+                self.can_travel_through(exit_map)
+            })
+    }
+
+    /// Every room the player could travel back to from where they are.
+    pub fn rooms_within_reach(&self) -> HashSet<Uuid> {
+        self.world
+            .reachable_rooms(self.current_room_id, |exit_map| {
+                self.can_travel_through(exit_map)
+            })
+    }
+
+    fn can_travel_through(&self, exit_map: &ExitMap) -> bool {
+        [exit_map.left_room_id, exit_map.right_room_id]
+            .iter()
+            .all(|side| match side {
+                Some(side_room_id) if self.rooms_seen.contains(side_room_id) => self
+                    .world
+                    .rooms
+                    .iter()
+                    .find(|room| room.id.eq(side_room_id))
+                    .and_then(|room| room.exits.iter().find(|exit| exit.id.eq(&exit_map.exit_id)))
+                    .map(|exit| self.knows_exit(exit) && !exit.is_locked())
+                    .unwrap_or_default(),
+                _ => false,
+            })
+    }
+
     pub fn current_room_exits(&self) -> Vec<Uuid> {
         self.current_room()
             .exits
             .iter()
+            .filter(|exit| self.knows_exit(exit))
             .map(|exit| exit.id)
             .collect()
     }
@@ -108,6 +222,27 @@ impl GameState {
             .unwrap()
     }
 
+    /// Every exit the player knows about in the rooms they've been in.
+    pub fn view_map(&self) -> WorldMap {
+        let known_exits: Vec<Uuid> = self
+            .world
+            .rooms
+            .iter()
+            .filter(|room| self.rooms_seen.contains(&room.id))
+            .flat_map(|room| room.exits.iter())
+            .filter(|exit| self.knows_exit(exit))
+            .map(|exit| exit.id)
+            .collect();
+
+        world_map::view(
+            &self.world,
+            self.current_room_id,
+            &self.rooms_seen,
+            &known_exits,
+            self.player_knows_all,
+        )
+    }
+
     pub fn view_current_room(&self) -> RoomView {
         let room = self.current_room();
 
@@ -124,6 +259,7 @@ impl GameState {
                 fixture_id,
                 FixtureViewArgs {
                     knows_has_hidden_compartment: knowledge.knows_has_hidden_compartment,
+                    knows_has_trap: knowledge.knows_has_trap,
                 },
             );
         }
@@ -146,12 +282,36 @@ impl GameState {
                         knows_hidden_in_inventory: knowledge.knows_hidden_in_inventory,
                         knows_packed_in_inventory: knowledge.knows_packed_in_inventory,
                     },
+                    turn: self.turn,
                 },
             );
         }
 
+        let fixture_item_ids = room
+            .fixture_positions
+            .iter()
+            .flat_map(|fixture_position| fixture_position.fixture.items.iter())
+            .map(|fixture_item| &fixture_item.item.id);
+        let npc_item_ids = room
+            .npc_positions
+            .iter()
+            .flat_map(|npc_position| npc_position.npc.character.inventory.equipment.iter())
+            .map(|character_item| &character_item.item.id);
+        let item_args = self.item_view_args(fixture_item_ids.chain(npc_item_ids));
+
         let mut exit_visitations: HashMap<Uuid, bool> = HashMap::new();
         let room_id = room.id;
+        let exit_knowledge: HashMap<Uuid, ExitKnowledge> = room
+            .exits
+            .iter()
+            .map(|exit| {
+                let knowledge = ExitKnowledge {
+                    discovered: self.knows_exit(exit),
+                    ..self.exit_knowledge(&exit.id)
+                };
+                (exit.id, knowledge)
+            })
+            .collect();
         for exit in room.exits.iter() {
             let exit_map = match self
                 .world
@@ -172,7 +332,9 @@ impl GameState {
             room,
             npc_args,
             fixture_args,
+            item_args,
             exit_visitations,
+            exit_knowledge,
             self.player_knows_all,
         )
     }
@@ -193,4 +355,18 @@ mod tests {
 
         assert_eq!(stats.num_killed, 1);
     }
+
+    #[test]
+    fn map_starts_with_only_the_entry_and_its_exits() {
+        let state = game_generator().generate();
+        let map = state.view_map();
+
+        assert_eq!(map.rooms.len(), 1);
+        assert!(map.rooms.iter().all(|room| room.is_current && room.visited));
+        assert_eq!(
+            map.unexplored_exits().len(),
+            state.current_room_exits().len()
+        );
+        assert!(map.to_dot().starts_with("graph world {"));
+    }
 }
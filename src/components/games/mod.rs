@@ -1,9 +1,13 @@
 pub mod character_knowledge;
+pub mod exit_knowledge;
 pub mod fixture_knowledge;
 pub mod game_state;
+pub mod item_knowledge;
 pub mod statistics;
 
 pub use character_knowledge::CharacterKnowledge;
+pub use exit_knowledge::ExitKnowledge;
 pub use fixture_knowledge::FixtureKnowledge;
 pub use game_state::GameState;
+pub use item_knowledge::ItemKnowledge;
 pub use statistics::Statistics;
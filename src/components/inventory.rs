@@ -37,10 +37,55 @@ impl Inventory {
             .cloned()
     }
 
-    pub fn add_item(&mut self, character_item: CharacterItem) {
+    pub fn find_item_mut(&mut self, item_id: &Uuid) -> Option<&mut CharacterItem> {
+        self.equipment
+            .iter_mut()
+            .find(|character_item| character_item.item.id.eq(item_id))
+    }
+
+    /// Adds the item to the inventory, topping up any stacks of identical items
+    /// first. Whatever doesn't fit is kept as its own entry.
+    pub fn add_item(&mut self, mut character_item: CharacterItem) {
+        for stack in self.equipment.iter_mut() {
+            if !stack.stacks_with(&character_item) {
+                continue;
+            }
+
+            let moved = stack.stack_space().min(character_item.quantity);
+            stack.quantity += moved;
+            character_item.quantity -= moved;
+
+            if character_item.quantity == 0 {
+                return;
+            }
+        }
+
         self.equipment.push(character_item)
     }
 
+    /// Splits some items off a stack into a new entry with the given id. The new
+    /// entry is kept apart from the stack until it is added again.
+    pub fn split_item(&mut self, item_id: &Uuid, new_item_id: &Uuid, quantity: u32) {
+        let stack = match self
+            .equipment
+            .iter_mut()
+            .find(|character_item| character_item.item.id.eq(item_id))
+        {
+            Some(it) => it,
+            None => return,
+        };
+
+        if quantity == 0 || quantity >= stack.quantity {
+            return;
+        }
+
+        stack.quantity -= quantity;
+        let mut split = stack.clone();
+        split.quantity = quantity;
+        split.item.id = *new_item_id;
+        self.equipment.push(split);
+    }
+
     pub fn remove_item(&mut self, item_id: &Uuid) -> Option<CharacterItem> {
         let index = self
             .equipment
@@ -55,6 +100,13 @@ impl Inventory {
         }
     }
 
+    pub fn weight(&self) -> u32 {
+        self.equipment
+            .iter()
+            .map(|character_item| character_item.weight())
+            .sum()
+    }
+
     pub fn equipped_wearables(&self) -> Vec<CharacterItem> {
         self.equipment
             .iter()
@@ -0,0 +1,66 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Enum;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+/// Unique items that show up at most once in a world.
+#[derive(Clone, Debug, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum Artifact {
+    AegisOfTheDeep,
+    CrownOfTheHollowKing,
+    Emberfang,
+    Nightdrinker,
+    SerpentsKiss,
+}
+
+impl Artifact {
+    pub fn title(&self) -> &'static str {
+        match *self {
+            Artifact::AegisOfTheDeep => "Aegis of the Deep",
+            Artifact::CrownOfTheHollowKing => "Crown of the Hollow King",
+            Artifact::Emberfang => "Emberfang",
+            Artifact::Nightdrinker => "Nightdrinker",
+            Artifact::SerpentsKiss => "Serpent's Kiss",
+        }
+    }
+
+    pub fn lore(&self) -> &'static str {
+        match *self {
+            Artifact::AegisOfTheDeep => {
+                "Hauled up from a drowned temple, the shield still hums with the prayers of its keepers."
+            }
+            Artifact::CrownOfTheHollowKing => {
+                "The last king of the underworld wore it until there was nothing left of him but the crown."
+            }
+            Artifact::Emberfang => {
+                "Forged in the heart of a dying furnace, its edge has never once cooled."
+            }
+            Artifact::Nightdrinker => {
+                "A bone dagger carved by a cult that believed every wound was a mouth."
+            }
+            Artifact::SerpentsKiss => {
+                "Braided from the hide of a great serpent, its bite carries the serpent's venom still."
+            }
+        }
+    }
+
+    /// The artifact won't show up until the world is at least this dangerous.
+    pub fn min_danger_level(&self) -> u32 {
+        match *self {
+            Artifact::Emberfang | Artifact::SerpentsKiss => 10,
+            Artifact::Nightdrinker => 15,
+            Artifact::AegisOfTheDeep => 20,
+            Artifact::CrownOfTheHollowKing => 30,
+        }
+    }
+}
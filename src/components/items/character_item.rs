@@ -18,9 +18,38 @@ pub struct CharacterItem {
     pub equipped_location: LocationTag,
     pub is_multiple: bool,
     pub at_the_ready: bool,
+    /// How many identical items are in this stack.
+    #[cfg_attr(feature = "serialization", serde(default = "default_quantity"))]
+    pub quantity: u32,
+}
+
+#[cfg(feature = "serialization")]
+fn default_quantity() -> u32 {
+    1
 }
 
 impl CharacterItem {
+    pub fn weight(&self) -> u32 {
+        self.item.weight() * self.quantity
+    }
+
+    /// How many more items the stack can take before it is full.
+    pub fn stack_space(&self) -> u32 {
+        self.item
+            .item_type
+            .max_stack_size()
+            .saturating_sub(self.quantity)
+    }
+
+    /// Whether the other item can be added to this stack, it has to be identical
+    /// and kept in the same place.
+    pub fn stacks_with(&self, other: &CharacterItem) -> bool {
+        self.is_hidden == other.is_hidden
+            && self.equipped_location == other.equipped_location
+            && self.at_the_ready == other.at_the_ready
+            && self.item.stacks_with(&other.item)
+    }
+
     pub fn is_at_the_ready(&self) -> bool {
         self.at_the_ready
     }
@@ -69,6 +98,7 @@ pub struct CharacterItemView {
     pub equipped_location: Option<LocationTag>,
     pub is_multiple: bool,
     pub at_the_ready: bool,
+    pub quantity: u32,
 }
 
 impl CharacterItemView {
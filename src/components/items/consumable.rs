@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 
 use super::ConsumableEffect;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(
     feature = "serialization",
@@ -19,6 +19,24 @@ pub struct Consumable {
     pub effect: ConsumableEffect,
 }
 
+impl Consumable {
+    /// Consumables with the same effect stack, except for scrolls which only
+    /// stack with others teaching the same spell.
+    pub fn stacks_with(&self, other: &Consumable) -> bool {
+        self.effect.name == other.effect.name
+            && self
+                .effect
+                .learn_spell_effect
+                .as_ref()
+                .map(|effect| &effect.spell_name)
+                == other
+                    .effect
+                    .learn_spell_effect
+                    .as_ref()
+                    .map(|effect| &effect.spell_name)
+    }
+}
+
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(
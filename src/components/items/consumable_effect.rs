@@ -8,10 +8,10 @@ use strum_macros::EnumIter;
 
 use crate::components::{
     spells::SpellName,
-    {Attack, Defense},
+    Dice, {Attack, Defense},
 };
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(
     feature = "serialization",
@@ -24,6 +24,10 @@ pub struct ConsumableEffect {
     #[cfg_attr(feature = "serialization", serde(default))]
     pub learn_spell_effect: Option<LearnSpellEffect>,
     pub healing_effect: Option<HealingEffect>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub repair_effect: Option<RepairEffect>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub poison_effect: Option<PoisonEffect>,
 }
 
 #[derive(Clone, Debug, EnumIter, PartialEq, Eq, Hash)]
@@ -41,9 +45,12 @@ pub struct ConsumableEffect {
 pub enum ConsumableEffectName {
     LearnSpell,
     HealingGrog,
+    RepairKit,
+    Poison,
+    RemoveCurse,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(
     feature = "serialization",
@@ -58,7 +65,7 @@ pub struct LearnSpellEffect {
     pub spell_uses: i32,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(
     feature = "serialization",
@@ -67,5 +74,31 @@ pub struct LearnSpellEffect {
 )]
 #[cfg_attr(feature = "openapi", derive(Object), oai(rename_all = "snake_case"))]
 pub struct HealingEffect {
-    pub healing: Attack,
+    pub healing: Dice,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object), oai(rename_all = "snake_case"))]
+pub struct RepairEffect {
+    /// How much durability a single use restores.
+    pub amount: i32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object), oai(rename_all = "snake_case"))]
+pub struct PoisonEffect {
+    pub damage: i32,
+    pub duration: i32,
 }
@@ -0,0 +1,24 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Enum;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+/// A cursed item can't be put away once it's been readied, not until the
+/// curse is lifted.
+#[derive(Clone, Debug, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum Curse {
+    /// Draws the attention of everyone in the room.
+    Beckoning,
+    /// Slowly drains the health of whoever has it readied.
+    Draining,
+}
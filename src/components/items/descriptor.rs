@@ -52,3 +52,22 @@ pub enum Descriptor {
     WaterLogged,
     Weathered,
 }
+
+impl Descriptor {
+    /// Descriptors that say something about the wear and tear of an item.
+    pub fn is_condition(&self) -> bool {
+        matches!(
+            self,
+            Descriptor::Beaten
+                | Descriptor::Broken
+                | Descriptor::Chipped
+                | Descriptor::Cracked
+                | Descriptor::Ripped
+                | Descriptor::Rusty
+                | Descriptor::Scuffed
+                | Descriptor::Splintered
+                | Descriptor::Tarnished
+                | Descriptor::Torn
+        )
+    }
+}
@@ -0,0 +1,46 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Object;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object), oai(rename = "Durability"))]
+pub struct Durability {
+    pub current: i32,
+    pub max: i32,
+}
+
+impl Durability {
+    pub fn new(max: i32) -> Self {
+        Self { current: max, max }
+    }
+
+    pub fn is_broken(&self) -> bool {
+        self.current <= 0
+    }
+
+    /// Worn items are at half durability or less, but still usable.
+    pub fn is_worn(&self) -> bool {
+        !self.is_broken() && self.current * 2 <= self.max
+    }
+
+    pub fn needs_repair(&self) -> bool {
+        self.current < self.max
+    }
+
+    pub fn wear(&mut self, amount: i32) {
+        self.current = 0.max(self.current - amount);
+    }
+
+    pub fn repair(&mut self, amount: i32) {
+        self.current = self.max.min(self.current + amount);
+    }
+}
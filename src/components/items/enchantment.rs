@@ -0,0 +1,56 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Enum;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+use crate::components::damage::{AttackEffect, DamageType};
+
+#[derive(Clone, Debug, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum Enchantment {
+    /// The weapon burns whatever it hits.
+    Flaming,
+    /// Hits with the weapon heal the wielder.
+    Vampiric,
+    /// Hits with the weapon poison the target, "of Venom".
+    Venom,
+    /// Putting the item on surrounds the wearer with a shield aura.
+    Warding,
+}
+
+impl Enchantment {
+    pub fn is_for_weapons(&self) -> bool {
+        matches!(
+            *self,
+            Enchantment::Flaming | Enchantment::Vampiric | Enchantment::Venom
+        )
+    }
+
+    pub fn is_for_wearables(&self) -> bool {
+        matches!(*self, Enchantment::Warding)
+    }
+
+    /// Damage type the enchantment turns a weapon's hits into.
+    pub fn damage_type(&self) -> Option<DamageType> {
+        match *self {
+            Enchantment::Flaming => Some(DamageType::Fire),
+            _ => None,
+        }
+    }
+
+    pub fn attack_effect(&self) -> Option<AttackEffect> {
+        match *self {
+            Enchantment::Venom => Some(AttackEffect::Toxic),
+            _ => None,
+        }
+    }
+}
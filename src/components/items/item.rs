@@ -7,11 +7,13 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::components::{
-    Material, Tag, {Attack, Defense},
+    damage::{AttackEffect, DamageType},
+    DiceRange, Lock, Material, Tag, {Attack, Defense},
 };
 
 use super::{
-    Descriptor, ItemType, Throwable, ThrowableView, {Consumable, ConsumableView},
+    Artifact, Curse, Descriptor, Durability, Enchantment, ItemType, Throwable, ThrowableView,
+    {Consumable, ConsumableView},
 };
 
 #[derive(Clone, Debug)]
@@ -39,13 +41,27 @@ pub struct Item {
     pub consumable: Option<Consumable>,
     #[cfg_attr(feature = "serialization", serde(default))]
     pub throwable: Option<Throwable>,
+    /// Items without durability never wear out.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub durability: Option<Durability>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub enchantments: Vec<Enchantment>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub artifact: Option<Artifact>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub curse: Option<Curse>,
+    /// Curses stay hidden until the item is readied.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub curse_revealed: bool,
+    /// The id of the lock this item opens, if it's a key.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub unlocks: Option<Uuid>,
 }
 
 impl Item {
     pub fn num_attack_rolls(&self) -> usize {
-        self.attack
-            .as_ref()
-            .map(|attack| attack.num_rolls)
+        self.usable_attack()
+            .map(|attack| attack.dice.num_dice)
             .unwrap_or_default()
     }
 
@@ -65,8 +81,191 @@ impl Item {
         self.tags.iter().any(|tag| tag.is_consumable())
     }
 
+    /// Whether the two items are identical apart from their ids, and of a type
+    /// that can be stacked. Consumables only need to share an effect.
+    pub fn stacks_with(&self, other: &Item) -> bool {
+        let consumables_stack = match (&self.consumable, &other.consumable) {
+            (Some(consumable), Some(other_consumable)) => consumable.stacks_with(other_consumable),
+            (None, None) => true,
+            _ => false,
+        };
+
+        self.item_type.is_stackable()
+            && self.item_type == other.item_type
+            && self.name == other.name
+            && self.descriptors == other.descriptors
+            && self.material == other.material
+            && self.attack == other.attack
+            && self.defense == other.defense
+            && consumables_stack
+            && self.throwable == other.throwable
+            && self.durability == other.durability
+            && self.enchantments == other.enchantments
+            && self.curse == other.curse
+            && self.artifact == other.artifact
+            && self.unlocks == other.unlocks
+    }
+
+    /// What an unidentified consumable looks like to someone who doesn't know
+    /// what's in it.
+    pub fn unidentified_name(&self) -> Option<String> {
+        self.consumable.as_ref()?;
+
+        match self.item_type {
+            ItemType::Flask => Some("murky flask".to_string()),
+            ItemType::Scroll => Some("faded scroll".to_string()),
+            _ => None,
+        }
+    }
+
+    pub fn is_key_for(&self, lock: &Lock) -> bool {
+        self.unlocks.map(|id| id.eq(&lock.id)).unwrap_or_default()
+    }
+
+    pub fn is_cursed(&self) -> bool {
+        self.curse.is_some()
+    }
+
+    pub fn has_curse(&self, curse: &Curse) -> bool {
+        self.curse
+            .as_ref()
+            .map(|it| it.eq(curse))
+            .unwrap_or_default()
+    }
+
+    pub fn weight(&self) -> u32 {
+        let relative_weight = self
+            .material
+            .as_ref()
+            .map(|material| material.relative_weight())
+            .unwrap_or(100);
+        1.max(self.item_type.base_weight() * relative_weight / 100)
+    }
+
+    pub fn is_broken(&self) -> bool {
+        self.durability
+            .as_ref()
+            .map(|durability| durability.is_broken())
+            .unwrap_or_default()
+    }
+
+    pub fn needs_repair(&self) -> bool {
+        self.durability
+            .as_ref()
+            .map(|durability| durability.needs_repair())
+            .unwrap_or_default()
+    }
+
+    /// The attack of the item, unless it is broken.
+    pub fn usable_attack(&self) -> Option<&Attack> {
+        if self.is_broken() {
+            None
+        } else {
+            self.attack.as_ref()
+        }
+    }
+
+    /// The defense of the item, unless it is broken.
+    pub fn usable_defense(&self) -> Option<&Defense> {
+        if self.is_broken() {
+            None
+        } else {
+            self.defense.as_ref()
+        }
+    }
+
+    pub fn has_enchantment(&self, enchantment: &Enchantment) -> bool {
+        self.enchantments.contains(enchantment)
+    }
+
+    /// The effects of the item's attack along with any its enchantments add.
+    /// Broken items have none.
+    pub fn attack_effects(&self) -> Vec<AttackEffect> {
+        let mut effects = self
+            .usable_attack()
+            .map(|attack| attack.effects.clone())
+            .unwrap_or_default();
+
+        if self.usable_attack().is_some() {
+            for effect in self
+                .enchantments
+                .iter()
+                .filter_map(|enchantment| enchantment.attack_effect())
+            {
+                if !effects.contains(&effect) {
+                    effects.push(effect);
+                }
+            }
+        }
+
+        effects
+    }
+
+    /// The type of damage the item deals, enchantments take precedence over the
+    /// attack itself.
+    pub fn damage_type(&self) -> Option<DamageType> {
+        let attack = self.usable_attack()?;
+        Some(
+            self.enchantments
+                .iter()
+                .find_map(|enchantment| enchantment.damage_type())
+                .unwrap_or_else(|| attack.damage_type.clone()),
+        )
+    }
+
+    pub fn wear(&mut self, amount: i32) {
+        if let Some(durability) = self.durability.as_mut() {
+            durability.wear(amount);
+            self.update_condition();
+        }
+    }
+
+    pub fn repair(&mut self, amount: i32) {
+        if let Some(durability) = self.durability.as_mut() {
+            durability.repair(amount);
+            self.update_condition();
+        }
+    }
+
+    /// Keeps the condition descriptors in line with the durability.
+    /// Broken items are only ever described as broken, worn items pick up a
+    /// descriptor for their material and fully repaired ones lose them all.
+    fn update_condition(&mut self) {
+        let durability = match &self.durability {
+            Some(it) => it.clone(),
+            None => return,
+        };
+
+        if durability.is_broken() {
+            self.descriptors
+                .retain(|descriptor| !descriptor.is_condition());
+            self.descriptors.push(Descriptor::Broken);
+        } else if durability.is_worn() {
+            self.descriptors
+                .retain(|descriptor| descriptor != &Descriptor::Broken);
+            if !self
+                .descriptors
+                .iter()
+                .any(|descriptor| descriptor.is_condition())
+            {
+                let worn_descriptor = self
+                    .material
+                    .as_ref()
+                    .map(|material| material.worn_descriptor())
+                    .unwrap_or(Descriptor::Beaten);
+                self.descriptors.push(worn_descriptor);
+            }
+        } else if !durability.needs_repair() {
+            self.descriptors
+                .retain(|descriptor| !descriptor.is_condition());
+        } else {
+            self.descriptors
+                .retain(|descriptor| descriptor != &Descriptor::Broken);
+        }
+    }
+
     pub fn decrease_uses(&mut self) {
-        if let Some(mut consumable) = self.consumable.as_mut() {
+        if let Some(consumable) = self.consumable.as_mut() {
             consumable.uses -= 1;
         }
     }
@@ -96,6 +295,8 @@ pub struct ItemView {
     pub attack: Option<Attack>,
     pub attack_known: bool,
     #[cfg_attr(feature = "serialization", serde(default))]
+    pub damage: Option<DiceRange>,
+    #[cfg_attr(feature = "serialization", serde(default))]
     pub defense: Option<Defense>,
     pub defense_known: bool,
     #[cfg_attr(feature = "serialization", serde(default))]
@@ -103,6 +304,27 @@ pub struct ItemView {
     pub knows_consumable: bool,
     pub throwable: Option<ThrowableView>,
     pub is_equippable: bool,
+    pub weight: u32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub durability: Option<Durability>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub enchantments: Vec<Enchantment>,
+    pub enchantments_known: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub artifact: Option<Artifact>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub lore: Option<String>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub curse: Option<Curse>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub curse_known: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub identified: bool,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ItemViewArgs {
+    pub identified: bool,
 }
 
 impl ItemView {
@@ -114,3 +336,84 @@ impl ItemView {
         self.tags.iter().any(|tag| tag.is_wearable())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        components::{
+            items::{
+                Artifact, Consumable, ConsumableEffect, ConsumableEffectName, ItemType,
+                LearnSpellEffect,
+            },
+            spells::SpellName,
+        },
+        generators::{generator::Generator, items::item_generator_for_level},
+    };
+
+    use super::Item;
+
+    fn scroll(spell_name: SpellName, uses: i32) -> Item {
+        let mut scroll = item_generator_for_level(&ItemType::Scroll, false, 1).generate();
+        scroll.consumable = Some(Consumable {
+            uses,
+            effect: ConsumableEffect {
+                name: ConsumableEffectName::LearnSpell,
+                learn_spell_effect: Some(LearnSpellEffect {
+                    spell_name,
+                    spell_attack: None,
+                    spell_defense: None,
+                    spell_uses: 1,
+                }),
+                healing_effect: None,
+                repair_effect: None,
+                poison_effect: None,
+            },
+        });
+
+        scroll
+    }
+
+    fn copy_of(item: &Item) -> Item {
+        Item {
+            id: Uuid::new_v4(),
+            ..item.clone()
+        }
+    }
+
+    #[test]
+    fn consumables_with_the_same_effect_stack() {
+        let first = scroll(SpellName::Heal, 1);
+        let second = Item {
+            consumable: scroll(SpellName::Heal, 2).consumable,
+            ..copy_of(&first)
+        };
+
+        assert!(first.stacks_with(&second));
+    }
+
+    #[test]
+    fn scrolls_for_different_spells_do_not_stack() {
+        let first = scroll(SpellName::Heal, 1);
+        let second = Item {
+            consumable: scroll(SpellName::PoisonDart, 1).consumable,
+            ..copy_of(&first)
+        };
+
+        assert!(!first.stacks_with(&second));
+    }
+
+    #[test]
+    fn artifacts_and_keys_do_not_stack_with_plain_items() {
+        let first = scroll(SpellName::Heal, 1);
+
+        let mut artifact = copy_of(&first);
+        artifact.artifact = Some(Artifact::Emberfang);
+        assert!(!first.stacks_with(&artifact));
+
+        let mut key = copy_of(&first);
+        key.unlocks = Some(Uuid::new_v4());
+        assert!(!first.stacks_with(&key));
+    }
+}
@@ -6,7 +6,10 @@ use poem_openapi::Enum;
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 
-use crate::components::tag::{Tag, Tagged};
+use crate::components::{
+    tag::{Tag, Tagged},
+    DamageType,
+};
 
 #[derive(Clone, Debug, EnumIter, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
@@ -33,6 +36,7 @@ pub enum ItemType {
     Halberd,
     Hammer,
     Helm,
+    Key,
     LoinCloth,
     LongSword,
     Mace,
@@ -43,6 +47,7 @@ pub enum ItemType {
     PlateGauntlets,
     PlateHelmet,
     Pot,
+    RepairKit,
     Scroll,
     Shield,
     ShortSword,
@@ -84,6 +89,70 @@ impl ItemType {
             ItemType::Shackles | ItemType::Boots | ItemType::PlateBoots
         )
     }
+
+    /// The kind of damage a weapon of this type deals.
+    pub fn damage_type(&self) -> DamageType {
+        match *self {
+            ItemType::Dagger | ItemType::Dirk | ItemType::Pike | ItemType::Spear => {
+                DamageType::Piercing
+            }
+            ItemType::GreatSword
+            | ItemType::Halberd
+            | ItemType::LongSword
+            | ItemType::ShortSword
+            | ItemType::Whip => DamageType::Slashing,
+            _ => DamageType::Blunt,
+        }
+    }
+
+    /// How many identical items of this type fit into a single stack.
+    pub fn max_stack_size(&self) -> u32 {
+        match *self {
+            ItemType::Scroll => 10,
+            ItemType::Flask | ItemType::Pot => 5,
+            ItemType::RepairKit => 3,
+            _ => 1,
+        }
+    }
+
+    pub fn is_stackable(&self) -> bool {
+        self.max_stack_size() > 1
+    }
+
+    /// How much an item of this type weighs when made of iron. Lighter or
+    /// heavier materials scale this.
+    pub fn base_weight(&self) -> u32 {
+        match *self {
+            ItemType::Key | ItemType::LoinCloth | ItemType::Scroll => 1,
+            ItemType::BowlerHat
+            | ItemType::Dagger
+            | ItemType::Dirk
+            | ItemType::Fedora
+            | ItemType::Flask
+            | ItemType::Gloves
+            | ItemType::Mask
+            | ItemType::TopHat => 2,
+            ItemType::Crown | ItemType::Pot | ItemType::RepairKit | ItemType::Whip => 3,
+            ItemType::Boots | ItemType::Shirt | ItemType::ShortSword => 4,
+            ItemType::Buckler | ItemType::PlateGauntlets | ItemType::Trousers => 5,
+            ItemType::Cloak
+            | ItemType::Club
+            | ItemType::Helm
+            | ItemType::LongSword
+            | ItemType::Shackles
+            | ItemType::Spear
+            | ItemType::Vest => 6,
+            ItemType::Mace => 7,
+            ItemType::Hammer
+            | ItemType::Morningstar
+            | ItemType::PlateBoots
+            | ItemType::PlateHelmet => 8,
+            ItemType::Shield => 10,
+            ItemType::GreatSword | ItemType::Pike => 12,
+            ItemType::Halberd => 14,
+            ItemType::Breastplate => 15,
+        }
+    }
 }
 
 impl Tagged for ItemType {
@@ -125,6 +194,8 @@ impl Tagged for ItemType {
             ItemType::Scroll => vec![Tag::Consumable, Tag::Teachable],
             ItemType::Pot => vec![Tag::Consumable, Tag::Throwable],
             ItemType::Flask => vec![Tag::Consumable],
+            ItemType::RepairKit => vec![Tag::Consumable],
+            ItemType::Key => Vec::new(),
         }
     }
 }
@@ -10,7 +10,7 @@ use crate::components::Tag;
 
 use super::LocationTag;
 
-#[derive(Clone, Debug, EnumIter, PartialEq, Eq)]
+#[derive(Clone, Debug, Default, EnumIter, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(
     feature = "serialization",
@@ -31,15 +31,10 @@ pub enum LocationDescriptor {
     StrappedToBack,
     StrappedToThigh,
     ClenchedInFists,
+    #[default]
     None,
 }
 
-impl Default for LocationDescriptor {
-    fn default() -> Self {
-        LocationDescriptor::None
-    }
-}
-
 impl LocationDescriptor {
     pub fn matches_any_location_tags(&self, tags: &[LocationTag]) -> bool {
         tags.iter().any(|tag| self.tags().contains(tag))
@@ -105,6 +105,8 @@ pub fn ready_tag_for_item_type(item_type: &ItemType) -> LocationTag {
         ItemType::Scroll => LocationTag::Pockets,
         ItemType::Pot => LocationTag::Packed,
         ItemType::Flask => LocationTag::Packed,
+        ItemType::RepairKit => LocationTag::Packed,
+        ItemType::Key => LocationTag::Pockets,
     }
 }
 
@@ -164,6 +166,8 @@ pub fn packed_tags_for_item_type(item_type: &ItemType) -> Vec<LocationTag> {
         ItemType::Scroll => vec![LocationTag::Packed, LocationTag::Pockets],
         ItemType::Pot => vec![LocationTag::Packed],
         ItemType::Flask => vec![LocationTag::Packed],
+        ItemType::RepairKit => vec![LocationTag::Packed],
+        ItemType::Key => vec![LocationTag::Packed, LocationTag::Pockets],
     }
 }
 
@@ -211,5 +215,7 @@ pub fn location_tags_for_item_type(item_type: &ItemType) -> Vec<LocationTag> {
         ItemType::Scroll => vec![LocationTag::Packed, LocationTag::Pockets],
         ItemType::Pot => vec![LocationTag::Packed],
         ItemType::Flask => vec![LocationTag::Packed],
+        ItemType::RepairKit => vec![LocationTag::Packed],
+        ItemType::Key => vec![LocationTag::Packed, LocationTag::Pockets],
     }
 }
@@ -1,29 +1,39 @@
+mod artifact;
 mod character_item;
 mod consumable;
 mod consumable_effect;
+mod curse;
 mod descriptor;
+mod durability;
+mod enchantment;
 mod fixture_item;
 mod item;
 mod item_type;
 mod location_descriptor;
 mod location_tag;
+mod recipe;
 mod throwable;
 mod throwable_effect;
 
+pub use artifact::{Artifact, ArtifactIter};
 pub use character_item::{CharacterItem, CharacterItemView};
 pub use consumable::{Consumable, ConsumableView};
 pub use consumable_effect::{
     ConsumableEffect, ConsumableEffectName, ConsumableEffectNameIter, HealingEffect,
-    LearnSpellEffect,
+    LearnSpellEffect, PoisonEffect, RepairEffect,
 };
+pub use curse::{Curse, CurseIter};
 pub use descriptor::{Descriptor, DescriptorIter};
+pub use durability::Durability;
+pub use enchantment::{Enchantment, EnchantmentIter};
 pub use fixture_item::{FixtureItem, FixtureItemView};
-pub use item::{Item, ItemView};
+pub use item::{Item, ItemView, ItemViewArgs};
 pub use item_type::{ItemType, ItemTypeIter};
 pub use location_descriptor::{LocationDescriptor, LocationDescriptorIter};
 pub use location_tag::{
     location_tags_for_item_type, packed_tags_for_item_type, ready_tag_for_item_type, LocationTag,
     LocationTagIter,
 };
+pub use recipe::{Ingredient, Recipe, RecipeName, RecipeNameIter};
 pub use throwable::{Throwable, ThrowableView};
-pub use throwable_effect::{OilSplashEffect, ThrowableEffect, ThrowableEffectName};
+pub use throwable_effect::{FirebombEffect, OilSplashEffect, ThrowableEffect, ThrowableEffectName};
@@ -0,0 +1,162 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Enum;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+use crate::components::Tag;
+
+use super::{CharacterItem, ConsumableEffectName, Item, ThrowableEffectName};
+
+#[derive(Clone, Debug, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum RecipeName {
+    /// A pot of oil stuffed with a cloth wick, thrown to set enemies alight.
+    Firebomb,
+    /// A blade coated in poison, every hit with it poisons.
+    ToxicBlade,
+}
+
+/// Something an item has to be for it to be used in a recipe.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Ingredient {
+    Consumable(ConsumableEffectName),
+    Tagged(Tag),
+    Throwable(ThrowableEffectName),
+}
+
+impl Ingredient {
+    pub fn matches(&self, item: &Item) -> bool {
+        match self {
+            Ingredient::Consumable(name) => item
+                .consumable
+                .as_ref()
+                .map(|consumable| consumable.effect.name.eq(name))
+                .unwrap_or_default(),
+            Ingredient::Tagged(tag) => item.tags.contains(tag),
+            Ingredient::Throwable(name) => item
+                .throwable
+                .as_ref()
+                .map(|throwable| throwable.effect.name.eq(name))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct Recipe {
+    pub name: RecipeName,
+    /// The first ingredient is the base of the recipe, the crafted item is built
+    /// from it.
+    pub ingredients: Vec<Ingredient>,
+}
+
+/// Every recipe that can be crafted. Adding a recipe is only a matter of adding
+/// its name and ingredients here, the first ingredient being its base.
+const RECIPE_DEFINITIONS: &[(RecipeName, &[Ingredient])] = &[
+    (
+        RecipeName::Firebomb,
+        &[
+            Ingredient::Throwable(ThrowableEffectName::OilSplash),
+            Ingredient::Tagged(Tag::Cloth),
+        ],
+    ),
+    (
+        RecipeName::ToxicBlade,
+        &[
+            Ingredient::Tagged(Tag::Blade),
+            Ingredient::Consumable(ConsumableEffectName::Poison),
+        ],
+    ),
+];
+
+impl Recipe {
+    pub fn all() -> Vec<Recipe> {
+        RECIPE_DEFINITIONS
+            .iter()
+            .map(|(name, ingredients)| Recipe {
+                name: name.clone(),
+                ingredients: ingredients.to_vec(),
+            })
+            .collect()
+    }
+
+    /// Pairs every ingredient with a different one of the items, returning the
+    /// items in the same order as the ingredients. Every item has to be used.
+    pub fn match_items(&self, items: &[CharacterItem]) -> Option<Vec<CharacterItem>> {
+        if items.len() != self.ingredients.len() {
+            return None;
+        }
+
+        self.find_ingredients(items)
+    }
+
+    /// Like `match_items`, but picks the ingredients out of a larger set of items.
+    pub fn find_ingredients(&self, items: &[CharacterItem]) -> Option<Vec<CharacterItem>> {
+        let mut used = vec![false; items.len()];
+        let mut matched: Vec<CharacterItem> = Vec::new();
+        if self.match_from(0, items, &mut used, &mut matched) {
+            Some(matched)
+        } else {
+            None
+        }
+    }
+
+    fn match_from(
+        &self,
+        index: usize,
+        items: &[CharacterItem],
+        used: &mut [bool],
+        matched: &mut Vec<CharacterItem>,
+    ) -> bool {
+        let ingredient = match self.ingredients.get(index) {
+            Some(it) => it,
+            None => return true,
+        };
+
+        for (item_index, character_item) in items.iter().enumerate() {
+            if used[item_index] || !ingredient.matches(&character_item.item) {
+                continue;
+            }
+
+            used[item_index] = true;
+            matched.push(character_item.clone());
+            if self.match_from(index + 1, items, used, matched) {
+                return true;
+            }
+            used[item_index] = false;
+            matched.pop();
+        }
+
+        false
+    }
+
+    /// Finds the recipe that the items make, if any.
+    pub fn for_items(items: &[CharacterItem]) -> Option<(Recipe, Vec<CharacterItem>)> {
+        Self::all().into_iter().find_map(|recipe| {
+            recipe
+                .match_items(items)
+                .map(|matched| (recipe.clone(), matched))
+        })
+    }
+}
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 
 use super::ThrowableEffect;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(
     feature = "serialization",
@@ -6,7 +6,9 @@ use poem_openapi::{Enum, Object};
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 
-#[derive(Clone, Debug)]
+use crate::components::Dice;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(
     feature = "serialization",
@@ -18,6 +20,8 @@ pub struct ThrowableEffect {
     pub name: ThrowableEffectName,
     #[cfg_attr(feature = "serialization", serde(default))]
     pub oil_splash_effect: Option<OilSplashEffect>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub firebomb_effect: Option<FirebombEffect>,
 }
 
 #[derive(Clone, Debug, EnumIter, PartialEq, Eq, Hash)]
@@ -34,9 +38,10 @@ pub struct ThrowableEffect {
 )]
 pub enum ThrowableEffectName {
     OilSplash,
+    Firebomb,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(
     feature = "serialization",
@@ -47,3 +52,15 @@ pub enum ThrowableEffectName {
 pub struct OilSplashEffect {
     pub covers_all_enemies: bool,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct FirebombEffect {
+    pub damage: Dice,
+}
@@ -5,6 +5,8 @@ use poem_openapi::Enum;
 #[cfg(feature = "serialization")]
 use serde::{Deserialize, Serialize};
 
+use super::{DamageType, Resistance};
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(
@@ -18,3 +20,22 @@ pub enum LifeModifier {
     Vampire,
     Zombie,
 }
+
+impl LifeModifier {
+    pub fn resistance_to(&self, damage_type: &DamageType) -> Option<Resistance> {
+        match (self, damage_type) {
+            (LifeModifier::Skeleton | LifeModifier::Zombie, DamageType::Poison) => {
+                Some(Resistance::Immune)
+            }
+            (LifeModifier::Skeleton, DamageType::Piercing | DamageType::Slashing) => {
+                Some(Resistance::Resistant)
+            }
+            (LifeModifier::Skeleton, DamageType::Blunt) => Some(Resistance::Vulnerable),
+            (LifeModifier::Vampire, DamageType::Poison) => Some(Resistance::Resistant),
+            (LifeModifier::Vampire | LifeModifier::Zombie, DamageType::Fire) => {
+                Some(Resistance::Vulnerable)
+            }
+            _ => None,
+        }
+    }
+}
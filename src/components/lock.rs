@@ -0,0 +1,51 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Enum;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+use uuid::Uuid;
+
+#[derive(Clone, Debug, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum LockDifficulty {
+    Simple,
+    Sturdy,
+    Masterwork,
+}
+
+impl LockDifficulty {
+    /// What a d6 roll has to reach to pick the lock.
+    pub fn pick_target(&self) -> i32 {
+        match *self {
+            LockDifficulty::Simple => 3,
+            LockDifficulty::Sturdy => 5,
+            LockDifficulty::Masterwork => 6,
+        }
+    }
+}
+
+/// A lock on a fixture or an exit. Keys are tied to the id of the lock
+/// they open.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct Lock {
+    pub id: Uuid,
+    pub difficulty: LockDifficulty,
+    pub locked: bool,
+    /// Some locks, like those on puzzle vaults, have no keyhole to work at.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub unpickable: bool,
+}
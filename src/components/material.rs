@@ -6,7 +6,7 @@ use poem_openapi::Enum;
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 
-use super::{Tag, Tagged};
+use super::{items::Descriptor, DamageType, Resistance, Tag, Tagged};
 
 #[derive(Clone, Debug, PartialEq, Eq, EnumIter)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
@@ -63,3 +63,74 @@ impl Tagged for Material {
         }
     }
 }
+
+impl Material {
+    /// How armour made of this material holds up against a type of damage.
+    pub fn resistance_to(&self, damage_type: &DamageType) -> Option<Resistance> {
+        match (self, damage_type) {
+            (Material::Iron | Material::Steel, DamageType::Slashing) => Some(Resistance::Resistant),
+            (Material::Iron | Material::Steel, DamageType::Lightning) => {
+                Some(Resistance::Vulnerable)
+            }
+            (Material::Fur | Material::Hide | Material::Leather, DamageType::Blunt) => {
+                Some(Resistance::Resistant)
+            }
+            (
+                Material::Cotton
+                | Material::Linen
+                | Material::Paper
+                | Material::Papyrus
+                | Material::Silk
+                | Material::Wool,
+                DamageType::Fire,
+            ) => Some(Resistance::Vulnerable),
+            _ => None,
+        }
+    }
+
+    /// Weight of the material as a percentage of iron.
+    pub fn relative_weight(&self) -> u32 {
+        match *self {
+            Material::Gold => 150,
+            Material::Stone => 130,
+            Material::Iron | Material::Steel => 100,
+            Material::Bone | Material::Ceramic | Material::Glass => 70,
+            Material::Wooden => 60,
+            Material::Hide | Material::Leather => 50,
+            Material::Fur => 40,
+            Material::Cotton | Material::Linen | Material::Silk | Material::Wool => 30,
+            Material::Paper | Material::Papyrus => 20,
+        }
+    }
+
+    /// How many hits an item made of this material can take before it breaks.
+    pub fn max_durability(&self) -> i32 {
+        match *self {
+            Material::Steel => 40,
+            Material::Iron | Material::Stone => 30,
+            Material::Hide | Material::Leather => 25,
+            Material::Bone | Material::Wooden => 20,
+            Material::Fur | Material::Gold | Material::Wool => 15,
+            Material::Cotton | Material::Linen | Material::Silk => 10,
+            Material::Ceramic | Material::Glass => 8,
+            Material::Paper | Material::Papyrus => 5,
+        }
+    }
+
+    /// The descriptor an item made of this material picks up as it wears down.
+    pub fn worn_descriptor(&self) -> Descriptor {
+        match *self {
+            Material::Iron | Material::Steel => Descriptor::Rusty,
+            Material::Gold => Descriptor::Tarnished,
+            Material::Bone | Material::Wooden => Descriptor::Beaten,
+            Material::Ceramic | Material::Glass | Material::Stone => Descriptor::Cracked,
+            Material::Fur | Material::Hide | Material::Leather => Descriptor::Scuffed,
+            Material::Cotton
+            | Material::Linen
+            | Material::Paper
+            | Material::Papyrus
+            | Material::Silk
+            | Material::Wool => Descriptor::Torn,
+        }
+    }
+}
@@ -1,31 +1,48 @@
+pub mod attributes;
+pub mod boss;
 pub mod character;
 pub mod damage;
+pub mod dice;
 pub mod effects;
 pub mod fixtures;
 pub mod games;
 pub mod inventory;
 pub mod items;
 pub mod life_modifier;
+pub mod lock;
 pub mod material;
 pub mod non_player;
 pub mod player;
+pub mod progression;
 pub mod rooms;
 pub mod size;
 pub mod species;
 pub mod spells;
 pub mod stats;
 pub mod tag;
+pub mod trap;
 pub mod worlds;
 
+pub use attributes::{Attribute, Attributes};
+pub use boss::{Boss, BossKind, BossKindIter, BossPhase, BossPhaseIter};
 pub use character::{Character, CharacterView, CharacterViewArgs};
-pub use damage::{Attack, Defense, Health};
-pub use effects::{Effects, EffectsView, Poison};
+pub use damage::{
+    Attack, DamageCalculation, DamageModifier, DamageType, Defense, Health, Resistance,
+    ResistanceSource,
+};
+pub use dice::{Dice, DiceRange};
+pub use effects::{Bleeding, Effects, EffectsView, Poison};
 pub use inventory::{Inventory, InventoryView};
 pub use life_modifier::LifeModifier;
+pub use lock::{Lock, LockDifficulty, LockDifficultyIter};
 pub use material::{BuiltWithMaterial, Material, MaterialIter};
-pub use non_player::{NonPlayer, NonPlayerView, NonPlayerViewArgs};
+pub use non_player::{
+    CorpseDecay, NonPlayer, NonPlayerView, NonPlayerViewArgs, NpcMovement, NpcMovementIter,
+};
 pub use player::{PlayerCharacter, PlayerCharacterView};
+pub use progression::{Perk, PerkIter, Progression, ProgressionView};
 pub use size::Size;
 pub use species::{Species, SpeciesIter};
 pub use stats::{Stats, StatsView};
 pub use tag::{Tag, Tagged};
+pub use trap::{Trap, TrapType, TrapTypeIter};
@@ -1,12 +1,13 @@
 #[cfg(feature = "bevy_components")]
 use bevy_ecs::prelude::Component;
 #[cfg(feature = "openapi")]
-use poem_openapi::Object;
+use poem_openapi::{Enum, Object};
 #[cfg(feature = "serialization")]
 use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
 use uuid::Uuid;
 
-use super::{Character, CharacterView, CharacterViewArgs};
+use super::{Boss, BossKind, BossPhase, Character, CharacterView, CharacterViewArgs};
 
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
@@ -15,6 +16,50 @@ pub struct NonPlayer {
     pub character: Character,
     pub id: Uuid,
     pub name: Option<String>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub boss: Option<Boss>,
+    /// The world turn the NPC died on, how far the body has rotted is worked
+    /// out from it.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub died_on_turn: Option<u32>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub movement: NpcMovement,
+    /// The room the NPC was first placed in, patrols always lead back there.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub home_room_id: Option<Uuid>,
+}
+
+#[derive(Clone, Debug, Default, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum NpcMovement {
+    #[default]
+    Stationary,
+    /// Drifts from room to room with no place to be.
+    Wandering,
+    /// Walks out to the rooms next to their home and back again.
+    Patrolling,
+}
+
+pub const CORPSE_ROTTING_TURNS: u32 = 20;
+pub const CORPSE_SKELETAL_TURNS: u32 = 50;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum CorpseDecay {
+    Fresh,
+    Rotting,
+    Skeletal,
 }
 
 impl NonPlayer {
@@ -25,6 +70,25 @@ impl NonPlayer {
     pub fn kill(&mut self) {
         self.character.kill();
     }
+
+    pub fn corpse_decay(&self, turn: u32) -> Option<CorpseDecay> {
+        if !self.character.is_dead() {
+            return None;
+        }
+
+        let turns_dead = turn.saturating_sub(self.died_on_turn.unwrap_or(turn));
+        if turns_dead >= CORPSE_SKELETAL_TURNS {
+            Some(CorpseDecay::Skeletal)
+        } else if turns_dead >= CORPSE_ROTTING_TURNS {
+            Some(CorpseDecay::Rotting)
+        } else {
+            Some(CorpseDecay::Fresh)
+        }
+    }
+
+    pub fn boss_kind(&self) -> Option<BossKind> {
+        self.boss.as_ref().map(|boss| boss.kind.clone())
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -36,9 +100,14 @@ pub struct NonPlayerView {
     pub can_be_looted: bool,
     pub id: String,
     pub name: Option<String>,
+    pub boss: Option<BossKind>,
+    pub boss_phase: Option<BossPhase>,
+    pub corpse_decay: Option<CorpseDecay>,
 }
 
 #[derive(Clone, Debug, Default)]
 pub struct NonPlayerViewArgs {
     pub character_args: CharacterViewArgs,
+    /// The current world turn, to tell how long a body has been lying there.
+    pub turn: u32,
 }
@@ -6,7 +6,7 @@ use poem_openapi::Object;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::{Character, CharacterView};
+use super::{Character, CharacterView, Progression, ProgressionView};
 
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
@@ -17,6 +17,8 @@ pub struct PlayerCharacter {
     #[cfg_attr(feature = "serialization", serde(default))]
     pub gold: u32,
     pub name: Option<String>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub progression: Progression,
 }
 
 #[derive(Clone, Debug)]
@@ -28,4 +30,9 @@ pub struct PlayerCharacterView {
     pub id: String,
     pub gold: u32,
     pub name: Option<String>,
+    pub progression: ProgressionView,
+    pub carried_weight: u32,
+    pub carrying_capacity: u32,
+    pub max_carried_weight: u32,
+    pub is_encumbered: bool,
 }
@@ -0,0 +1,118 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::{Enum, Object};
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+const EXPERIENCE_PER_LEVEL_STEP: u32 = 100;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct Progression {
+    pub level: u32,
+    pub experience: u32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub perks: Vec<Perk>,
+    /// Each level gained offers a set of perks, and the player picks one from
+    /// the oldest set first.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub pending_perk_choices: Vec<Vec<Perk>>,
+}
+
+impl Default for Progression {
+    fn default() -> Self {
+        Self {
+            level: 1,
+            experience: 0,
+            perks: Vec::new(),
+            pending_perk_choices: Vec::new(),
+        }
+    }
+}
+
+impl Progression {
+    /// Total experience needed to reach the given level.
+    /// Every level costs one step more than the one before it.
+    pub fn experience_for_level(level: u32) -> u32 {
+        let previous = level.saturating_sub(1);
+        EXPERIENCE_PER_LEVEL_STEP * previous * (previous + 1) / 2
+    }
+
+    pub fn experience_for_next_level(&self) -> u32 {
+        Self::experience_for_level(self.level + 1)
+    }
+
+    /// How many times the perk has been taken, never more than its max ranks.
+    pub fn perk_count(&self, perk: &Perk) -> usize {
+        self.perks
+            .iter()
+            .filter(|p| p.eq(&perk))
+            .count()
+            .min(perk.max_ranks())
+    }
+
+    pub fn perk_is_maxed(&self, perk: &Perk) -> bool {
+        self.perk_count(perk) >= perk.max_ranks()
+    }
+
+    pub fn can_choose_perk(&self, perk: &Perk) -> bool {
+        !self.perk_is_maxed(perk)
+            && self
+                .pending_perk_choices
+                .first()
+                .map(|choices| choices.contains(perk))
+                .unwrap_or_default()
+    }
+
+    pub fn choose_perk(&mut self, perk: &Perk) {
+        if self.can_choose_perk(perk) {
+            self.pending_perk_choices.remove(0);
+            self.perks.push(perk.clone());
+        }
+    }
+}
+
+#[derive(Clone, Debug, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum Perk {
+    /// Hits harder with every weapon attack.
+    Brawler,
+    /// Gains a chunk of max health when chosen.
+    Hardy,
+    /// More likely to dodge attacks.
+    Nimble,
+    /// More likely to discover things when inspecting.
+    Observant,
+}
+
+impl Perk {
+    /// Taking a perk again stacks its effect, up to this many times.
+    pub fn max_ranks(&self) -> usize {
+        match *self {
+            Perk::Brawler | Perk::Nimble | Perk::Observant => 3,
+            Perk::Hardy => 5,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "openapi", derive(Object), oai(rename = "Progression"))]
+pub struct ProgressionView {
+    pub level: u32,
+    pub experience: u32,
+    pub next_level_experience: u32,
+    pub perks: Vec<Perk>,
+    pub perk_choices: Vec<Perk>,
+    pub num_pending_perk_choices: usize,
+}
@@ -7,7 +7,7 @@ use poem_openapi::Object;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::components::{Material, Size};
+use crate::components::{Lock, LockDifficulty, Material, Size, Trap};
 
 use super::{ExitDescriptor, ExitType};
 
@@ -21,6 +21,22 @@ pub struct Exit {
     pub material: Option<Material>,
     pub descriptors: Vec<ExitDescriptor>,
     pub size: Option<Size>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub lock: Option<Lock>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub trap: Option<Trap>,
+    /// A secret door or passage, left out of the room until it's discovered.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub hidden: bool,
+}
+
+impl Exit {
+    pub fn is_locked(&self) -> bool {
+        self.lock
+            .as_ref()
+            .map(|lock| lock.locked)
+            .unwrap_or_default()
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -35,4 +51,14 @@ pub struct ExitView {
     pub descriptors: Vec<ExitDescriptor>,
     pub size: Option<Size>,
     pub has_visited_connected_room: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub is_locked: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub lock_difficulty: Option<LockDifficulty>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub is_hidden: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub has_trap: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub knows_if_trap: bool,
 }
@@ -24,3 +24,24 @@ pub enum ExitType {
     Hallway,
     DugOutTunnelEntrance,
 }
+
+impl ExitType {
+    /// How many floors going through the exit moves the player, stairs down
+    /// lead one floor deeper.
+    pub fn depth_change(&self) -> i32 {
+        match *self {
+            ExitType::StaircaseDown => 1,
+            ExitType::StaircaseUp => -1,
+            _ => 0,
+        }
+    }
+
+    /// What the exit looks like from the room on the other side of it.
+    pub fn other_side(&self) -> ExitType {
+        match *self {
+            ExitType::StaircaseDown => ExitType::StaircaseUp,
+            ExitType::StaircaseUp => ExitType::StaircaseDown,
+            _ => self.clone(),
+        }
+    }
+}
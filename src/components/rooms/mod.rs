@@ -9,6 +9,7 @@ pub mod flavour;
 pub mod group_descriptor;
 pub mod npc_position;
 pub mod npc_position_descriptor;
+pub mod puzzle;
 pub mod room;
 pub mod room_type;
 pub mod room_view;
@@ -24,6 +25,10 @@ pub use flavour::{Flavour, FlavourIter};
 pub use group_descriptor::GroupDescriptor;
 pub use npc_position::{NpcPosition, NpcPositionView};
 pub use npc_position_descriptor::NpcPositionDescriptor;
+pub use puzzle::{
+    FixtureInteraction, FixtureInteractionIter, Puzzle, PuzzlePiece, PuzzlePieceView, PuzzleReward,
+    PuzzleView,
+};
 pub use room::Room;
 pub use room_type::{RoomType, RoomTypeIter};
 pub use room_view::{RoomView, RoomViewArgs};
@@ -19,6 +19,19 @@ pub struct NpcPosition {
     pub position_descriptor: Option<NpcPositionDescriptor>,
 }
 
+impl NpcPosition {
+    /// Alive, not asleep and not reeling from a blow, so ready to fight.
+    pub fn is_awake(&self) -> bool {
+        !self.npc.character.is_dead()
+            && !self.npc.character.current_effects.stunned
+            && !self
+                .position_descriptor
+                .as_ref()
+                .map(|descriptor| descriptor.is_asleep())
+                .unwrap_or_default()
+    }
+}
+
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
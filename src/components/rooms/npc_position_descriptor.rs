@@ -34,3 +34,14 @@ pub enum NpcPositionDescriptor {
     SittingInAChairIs,
     IsLyingInPoolBlood,
 }
+
+impl NpcPositionDescriptor {
+    pub fn is_asleep(&self) -> bool {
+        matches!(
+            *self,
+            NpcPositionDescriptor::IsSleepingInTheBed
+                | NpcPositionDescriptor::IsSleepingInACot
+                | NpcPositionDescriptor::IsSleepingInSleepingRoll
+        )
+    }
+}
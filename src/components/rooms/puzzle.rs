@@ -0,0 +1,145 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::{Enum, Object};
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+use uuid::Uuid;
+
+use crate::components::items::Item;
+
+#[derive(Clone, Debug, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum FixtureInteraction {
+    Activate,
+    Push,
+    Rotate,
+}
+
+impl FixtureInteraction {
+    /// How many positions a fixture moves through before it's back where it
+    /// started.
+    pub fn num_positions(&self) -> u32 {
+        match *self {
+            FixtureInteraction::Activate | FixtureInteraction::Push => 2,
+            FixtureInteraction::Rotate => 4,
+        }
+    }
+}
+
+/// A fixture in the room that is part of the puzzle.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct PuzzlePiece {
+    pub fixture_id: Uuid,
+    pub interaction: FixtureInteraction,
+    pub position: u32,
+    pub target: u32,
+}
+
+impl PuzzlePiece {
+    pub fn is_in_place(&self) -> bool {
+        self.position == self.target
+    }
+
+    pub fn next_position(&self) -> u32 {
+        (self.position + 1) % self.interaction.num_positions()
+    }
+}
+
+/// What solving the puzzle gets the player.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum PuzzleReward {
+    /// A hidden exit in the room swings open.
+    HiddenExit(Uuid),
+    /// A fixture with a lock that has no key unlocks.
+    Vault(Uuid),
+    /// An item rises out of the first fixture of the puzzle.
+    Item(Box<Item>),
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct Puzzle {
+    pub id: Uuid,
+    pub pieces: Vec<PuzzlePiece>,
+    pub reward: PuzzleReward,
+    pub solved: bool,
+}
+
+impl Puzzle {
+    pub fn find_piece(&self, fixture_id: &Uuid) -> Option<&PuzzlePiece> {
+        self.pieces
+            .iter()
+            .find(|piece| piece.fixture_id.eq(fixture_id))
+    }
+
+    pub fn find_piece_mut(&mut self, fixture_id: &Uuid) -> Option<&mut PuzzlePiece> {
+        self.pieces
+            .iter_mut()
+            .find(|piece| piece.fixture_id.eq(fixture_id))
+    }
+
+    pub fn pieces_in_place(&self) -> usize {
+        self.pieces
+            .iter()
+            .filter(|piece| piece.is_in_place())
+            .count()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object), oai(rename = "PuzzlePiece"))]
+pub struct PuzzlePieceView {
+    pub fixture_id: String,
+    pub interaction: FixtureInteraction,
+    pub position: u32,
+    pub num_positions: u32,
+    pub target: Option<u32>,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object), oai(rename = "Puzzle"))]
+pub struct PuzzleView {
+    pub id: String,
+    pub pieces: Vec<PuzzlePieceView>,
+    /// Every piece that's in place gives a faint click, so the player always
+    /// knows how close they are.
+    pub pieces_in_place: usize,
+    pub solved: bool,
+}
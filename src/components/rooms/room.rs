@@ -6,7 +6,9 @@ use uuid::Uuid;
 
 use crate::components::NonPlayer;
 
-use super::{Descriptor, Dimensions, Exit, FixturePosition, Flavour, NpcPosition, RoomType};
+use super::{
+    Descriptor, Dimensions, Exit, FixturePosition, Flavour, NpcPosition, Puzzle, RoomType,
+};
 
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
@@ -27,6 +29,11 @@ pub struct Room {
     pub flavour: Option<Flavour>,
     #[cfg_attr(feature = "serialization", serde(default))]
     pub exits: Vec<Exit>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub puzzle: Option<Puzzle>,
+    /// How many floors down the room is, the entry floor is zero.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub depth: u32,
 }
 
 impl Room {
@@ -51,6 +58,21 @@ impl Room {
             .find(|_| true) // First one
     }
 
+    pub fn awake_npcs(&self) -> Vec<&NonPlayer> {
+        self.npc_positions
+            .iter()
+            .filter(|npc_position| npc_position.is_awake())
+            .map(|npc_position| &npc_position.npc)
+            .collect()
+    }
+
+    pub fn alive_boss(&self) -> Option<&NonPlayer> {
+        self.npc_positions
+            .iter()
+            .map(|npc_position| &npc_position.npc)
+            .find(|npc| npc.boss.is_some() && !npc.character.is_dead())
+    }
+
     pub fn find_npc_mut(&mut self, target_id: &Uuid) -> Option<&mut NpcPosition> {
         self.npc_positions
             .iter_mut()
@@ -6,7 +6,8 @@ use poem_openapi::Object;
 use serde::{Deserialize, Serialize};
 
 use super::{
-    Descriptor, Dimensions, ExitView, FixturePositionView, Flavour, NpcPositionView, RoomType,
+    Descriptor, Dimensions, ExitView, FixturePositionView, Flavour, NpcPositionView, PuzzleView,
+    RoomType,
 };
 
 #[derive(Clone, Debug)]
@@ -23,6 +24,10 @@ pub struct RoomView {
     pub npc_positions: Vec<NpcPositionView>,
     pub flavour: Option<Flavour>,
     pub exits: Vec<ExitView>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub puzzle: Option<PuzzleView>,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub depth: u32,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -33,4 +33,18 @@ impl Size {
     pub fn is_average(&self) -> bool {
         matches!(*self, Size::Medium | Size::Average)
     }
+
+    /// How much weight a creature of this size can carry before strength is
+    /// taken into account.
+    pub fn carrying_capacity(&self) -> u32 {
+        match *self {
+            Size::Tiny => 10,
+            Size::Narrow | Size::Short | Size::Small => 20,
+            Size::Average | Size::Medium | Size::Squat => 30,
+            Size::Long | Size::Tall | Size::Wide => 35,
+            Size::Large => 45,
+            Size::Huge => 60,
+            Size::Massive => 80,
+        }
+    }
 }
@@ -6,6 +6,8 @@ use poem_openapi::Enum;
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 
+use super::{DamageType, Resistance};
+
 #[derive(Clone, Debug, EnumIter, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(
@@ -30,3 +32,32 @@ pub enum Species {
     Shadow,
     Turtlekin,
 }
+
+impl Species {
+    pub fn resistance_to(&self, damage_type: &DamageType) -> Option<Resistance> {
+        match (self, damage_type) {
+            (Species::Dragonkin, DamageType::Fire) => Some(Resistance::Resistant),
+            (Species::Dragonkin, DamageType::Lightning) => Some(Resistance::Vulnerable),
+            (Species::Frogkin, DamageType::Poison) => Some(Resistance::Resistant),
+            (Species::Frogkin, DamageType::Fire) => Some(Resistance::Vulnerable),
+            (Species::Lizardkin, DamageType::Poison) => Some(Resistance::Resistant),
+            (Species::Ogre, DamageType::Blunt) => Some(Resistance::Resistant),
+            (Species::Ogre, DamageType::Piercing) => Some(Resistance::Vulnerable),
+            (Species::Phantom, DamageType::Blunt | DamageType::Piercing | DamageType::Slashing) => {
+                Some(Resistance::Resistant)
+            }
+            (Species::Phantom, DamageType::Lightning) => Some(Resistance::Vulnerable),
+            (Species::Rockoblin, DamageType::Blunt | DamageType::Slashing) => {
+                Some(Resistance::Resistant)
+            }
+            (Species::Rockoblin, DamageType::Acid) => Some(Resistance::Vulnerable),
+            (Species::Shadow, DamageType::Poison) => Some(Resistance::Resistant),
+            (Species::Shadow, DamageType::Fire) => Some(Resistance::Vulnerable),
+            (Species::Turtlekin, DamageType::Blunt | DamageType::Piercing) => {
+                Some(Resistance::Resistant)
+            }
+            (Species::Turtlekin, DamageType::Lightning) => Some(Resistance::Vulnerable),
+            _ => None,
+        }
+    }
+}
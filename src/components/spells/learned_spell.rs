@@ -20,6 +20,16 @@ pub struct LearnedSpell {
     pub id: Uuid,
     pub spell: Spell,
     pub learned_at: DateTime<Utc>,
+    /// How many uses the spell had when it was learned, resting never brings
+    /// it back past that.
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub learned_uses: i32,
+}
+
+impl LearnedSpell {
+    pub fn max_uses(&self) -> i32 {
+        self.learned_uses.max(self.spell.uses)
+    }
 }
 
 #[derive(Clone, Debug)]
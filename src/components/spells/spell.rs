@@ -5,7 +5,7 @@ use poem_openapi::Object;
 #[cfg(feature = "serialization")]
 use serde::{Deserialize, Serialize};
 
-use crate::components::{Attack, Defense};
+use crate::components::{Attack, Defense, DiceRange};
 
 use super::{SpellName, SpellType};
 
@@ -52,6 +52,8 @@ pub struct SpellView {
     pub name: SpellName,
     pub attack: Option<Attack>,
     pub knows_attack: bool,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub damage: Option<DiceRange>,
     pub defense: Option<Defense>,
     pub knows_defense: bool,
     pub uses: i32,
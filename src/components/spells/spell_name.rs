@@ -6,6 +6,8 @@ use poem_openapi::Enum;
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 
+use crate::components::DamageType;
+
 use super::SpellType;
 
 #[derive(Clone, Debug, EnumIter, PartialEq, Eq)]
@@ -21,6 +23,7 @@ pub enum SpellName {
     ElectricBlast,
     GreatHeal,
     Heal,
+    Identify,
     Phoenix,
     QuickHeal,
     RagingFireball,
@@ -39,9 +42,25 @@ impl SpellName {
             | SpellName::PoisonDart
             | SpellName::AcidSplash => SpellType::Attack,
             SpellName::Heal | SpellName::QuickHeal | SpellName::GreatHeal => SpellType::Healing,
-            SpellName::Phoenix | SpellName::Retribution | SpellName::TinyShield => {
-                SpellType::PlayerEffect
-            }
+            SpellName::Identify
+            | SpellName::Phoenix
+            | SpellName::Retribution
+            | SpellName::TinyShield => SpellType::PlayerEffect,
+        }
+    }
+
+    pub fn damage_type(&self) -> Option<DamageType> {
+        match *self {
+            SpellName::AcidSplash => Some(DamageType::Acid),
+            SpellName::ElectricBlast | SpellName::Retribution => Some(DamageType::Lightning),
+            SpellName::RagingFireball => Some(DamageType::Fire),
+            SpellName::PoisonCloud | SpellName::PoisonDart => Some(DamageType::Poison),
+            SpellName::GreatHeal
+            | SpellName::Heal
+            | SpellName::Identify
+            | SpellName::Phoenix
+            | SpellName::QuickHeal
+            | SpellName::TinyShield => None,
         }
     }
 }
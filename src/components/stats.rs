@@ -5,7 +5,7 @@ use poem_openapi::Object;
 #[cfg(feature = "serialization")]
 use serde::{Deserialize, Serialize};
 
-use super::{Health, Size};
+use super::{Attributes, Health, Size};
 
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
@@ -13,6 +13,8 @@ use super::{Health, Size};
 pub struct Stats {
     pub health: Health,
     pub height: Size,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub attributes: Attributes,
 }
 
 #[derive(Clone, Debug)]
@@ -23,4 +25,6 @@ pub struct StatsView {
     pub health: Option<Health>,
     pub health_known: bool,
     pub height: Size,
+    pub attributes: Option<Attributes>,
+    pub attributes_known: bool,
 }
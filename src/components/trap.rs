@@ -0,0 +1,47 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Enum;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+use super::{DamageType, Dice};
+
+#[derive(Clone, Debug, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Enum), oai(rename_all = "snake_case"))]
+pub enum TrapType {
+    AcidSpray,
+    FallingBlock,
+    PoisonNeedle,
+}
+
+impl TrapType {
+    pub fn damage_type(&self) -> DamageType {
+        match *self {
+            TrapType::AcidSpray => DamageType::Acid,
+            TrapType::FallingBlock => DamageType::Blunt,
+            TrapType::PoisonNeedle => DamageType::Piercing,
+        }
+    }
+}
+
+/// A trap on a fixture or an exit, sprung the first time it's opened or
+/// walked through. A sprung or disarmed trap is gone for good.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+pub struct Trap {
+    pub trap_type: TrapType,
+    pub damage: Dice,
+}
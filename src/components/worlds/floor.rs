@@ -0,0 +1,20 @@
+/// The deepest floor of the underworld, where the run comes to an end.
+pub const FINAL_DEPTH: u32 = 9;
+
+const BASE_DANGER_LEVEL: u32 = 1;
+const DANGER_PER_DEPTH: u32 = 4;
+const BASE_ROOMS_PER_FLOOR: usize = 5;
+
+/// Everything generated on a floor is as dangerous as the floor is deep.
+pub fn danger_level_for_depth(depth: u32) -> u32 {
+    BASE_DANGER_LEVEL + depth * DANGER_PER_DEPTH
+}
+
+/// Once a floor has this many rooms, new rooms on it only lead further down.
+pub fn room_budget_for_depth(depth: u32) -> usize {
+    BASE_ROOMS_PER_FLOOR + depth as usize / 2
+}
+
+pub fn next_depth(depth: u32, change: i32) -> u32 {
+    depth.saturating_add_signed(change).min(FINAL_DEPTH)
+}
@@ -1,5 +1,9 @@
+pub mod floor;
 pub mod world;
+pub mod world_map;
 pub mod world_view;
 
+pub use floor::{danger_level_for_depth, next_depth, room_budget_for_depth, FINAL_DEPTH};
 pub use world::{ExitMap, World};
+pub use world_map::{MapConnection, MapRoom, WorldMap};
 pub use world_view::{ExitMapView, WorldView};
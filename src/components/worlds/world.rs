@@ -1,10 +1,15 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
 #[cfg(feature = "bevy_components")]
 use bevy_ecs::prelude::Component;
 #[cfg(feature = "serialization")]
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::components::rooms::Room;
+use crate::components::{
+    rooms::{Exit, NpcPosition, Room},
+    Lock, Trap,
+};
 
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
@@ -35,6 +40,172 @@ impl World {
             self.rooms.push(room);
         }
     }
+
+    /// Finds an NPC in any room, not just the one the player is in.
+    pub fn find_npc_mut(&mut self, npc_id: &Uuid) -> Option<&mut NpcPosition> {
+        self.rooms
+            .iter_mut()
+            .find_map(|room| room.find_npc_mut(npc_id))
+    }
+
+    /// Takes the NPC out of whichever room they're in and puts them in another.
+    pub fn move_npc(&mut self, npc_id: &Uuid, to_room_id: &Uuid) {
+        let npc_position = match self.rooms.iter_mut().find_map(|room| {
+            room.index_of_npc_position(npc_id)
+                .map(|index| room.remove_npc_position(index))
+        }) {
+            Some(it) => it,
+            None => return,
+        };
+
+        if let Some(room) = self.rooms.iter_mut().find(|room| room.id.eq(to_room_id)) {
+            room.npc_positions.push(npc_position);
+        }
+    }
+
+    /// An exit is locked if it's locked from either side.
+    pub fn exit_is_locked(&self, exit_id: &Uuid) -> bool {
+        self.exit_lock(exit_id).is_some()
+    }
+
+    /// The lock still holding the exit shut, whichever side it was put on.
+    pub fn exit_lock(&self, exit_id: &Uuid) -> Option<&Lock> {
+        self.exit_sides(*exit_id)
+            .filter_map(|exit| exit.lock.as_ref())
+            .find(|lock| lock.locked)
+    }
+
+    /// A trap on either side of the exit goes off whichever way it's crossed.
+    pub fn exit_trap(&self, exit_id: &Uuid) -> Option<&Trap> {
+        self.exit_sides(*exit_id)
+            .find_map(|exit| exit.trap.as_ref())
+    }
+
+    /// Both rooms joined by an exit keep their own copy of it.
+    pub fn exit_sides(&self, exit_id: Uuid) -> impl Iterator<Item = &Exit> {
+        self.rooms
+            .iter()
+            .flat_map(|room| room.exits.iter())
+            .filter(move |exit| exit.id.eq(&exit_id))
+    }
+
+    pub fn exit_sides_mut(&mut self, exit_id: Uuid) -> impl Iterator<Item = &mut Exit> {
+        self.rooms
+            .iter_mut()
+            .flat_map(|room| room.exits.iter_mut())
+            .filter(move |exit| exit.id.eq(&exit_id))
+    }
+
+    /// The rooms that can be walked straight into from this one, along with
+    /// the exit that leads there. Locked exits and exits into rooms that
+    /// haven't been generated yet are left out.
+    pub fn open_neighbours(&self, room_id: Uuid) -> Vec<(Uuid, Uuid)> {
+        self.exit_graph
+            .iter()
+            .filter(|exit_map| {
+                exit_map.left_room_id.eq(&Some(room_id))
+                    || exit_map.right_room_id.eq(&Some(room_id))
+            })
+            .filter(|exit_map| !self.exit_is_locked(&exit_map.exit_id))
+            .filter_map(|exit_map| {
+                exit_map
+                    .other_room_id(room_id)
+                    .map(|other_room_id| (exit_map.exit_id, other_room_id))
+            })
+            .collect()
+    }
+
+    pub fn rooms_on_floor(&self, depth: u32) -> usize {
+        self.rooms.iter().filter(|room| room.depth == depth).count()
+    }
+
+    pub fn deepest_depth(&self) -> u32 {
+        self.rooms
+            .iter()
+            .map(|room| room.depth)
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Finds the fewest exits to go through to get from one room to another,
+    /// in the order they need to be taken. Only exit maps that `can_use`
+    /// allows are followed.
+    pub fn shortest_path<F>(
+        &self,
+        from_room_id: Uuid,
+        to_room_id: Uuid,
+        can_use: F,
+    ) -> Option<Vec<Uuid>>
+    where
+        F: Fn(&ExitMap) -> bool,
+    {
+        let came_from = self.search(from_room_id, Some(to_room_id), can_use);
+        if to_room_id.ne(&from_room_id) && !came_from.contains_key(&to_room_id) {
+            return None;
+        }
+
+        let mut path: Vec<Uuid> = Vec::new();
+        let mut current = to_room_id;
+        while let Some((previous, exit_id)) = came_from.get(&current) {
+            path.push(*exit_id);
+            current = *previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every room that can be reached from this one, not counting itself,
+    /// following only exit maps that `can_use` allows.
+    pub fn reachable_rooms<F>(&self, from_room_id: Uuid, can_use: F) -> HashSet<Uuid>
+    where
+        F: Fn(&ExitMap) -> bool,
+    {
+        self.search(from_room_id, None, can_use)
+            .into_keys()
+            .collect()
+    }
+
+    /// Breadth first search out from a room, stopping early once `to_room_id`
+    /// is found. Maps each room reached to the room and exit it was reached
+    /// from.
+    fn search<F>(
+        &self,
+        from_room_id: Uuid,
+        to_room_id: Option<Uuid>,
+        can_use: F,
+    ) -> HashMap<Uuid, (Uuid, Uuid)>
+    where
+        F: Fn(&ExitMap) -> bool,
+    {
+        let mut came_from: HashMap<Uuid, (Uuid, Uuid)> = HashMap::new();
+        let mut queue: VecDeque<Uuid> = VecDeque::from([from_room_id]);
+
+        while let Some(room_id) = queue.pop_front() {
+            if to_room_id.eq(&Some(room_id)) {
+                break;
+            }
+
+            for exit_map in self.exit_graph.iter().filter(|exit_map| {
+                (exit_map.left_room_id.eq(&Some(room_id))
+                    || exit_map.right_room_id.eq(&Some(room_id)))
+                    && can_use(exit_map)
+            }) {
+                let next_room_id = match exit_map.other_room_id(room_id) {
+                    Some(it) => it,
+                    None => continue,
+                };
+
+                if next_room_id.eq(&from_room_id) || came_from.contains_key(&next_room_id) {
+                    continue;
+                }
+
+                came_from.insert(next_room_id, (room_id, exit_map.exit_id));
+                queue.push_back(next_room_id);
+            }
+        }
+
+        came_from
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -63,3 +234,60 @@ impl ExitMap {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::{ExitMap, World};
+
+    fn exit_map(left_room_id: Uuid, right_room_id: Option<Uuid>) -> ExitMap {
+        ExitMap {
+            exit_id: Uuid::new_v4(),
+            left_room_id: Some(left_room_id),
+            right_room_id,
+        }
+    }
+
+    #[test]
+    fn shortest_path_takes_the_fewest_exits() {
+        let rooms: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+        // 0 - 1 - 2 - 3, with a shortcut from 0 straight to 2 and an exit out
+        // of 3 that leads nowhere yet.
+        let exit_graph = vec![
+            exit_map(rooms[0], Some(rooms[1])),
+            exit_map(rooms[1], Some(rooms[2])),
+            exit_map(rooms[2], Some(rooms[3])),
+            exit_map(rooms[0], Some(rooms[2])),
+            exit_map(rooms[3], None),
+        ];
+        let shortcut_id = exit_graph[3].exit_id;
+        let last_id = exit_graph[2].exit_id;
+        let world = World {
+            rooms: Vec::new(),
+            exit_graph,
+        };
+
+        assert_eq!(
+            world.shortest_path(rooms[0], rooms[3], |_| true),
+            Some(vec![shortcut_id, last_id])
+        );
+        assert_eq!(
+            world.shortest_path(rooms[0], rooms[0], |_| true),
+            Some(Vec::new())
+        );
+        assert_eq!(
+            world
+                .shortest_path(rooms[0], rooms[3], |exit_map| exit_map
+                    .exit_id
+                    .ne(&shortcut_id))
+                .map(|path| path.len()),
+            Some(3)
+        );
+        assert_eq!(
+            world.shortest_path(rooms[0], rooms[3], |exit_map| exit_map.exit_id.ne(&last_id)),
+            None
+        );
+        assert_eq!(world.reachable_rooms(rooms[0], |_| true).len(), 3);
+    }
+}
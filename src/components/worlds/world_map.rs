@@ -0,0 +1,123 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "openapi")]
+use poem_openapi::Object;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+use crate::components::rooms::RoomType;
+
+/// The parts of the world the player knows about, laid out as a graph of rooms
+/// and the exits between them.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct WorldMap {
+    pub current_room_id: String,
+    pub rooms: Vec<MapRoom>,
+    pub connections: Vec<MapConnection>,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct MapRoom {
+    pub id: String,
+    pub name: Option<String>,
+    pub room_type: RoomType,
+    pub depth: u32,
+    pub is_current: bool,
+    /// The player has been inside the room.
+    pub visited: bool,
+    /// The player has been inside the room, or in a room next to it.
+    pub seen: bool,
+}
+
+/// An exit between two rooms. An exit that hasn't been gone through yet only
+/// has the room it was seen from.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(
+    feature = "serialization",
+    derive(Deserialize, Serialize),
+    serde(rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "openapi", derive(Object))]
+pub struct MapConnection {
+    pub exit_id: String,
+    pub from_room_id: String,
+    pub to_room_id: Option<String>,
+}
+
+impl MapConnection {
+    pub fn is_unexplored(&self) -> bool {
+        self.to_room_id.is_none()
+    }
+}
+
+impl WorldMap {
+    pub fn unexplored_exits(&self) -> Vec<&MapConnection> {
+        self.connections
+            .iter()
+            .filter(|connection| connection.is_unexplored())
+            .collect()
+    }
+
+    /// Writes the map out as an undirected Graphviz graph. Unexplored exits are
+    /// drawn as small points hanging off their room.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("graph world {\n");
+
+        for room in self.rooms.iter() {
+            let label = match &room.name {
+                Some(name) => format!("{} ({:?}, depth {})", name, room.room_type, room.depth),
+                None => format!("{:?} (depth {})", room.room_type, room.depth),
+            };
+            let style = if room.is_current {
+                ", style=filled, fillcolor=gold"
+            } else if room.visited {
+                ""
+            } else {
+                ", style=dashed"
+            };
+            dot.push_str(&format!(
+                "    \"{}\" [label=\"{}\"{}];\n",
+                room.id, label, style
+            ));
+        }
+
+        for connection in self.connections.iter() {
+            match &connection.to_room_id {
+                Some(to_room_id) => dot.push_str(&format!(
+                    "    \"{}\" -- \"{}\" [id=\"{}\"];\n",
+                    connection.from_room_id, to_room_id, connection.exit_id
+                )),
+                None => {
+                    dot.push_str(&format!("    \"{}\" [shape=point];\n", connection.exit_id));
+                    dot.push_str(&format!(
+                        "    \"{}\" -- \"{}\" [style=dotted];\n",
+                        connection.from_room_id, connection.exit_id
+                    ));
+                }
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+}
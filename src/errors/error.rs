@@ -1,18 +1,45 @@
 #[derive(Debug, thiserror::Error, strum_macros::Display)]
 pub enum Error {
+    ExitHasNoTrap(String),
+    ExitHasTrapUnknown(String),
+    ExitLocked(String),
     ExitNotFoundError(String),
     FixtureNotFoundError(String),
     FixtureCannotBeFound(String),
     FixtureCannotBeOpened(String),
     FixtureHasNoHiddenCompartment(String),
     FixtureHasHiddenCompartmentUnknown(String),
+    FixtureHasNoTrap(String),
+    FixtureHasTrapUnknown(String),
+    FixtureCannotRepairItem(String),
+    FixtureInteractionNotPossible(String),
+    FixtureLocked(String),
+    FixtureNotOpen(String),
+    InvalidDiceError(String),
     InvalidIdError(String),
+    ItemIsCursed(String),
+    ItemNotDamagedError(String),
     ItemNotDirectlyUsableError(String),
     ItemNotFoundError(String),
+    ItemNotKeyError(String),
+    ItemNotRepairableError(String),
+    ItemNotRepairKitError(String),
     ItemNotThrowableError(String),
+    KeyDoesNotFitError(String),
+    LockCannotBePickedError(String),
+    NoLockError,
+    NoMatchingRecipeError,
+    NoTrapError,
+    NoPathToRoomError(String),
     NpcNotFoundError(String),
+    PerkNotAvailableError,
     PlayerIsDeadError,
+    RepairToolNeededError,
+    RestNotPossibleError,
+    RunCompleteError,
+    SpellHasNoUsesError(String),
     SpellNotFoundError(String),
+    TooHeavyToCarryError(String),
     TooManyWeaponsEquippedError,
     TooManyWearablesEquippedError,
 }
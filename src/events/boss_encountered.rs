@@ -0,0 +1,15 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::components::BossKind;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct BossEncountered {
+    pub boss: BossKind,
+    pub npc_id: Uuid,
+}
@@ -5,10 +5,11 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::components::{
-    games::GameState,
-    items::{CharacterItem, LocationTag},
+    games::{GameState, ItemKnowledge},
+    items::{CharacterItem, FixtureItem, LocationTag},
+    rooms::{NpcPositionDescriptor, PuzzleReward},
     spells::LearnedSpell,
-    PlayerCharacter, Poison,
+    Bleeding, BossPhase, Character, PlayerCharacter, Poison,
 };
 
 use super::NpcDamagedByPoison;
@@ -21,29 +22,62 @@ use super::NpcDamagedByPoison;
     serde(rename_all = "snake_case", tag = "event_type")
 )]
 pub enum Event {
+    ArtifactSpawned(super::ArtifactSpawned),
+    BossDefeated(super::BossDefeated),
+    BossEncountered(super::BossEncountered),
+    BossEnraged(super::BossEnraged),
     GameDangerLevelIncreased(u32),
     DeadNpcBeaten(super::DeadNpcBeaten),
+    ExitDiscovered(super::ExitDiscovered),
+    ExitHasTrapDiscovered(super::ExitHasTrapDiscovered),
+    ExitUnlocked(super::ExitUnlocked),
     FixtureHasHiddenCompartmentDiscovered(super::FixtureHasHiddenCompartmentDiscovered),
+    FixtureHasTrapDiscovered(super::FixtureHasTrapDiscovered),
     FixtureHiddenCompartmentOpened(super::FixtureHiddenCompartmentOpened),
     FixtureOpened(super::FixtureOpened),
+    FixtureUnlocked(super::FixtureUnlocked),
     FixtureViewed(super::FixtureViewed),
+    FloorReached(super::FloorReached),
+    ItemIdentified(super::ItemIdentified),
     ItemTakenFromFixture(super::ItemTakenFromFixture),
     ItemTakenFromNpc(super::ItemTakenFromNpc),
+    LockPickFailed(super::LockPickFailed),
+    NpcBleeding(super::NpcBleeding),
     NpcCoveredInOil(Uuid),
+    NpcDamagedByBleeding(super::NpcDamagedByBleeding),
     NpcDamagedByPoison(NpcDamagedByPoison),
+    NpcDroppedWeapon(super::NpcDroppedWeapon),
+    NpcEnteredRoom(super::NpcEnteredRoom),
+    NpcHealed(super::NpcHealed),
     NpcHealthDiscovered(super::NpcHealthDiscovered),
     NpcHiddenDiscovered(super::NpcHiddenDiscovered),
     NpcHitWithAcid(Uuid),
+    NpcHurtSelf(super::NpcHurtSelf),
+    NpcItemBroken(super::NpcItemBroken),
     NpcItemDestroyed(super::NpcItemDestroyed),
+    NpcItemWorn(super::NpcItemWorn),
+    NpcLeftRoom(super::NpcLeftRoom),
     NpcMissed(super::NpcMissed),
     NpcPackedDiscovered(super::NpcPackedDiscovered),
     NpcPoisonDurationChanged(super::NpcPoisonEffectDurationChanged),
     NpcPoisonEffectDissipated(super::NpcPoisonEffectDissipated),
     NpcPoisonLevelChanged(super::NpcPoisonLevelChanged),
     NpcPoisoned(super::NpcPoisoned),
+    NpcStunned(Uuid),
+    NpcStunRecovered(Uuid),
     NpcViewed(super::NpcViewed),
     NpcWeaponReadied(super::NpcWeaponReadied),
+    PlayerAmbushed(super::PlayerAmbushed),
+    PlayerAttributeIncreased(super::PlayerAttributeIncreased),
+    PlayerBleeding(super::PlayerBleeding),
+    PlayerCriticallyHit(super::PlayerCriticallyHit),
+    PlayerCriticallyHitNpc(super::PlayerCriticallyHitNpc),
+    PlayerDamagedByBleeding(i32),
+    PlayerDamagedByCurse(i32),
     PlayerDamagedByPoison(i32),
+    PlayerDroppedWeapon(super::PlayerDroppedWeapon),
+    PlayerFleeFailed(super::PlayerFleeFailed),
+    PlayerGainedExperience(u32),
     PlayerGainedGold(u32),
     PlayerGainsResurrectionAura,
     PlayerGainsRetributionAura(super::PlayerGainsRetributionAura),
@@ -54,13 +88,23 @@ pub enum Event {
     PlayerHitNpc(super::PlayerHitNpc),
     PlayerItemMoved(super::PlayerItemMoved),
     PlayerHitWithAcid,
+    PlayerHurtSelf(i32),
+    PlayerItemBroken(Uuid),
+    PlayerItemCurseLifted(Uuid),
+    PlayerItemCurseRevealed(super::PlayerItemCurseRevealed),
     PlayerItemDestroyed(Uuid),
     PlayerItemRemoved(super::PlayerItemRemoved),
+    PlayerItemRepaired(super::PlayerItemRepaired),
+    PlayerItemSplit(super::PlayerItemSplit),
     PlayerItemUsed(super::PlayerItemUsed),
+    PlayerItemWorn(super::PlayerItemWorn),
+    PlayerItemsCombined(super::PlayerItemsCombined),
     PlayerKilled(super::PlayerKilled),
     PlayerKilledNpc(super::PlayerKilledNpc),
+    PlayerLeveledUp(super::PlayerLeveledUp),
     PlayerMaxHealthChanged(i32),
     PlayerMissed(super::PlayerMissed),
+    PlayerPerkChosen(super::PlayerPerkChosen),
     PlayerPoisonLevelChanged(i32),
     PlayerPoisonDissipated,
     PlayerPoisoned(super::PlayerPoisoned),
@@ -69,12 +113,20 @@ pub enum Event {
     PlayerRetributionAuraDissipated,
     PlayerShieldAuraDamaged(i32),
     PlayerShieldAuraDissipated,
-    PlayerSpellForgotten(super::PlayerSpellForgotten),
     PlayerSpellLearned(super::PlayerSpellLearned),
+    PlayerRested(super::PlayerRested),
     PlayerSpellUsed(super::PlayerSpellUsed),
+    PlayerSpellUsesRestored(super::PlayerSpellUsesRestored),
+    PlayerStunned,
+    PlayerStunRecovered,
+    PuzzlePieceMoved(super::PuzzlePieceMoved),
+    PuzzleSolved(super::PuzzleSolved),
     RoomExited(super::RoomExited),
     RoomGenerated(super::RoomGenerated),
     RoomFirstSeen(super::RoomFirstSeen),
+    TrapDisarmed(super::TrapDisarmed),
+    TrapSprung(super::TrapSprung),
+    WorldTurnPassed,
 }
 
 pub fn apply_events(
@@ -90,6 +142,30 @@ pub fn apply_events(
             Event::RoomExited(room_exited) => {
                 new_game.current_room_id = room_exited.new_room_id;
             }
+            Event::ItemIdentified(item_identified) => {
+                new_game.set_item_knowledge(
+                    item_identified.item_id,
+                    ItemKnowledge { identified: true },
+                );
+            }
+            Event::ArtifactSpawned(artifact_spawned) => new_game
+                .spawned_artifacts
+                .push(artifact_spawned.artifact.clone()),
+            Event::BossEncountered(boss_encountered) => new_game
+                .encountered_bosses
+                .push(boss_encountered.boss.clone()),
+            Event::BossEnraged(boss_enraged) => {
+                if let Some(boss) = new_game
+                    .world
+                    .find_npc_mut(&boss_enraged.npc_id)
+                    .and_then(|position| position.npc.boss.as_mut())
+                {
+                    boss.phase = BossPhase::Enraged;
+                }
+            }
+            Event::BossDefeated(boss_defeated) => {
+                new_game.defeated_bosses.push(boss_defeated.boss.clone())
+            }
             Event::RoomGenerated(room_generated) => new_game
                 .world
                 .add_room(room_generated.entrance_id, room_generated.room.clone()),
@@ -100,8 +176,7 @@ pub fn apply_events(
                 new_game.add_player_damage_done_to_stats(&npc_hit.attacker_id, npc_hit.damage);
             }
             Event::PlayerKilledNpc(npc_killed) => {
-                let room = new_game.current_room_mut();
-                if let Some(position) = room.find_npc_mut(&npc_killed.npc_id) {
+                if let Some(position) = new_game.world.find_npc_mut(&npc_killed.npc_id) {
                     position.npc.character.kill();
                     position.position_descriptor = None;
                 }
@@ -152,6 +227,38 @@ pub fn apply_events(
                 knowledge.knows_has_hidden_compartment = true;
                 new_game.set_fixture_knowledge(has_hidden.fixture_id, knowledge);
             }
+            Event::PuzzlePieceMoved(moved) => {
+                if let Some(piece) = new_game
+                    .current_room_mut()
+                    .puzzle
+                    .as_mut()
+                    .and_then(|puzzle| puzzle.find_piece_mut(&moved.fixture_id))
+                {
+                    piece.position = moved.position;
+                }
+            }
+            Event::PuzzleSolved(_) => solve_puzzle(&mut new_game),
+            Event::ExitDiscovered(discovered) => {
+                let mut knowledge = new_game.exit_knowledge(&discovered.exit_id);
+                knowledge.discovered = true;
+                new_game.set_exit_knowledge(discovered.exit_id, knowledge);
+            }
+            Event::ExitHasTrapDiscovered(has_trap) => {
+                let mut knowledge = new_game.exit_knowledge(&has_trap.exit_id);
+                knowledge.knows_has_trap = true;
+                new_game.set_exit_knowledge(has_trap.exit_id, knowledge);
+            }
+            Event::FixtureHasTrapDiscovered(has_trap) => {
+                let mut knowledge = new_game.fixture_knowledge(&has_trap.fixture_id);
+                knowledge.knows_has_trap = true;
+                new_game.set_fixture_knowledge(has_trap.fixture_id, knowledge);
+            }
+            Event::TrapDisarmed(disarmed) => {
+                remove_trap(&mut new_game, &disarmed.fixture_id, &disarmed.exit_id)
+            }
+            Event::TrapSprung(sprung) => {
+                remove_trap(&mut new_game, &sprung.fixture_id, &sprung.exit_id)
+            }
             Event::RoomFirstSeen(first_seen) => {
                 new_game.rooms_seen.push(first_seen.room_id);
             }
@@ -183,11 +290,6 @@ pub fn apply_events(
             Event::PlayerRetributionAuraDissipated => {
                 new_player.character.current_effects.retribution_aura = None;
             }
-            Event::PlayerSpellForgotten(player_spell_forgotten) => {
-                new_player
-                    .character
-                    .forget_spell(&player_spell_forgotten.spell_id);
-            }
             Event::PlayerSpellUsed(player_spell_used) => {
                 if let Some(learned_spell) = new_player
                     .character
@@ -196,6 +298,24 @@ pub fn apply_events(
                     learned_spell.spell.uses -= 1;
                 }
             }
+            Event::PlayerSpellUsesRestored(uses_restored) => {
+                if let Some(learned_spell) =
+                    new_player.character.find_spell_mut(&uses_restored.spell_id)
+                {
+                    learned_spell.spell.uses += uses_restored.uses;
+                }
+            }
+            Event::PlayerAmbushed(player_ambushed) => {
+                if let Some(room) = new_game
+                    .world
+                    .rooms
+                    .iter_mut()
+                    .find(|room| room.id.eq(&player_ambushed.room_id))
+                {
+                    room.npc_positions
+                        .push(player_ambushed.npc_position.clone());
+                }
+            }
             Event::PlayerItemRemoved(player_item_removed) => {
                 new_player
                     .character
@@ -204,11 +324,58 @@ pub fn apply_events(
             Event::PlayerItemDestroyed(item_id) => {
                 new_player.character.remove_item(item_id);
             }
+            Event::PlayerItemWorn(player_item_worn) => {
+                if let Some(character_item) = new_player
+                    .character
+                    .find_item_mut(&player_item_worn.item_id)
+                {
+                    character_item.item.wear(player_item_worn.amount);
+                }
+            }
+            Event::PlayerItemSplit(player_item_split) => {
+                new_player.character.inventory.split_item(
+                    &player_item_split.item_id,
+                    &player_item_split.new_item_id,
+                    player_item_split.quantity,
+                );
+                let knowledge = new_game.item_knowledge(&player_item_split.item_id);
+                new_game.set_item_knowledge(player_item_split.new_item_id, knowledge);
+            }
+            Event::PlayerItemsCombined(items_combined) => {
+                for item_id in items_combined.consumed_item_ids.iter() {
+                    new_player.character.remove_item(item_id);
+                }
+                new_player
+                    .character
+                    .add_item(items_combined.crafted_item.clone());
+            }
+            Event::PlayerItemCurseRevealed(curse_revealed) => {
+                if let Some(character_item) =
+                    new_player.character.find_item_mut(&curse_revealed.item_id)
+                {
+                    character_item.item.curse_revealed = true;
+                }
+            }
+            Event::PlayerItemCurseLifted(item_id) => {
+                if let Some(character_item) = new_player.character.find_item_mut(item_id) {
+                    character_item.item.curse = None;
+                    character_item.item.curse_revealed = false;
+                }
+            }
+            Event::PlayerItemRepaired(player_item_repaired) => {
+                if let Some(character_item) = new_player
+                    .character
+                    .find_item_mut(&player_item_repaired.item_id)
+                {
+                    character_item.item.repair(player_item_repaired.amount);
+                }
+            }
             Event::PlayerSpellLearned(player_spell_learned) => {
                 new_player.character.spell_memory.add_spell(LearnedSpell {
                     id: player_spell_learned.spell_id,
                     spell: player_spell_learned.spell.clone(),
                     learned_at: player_spell_learned.learned_at,
+                    learned_uses: player_spell_learned.spell.uses,
                 });
             }
             Event::PlayerItemUsed(player_item_used) => {
@@ -221,21 +388,48 @@ pub fn apply_events(
                 new_player.character.add_item(character_item);
             }
             Event::FixtureOpened(opened) => open_fixture(&mut new_game, &opened.fixture_id),
+            Event::FixtureUnlocked(unlocked) => {
+                if let Some(fixture_position) = new_game
+                    .current_room_mut()
+                    .find_fixture_mut(&unlocked.fixture_id)
+                {
+                    if let Some(lock) = fixture_position.fixture.lock.as_mut() {
+                        lock.locked = false;
+                    }
+                }
+            }
+            Event::ExitUnlocked(unlocked) => {
+                for exit in new_game.world.exit_sides_mut(unlocked.exit_id) {
+                    if let Some(lock) = exit.lock.as_mut() {
+                        lock.locked = false;
+                    }
+                }
+            }
             Event::FixtureHiddenCompartmentOpened(opened) => {
                 open_fixture_hidden_compartment(&mut new_game, &opened.fixture_id)
             }
             Event::PlayerMaxHealthChanged(change) => {
                 new_player.character.increase_max_health(*change);
             }
+            Event::PlayerAttributeIncreased(attribute_increased) => {
+                new_player
+                    .character
+                    .stats
+                    .attributes
+                    .increase(&attribute_increased.attribute);
+            }
             Event::GameDangerLevelIncreased(level) => new_game.danger_level += level,
             Event::NpcDamagedByPoison(poison_damage) => {
-                if let Some(position) = new_game
-                    .current_room_mut()
-                    .find_npc_mut(&poison_damage.npc_id)
-                {
+                if let Some(position) = new_game.world.find_npc_mut(&poison_damage.npc_id) {
                     position.npc.character.damage(poison_damage.damage);
+                    if position.npc.character.is_dead() {
+                        position.position_descriptor = None;
+                    }
                 }
             }
+            Event::PlayerDamagedByCurse(damage) => {
+                new_player.character.damage(*damage);
+            }
             Event::PlayerDamagedByPoison(damage) => {
                 new_player.character.damage(*damage);
             }
@@ -245,10 +439,7 @@ pub fn apply_events(
                 }
             }
             Event::NpcPoisonDurationChanged(poison_change) => {
-                if let Some(position) = new_game
-                    .current_room_mut()
-                    .find_npc_mut(&poison_change.npc_id)
-                {
+                if let Some(position) = new_game.world.find_npc_mut(&poison_change.npc_id) {
                     if let Some(poison) = position.npc.character.current_effects.poison.as_mut() {
                         poison.duration += poison_change.duration;
                     }
@@ -286,6 +477,22 @@ pub fn apply_events(
             Event::PlayerGainedGold(gold) => {
                 new_player.gold += gold;
             }
+            Event::PlayerGainedExperience(experience) => {
+                new_player.progression.experience += experience;
+            }
+            Event::PlayerLeveledUp(leveled_up) => {
+                new_player.progression.level = leveled_up.level;
+                // Once every perk is maxed there is nothing left to choose.
+                if !leveled_up.perk_choices.is_empty() {
+                    new_player
+                        .progression
+                        .pending_perk_choices
+                        .push(leveled_up.perk_choices.clone());
+                }
+            }
+            Event::PlayerPerkChosen(perk_chosen) => {
+                new_player.progression.choose_perk(&perk_chosen.perk);
+            }
             Event::PlayerShieldAuraDamaged(damage) => {
                 if let Some(shield_aura) = new_player.character.current_effects.shield_aura.as_mut()
                 {
@@ -296,10 +503,7 @@ pub fn apply_events(
                 new_player.character.current_effects.shield_aura = None
             }
             Event::NpcPoisonEffectDissipated(effect_dissipated) => {
-                if let Some(position) = new_game
-                    .current_room_mut()
-                    .find_npc_mut(&effect_dissipated.npc_id)
-                {
+                if let Some(position) = new_game.world.find_npc_mut(&effect_dissipated.npc_id) {
                     position.npc.character.current_effects.poison = None;
                 }
             }
@@ -317,17 +521,133 @@ pub fn apply_events(
                         .remove_item(&npc_item_destroyed.item_id);
                 }
             }
+            Event::NpcItemWorn(npc_item_worn) => {
+                if let Some(character_item) = new_game
+                    .current_room_mut()
+                    .find_npc_mut(&npc_item_worn.npc_id)
+                    .and_then(|position| {
+                        position.npc.character.find_item_mut(&npc_item_worn.item_id)
+                    })
+                {
+                    character_item.item.wear(npc_item_worn.amount);
+                }
+            }
             Event::NpcCoveredInOil(npc_id) => {
                 if let Some(position) = new_game.current_room_mut().find_npc_mut(npc_id) {
                     position.npc.character.current_effects.covered_in_oil = true;
                 }
             }
+            Event::NpcBleeding(bleeding) => {
+                if let Some(position) = new_game.current_room_mut().find_npc_mut(&bleeding.npc_id) {
+                    position.npc.character.current_effects.bleeding = Some(Bleeding {
+                        damage: bleeding.damage,
+                        duration: bleeding.duration,
+                    });
+                }
+            }
+            Event::NpcDamagedByBleeding(bleeding_damage) => {
+                if let Some(position) = new_game.world.find_npc_mut(&bleeding_damage.npc_id) {
+                    position.npc.character.damage(bleeding_damage.damage);
+                    tick_bleeding(&mut position.npc.character.current_effects.bleeding);
+                    if position.npc.character.is_dead() {
+                        position.position_descriptor = None;
+                    }
+                }
+            }
+            Event::NpcHurtSelf(hurt_self) => {
+                if let Some(position) = new_game.current_room_mut().find_npc_mut(&hurt_self.npc_id)
+                {
+                    position.npc.character.damage(hurt_self.damage);
+                }
+            }
+            Event::NpcDroppedWeapon(dropped_weapon) => {
+                if let Some(position) = new_game
+                    .current_room_mut()
+                    .find_npc_mut(&dropped_weapon.npc_id)
+                {
+                    drop_weapon(&mut position.npc.character, &dropped_weapon.item_id);
+                }
+            }
+            Event::NpcHealed(npc_healed) => {
+                if let Some(position) = new_game.world.find_npc_mut(&npc_healed.npc_id) {
+                    position.npc.character.heal(npc_healed.amount);
+                }
+            }
+            Event::WorldTurnPassed => {
+                new_game.turn += 1;
+                let turn = new_game.turn;
+                new_game
+                    .world
+                    .rooms
+                    .iter_mut()
+                    .flat_map(|room| room.npc_positions.iter_mut())
+                    .map(|npc_position| &mut npc_position.npc)
+                    .filter(|npc| npc.character.is_dead() && npc.died_on_turn.is_none())
+                    .for_each(|npc| npc.died_on_turn = Some(turn));
+            }
+            Event::NpcLeftRoom(npc_left_room) => {
+                let to_room_id = new_game
+                    .world
+                    .exit_graph
+                    .iter()
+                    .find(|exit_map| exit_map.exit_id.eq(&npc_left_room.exit_id))
+                    .and_then(|exit_map| exit_map.other_room_id(npc_left_room.room_id));
+                if let Some(to_room_id) = to_room_id {
+                    new_game.world.move_npc(&npc_left_room.npc_id, &to_room_id);
+                }
+            }
+            Event::NpcEnteredRoom(npc_entered_room) => {
+                let is_current_room = npc_entered_room.room_id.eq(&new_game.current_room_id);
+                if let Some(position) = new_game.world.find_npc_mut(&npc_entered_room.npc_id) {
+                    position.position_descriptor = if is_current_room {
+                        Some(NpcPositionDescriptor::IsGlaringAtYou)
+                    } else {
+                        Some(NpcPositionDescriptor::IsStandingAround)
+                    };
+                }
+            }
+            Event::NpcStunned(npc_id) => {
+                if let Some(position) = new_game.current_room_mut().find_npc_mut(npc_id) {
+                    position.npc.character.current_effects.stunned = true;
+                }
+            }
+            Event::NpcStunRecovered(npc_id) => {
+                if let Some(position) = new_game.world.find_npc_mut(npc_id) {
+                    position.npc.character.current_effects.stunned = false;
+                }
+            }
+            Event::PlayerBleeding(bleeding) => {
+                new_player.character.current_effects.bleeding = Some(Bleeding {
+                    damage: bleeding.damage,
+                    duration: bleeding.duration,
+                });
+            }
+            Event::PlayerDamagedByBleeding(damage) => {
+                new_player.character.damage(*damage);
+                tick_bleeding(&mut new_player.character.current_effects.bleeding);
+            }
+            Event::PlayerHurtSelf(damage) => {
+                new_player.character.damage(*damage);
+            }
+            Event::PlayerDroppedWeapon(dropped_weapon) => {
+                drop_weapon(&mut new_player.character, &dropped_weapon.item_id);
+            }
+            Event::PlayerStunned => new_player.character.current_effects.stunned = true,
+            Event::PlayerStunRecovered => new_player.character.current_effects.stunned = false,
             Event::NpcMissed(_)
             | Event::DeadNpcBeaten(_)
+            | Event::PlayerCriticallyHit(_)
+            | Event::PlayerCriticallyHitNpc(_)
             | Event::PlayerMissed(_)
             | Event::NpcViewed(_)
             | Event::FixtureViewed(_)
             | Event::NpcHitWithAcid(_)
+            | Event::NpcItemBroken(_)
+            | Event::PlayerItemBroken(_)
+            | Event::FloorReached(_)
+            | Event::PlayerFleeFailed(_)
+            | Event::PlayerRested(_)
+            | Event::LockPickFailed(_)
             | Event::PlayerHitWithAcid => {}
         }
     }
@@ -335,6 +655,41 @@ pub fn apply_events(
     (new_game, new_player)
 }
 
+fn solve_puzzle(new_game: &mut GameState) {
+    let room = new_game.current_room_mut();
+    let puzzle = match room.puzzle.as_mut() {
+        Some(it) => it,
+        None => return,
+    };
+    puzzle.solved = true;
+
+    if let PuzzleReward::Item(item) = &puzzle.reward {
+        let item = item.as_ref().clone();
+        let first_piece_id = puzzle.pieces.first().map(|piece| piece.fixture_id);
+        if let Some(fixture_position) = first_piece_id.and_then(|id| room.find_fixture_mut(&id)) {
+            fixture_position.fixture.items.push(FixtureItem {
+                item,
+                is_inside: false,
+                is_in_hidden_compartment: false,
+            });
+        }
+    }
+}
+
+fn remove_trap(new_game: &mut GameState, fixture_id: &Option<Uuid>, exit_id: &Option<Uuid>) {
+    if let Some(fixture_position) =
+        fixture_id.and_then(|id| new_game.current_room_mut().find_fixture_mut(&id))
+    {
+        fixture_position.fixture.trap = None;
+    }
+
+    if let Some(exit_id) = exit_id {
+        for exit in new_game.world.exit_sides_mut(*exit_id) {
+            exit.trap = None;
+        }
+    }
+}
+
 fn open_fixture(new_game: &mut GameState, fixture_id: &Uuid) {
     let fixture_position = new_game
         .current_room_mut()
@@ -369,6 +724,7 @@ fn take_item_from_fixture(
         is_multiple: false,
         item: fixture_item.item,
         at_the_ready: false,
+        quantity: 1,
     };
     new_player.character.add_item(packed_item)
 }
@@ -388,6 +744,7 @@ fn take_item_from_npc(
         is_multiple: character_item.is_multiple,
         item: character_item.item,
         at_the_ready: false,
+        quantity: character_item.quantity,
     };
     new_player.character.add_item(packed_item);
 }
@@ -399,3 +756,21 @@ fn ready_npc_weapon(new_game: &mut GameState, npc_id: &Uuid, item_id: &Uuid) {
     character_item.equipped_location = LocationTag::Hand;
     position.npc.character.add_item(character_item);
 }
+
+fn tick_bleeding(bleeding: &mut Option<Bleeding>) {
+    if let Some(it) = bleeding.as_mut() {
+        it.duration -= 1;
+        if it.duration <= 0 {
+            *bleeding = None;
+        }
+    }
+}
+
+/// A dropped weapon is scooped up and stowed, so it has to be readied again.
+fn drop_weapon(character: &mut Character, item_id: &Uuid) {
+    if let Some(mut character_item) = character.remove_item(item_id) {
+        character_item.at_the_ready = false;
+        character_item.equipped_location = LocationTag::Packed;
+        character.add_item(character_item);
+    }
+}
@@ -0,0 +1,14 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+
+/// The player has gone deeper into the underworld than ever before.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct FloorReached {
+    pub depth: u32,
+    /// The final floor is home to the last boss, beating it ends the run.
+    pub is_final: bool,
+}
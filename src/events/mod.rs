@@ -1,15 +1,36 @@
+mod artifact_spawned;
+mod boss_defeated;
+mod boss_encountered;
+mod boss_enraged;
 mod dead_npc_beaten;
 mod event;
+mod exit_discovered;
+mod exit_has_trap_discovered;
+mod exit_unlocked;
 mod fixture_has_hidden_compartment_discovered;
+mod fixture_has_trap_discovered;
 mod fixture_hidden_compartment_opened;
 mod fixture_opened;
+mod fixture_unlocked;
 mod fixture_viewed;
+mod floor_reached;
+mod item_identified;
 mod item_taken_from_fixture;
 mod item_taken_from_npc;
+mod lock_pick_failed;
+mod npc_bleeding;
+mod npc_damaged_by_bleeding;
 mod npc_damaged_by_poison;
+mod npc_dropped_weapon;
+mod npc_entered_room;
+mod npc_healed;
 mod npc_health_discovered;
 mod npc_hidden_discovered;
+mod npc_hurt_self;
+mod npc_item_broken;
 mod npc_item_destroyed;
+mod npc_item_worn;
+mod npc_left_room;
 mod npc_missed;
 mod npc_packed_discovered;
 mod npc_poison_effect_dissipated;
@@ -18,44 +39,82 @@ mod npc_poison_level_changed;
 mod npc_poisoned;
 mod npc_viewed;
 mod npc_weapon_readied;
+mod player_ambushed;
+mod player_attribute_increased;
+mod player_bleeding;
+mod player_critically_hit;
+mod player_critically_hit_npc;
+mod player_dropped_weapon;
+mod player_flee_failed;
 mod player_gains_retribution_aura;
 mod player_gains_shield_aura;
 mod player_healed;
 mod player_hit;
 mod player_hit_npc;
+mod player_item_curse_revealed;
 mod player_item_moved;
 mod player_item_removed;
+mod player_item_repaired;
+mod player_item_split;
 mod player_item_used;
+mod player_item_worn;
+mod player_items_combined;
 mod player_killed;
 mod player_killed_npc;
+mod player_leveled_up;
 mod player_missed;
+mod player_perk_chosen;
 mod player_poisoned;
-mod player_spell_forgotten;
+mod player_rested;
 mod player_spell_learned;
 mod player_spell_used;
+mod player_spell_uses_restored;
+mod puzzle_piece_moved;
+mod puzzle_solved;
 mod room_exited;
 mod room_first_seen;
 mod room_generated;
+mod trap_disarmed;
+mod trap_sprung;
 
 pub use {
-    dead_npc_beaten::DeadNpcBeaten, event::apply_events, event::Event,
+    artifact_spawned::ArtifactSpawned, boss_defeated::BossDefeated,
+    boss_encountered::BossEncountered, boss_enraged::BossEnraged, dead_npc_beaten::DeadNpcBeaten,
+    event::apply_events, event::Event, exit_discovered::ExitDiscovered,
+    exit_has_trap_discovered::ExitHasTrapDiscovered, exit_unlocked::ExitUnlocked,
     fixture_has_hidden_compartment_discovered::FixtureHasHiddenCompartmentDiscovered,
+    fixture_has_trap_discovered::FixtureHasTrapDiscovered,
     fixture_hidden_compartment_opened::FixtureHiddenCompartmentOpened,
-    fixture_opened::FixtureOpened, fixture_viewed::FixtureViewed,
+    fixture_opened::FixtureOpened, fixture_unlocked::FixtureUnlocked,
+    fixture_viewed::FixtureViewed, floor_reached::FloorReached, item_identified::ItemIdentified,
     item_taken_from_fixture::ItemTakenFromFixture, item_taken_from_npc::ItemTakenFromNpc,
-    npc_damaged_by_poison::NpcDamagedByPoison, npc_health_discovered::NpcHealthDiscovered,
-    npc_hidden_discovered::NpcHiddenDiscovered, npc_item_destroyed::NpcItemDestroyed,
+    lock_pick_failed::LockPickFailed, npc_bleeding::NpcBleeding,
+    npc_damaged_by_bleeding::NpcDamagedByBleeding, npc_damaged_by_poison::NpcDamagedByPoison,
+    npc_dropped_weapon::NpcDroppedWeapon, npc_entered_room::NpcEnteredRoom, npc_healed::NpcHealed,
+    npc_health_discovered::NpcHealthDiscovered, npc_hidden_discovered::NpcHiddenDiscovered,
+    npc_hurt_self::NpcHurtSelf, npc_item_broken::NpcItemBroken,
+    npc_item_destroyed::NpcItemDestroyed, npc_item_worn::NpcItemWorn, npc_left_room::NpcLeftRoom,
     npc_missed::NpcMissed, npc_packed_discovered::NpcPackedDiscovered,
     npc_poison_effect_dissipated::NpcPoisonEffectDissipated,
     npc_poison_effect_duration_changed::NpcPoisonEffectDurationChanged,
     npc_poison_level_changed::NpcPoisonLevelChanged, npc_poisoned::NpcPoisoned,
-    npc_viewed::NpcViewed, npc_weapon_readied::NpcWeaponReadied,
+    npc_viewed::NpcViewed, npc_weapon_readied::NpcWeaponReadied, player_ambushed::PlayerAmbushed,
+    player_attribute_increased::PlayerAttributeIncreased, player_bleeding::PlayerBleeding,
+    player_critically_hit::PlayerCriticallyHit, player_critically_hit_npc::PlayerCriticallyHitNpc,
+    player_dropped_weapon::PlayerDroppedWeapon, player_flee_failed::PlayerFleeFailed,
     player_gains_retribution_aura::PlayerGainsRetributionAura,
     player_gains_shield_aura::PlayerGainsShieldAura, player_healed::PlayerHealed,
-    player_hit::PlayerHit, player_hit_npc::PlayerHitNpc, player_item_moved::PlayerItemMoved,
-    player_item_removed::PlayerItemRemoved, player_item_used::PlayerItemUsed,
-    player_killed::PlayerKilled, player_killed_npc::PlayerKilledNpc, player_missed::PlayerMissed,
-    player_poisoned::PlayerPoisoned, player_spell_forgotten::PlayerSpellForgotten,
-    player_spell_learned::PlayerSpellLearned, player_spell_used::PlayerSpellUsed,
-    room_exited::RoomExited, room_first_seen::RoomFirstSeen, room_generated::RoomGenerated,
+    player_hit::PlayerHit, player_hit_npc::PlayerHitNpc,
+    player_item_curse_revealed::PlayerItemCurseRevealed, player_item_moved::PlayerItemMoved,
+    player_item_removed::PlayerItemRemoved, player_item_repaired::PlayerItemRepaired,
+    player_item_split::PlayerItemSplit, player_item_used::PlayerItemUsed,
+    player_item_worn::PlayerItemWorn, player_items_combined::PlayerItemsCombined,
+    player_killed::PlayerKilled, player_killed_npc::PlayerKilledNpc,
+    player_leveled_up::PlayerLeveledUp, player_missed::PlayerMissed,
+    player_perk_chosen::PlayerPerkChosen, player_poisoned::PlayerPoisoned,
+    player_rested::PlayerRested, player_spell_learned::PlayerSpellLearned,
+    player_spell_used::PlayerSpellUsed, player_spell_uses_restored::PlayerSpellUsesRestored,
+    puzzle_piece_moved::PuzzlePieceMoved, puzzle_solved::PuzzleSolved, room_exited::RoomExited,
+    room_first_seen::RoomFirstSeen, room_generated::RoomGenerated, trap_disarmed::TrapDisarmed,
+    trap_sprung::TrapSprung,
 };
@@ -4,10 +4,14 @@ use bevy_ecs::prelude::Component;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::components::DamageCalculation;
+
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
 pub struct PlayerHit {
     pub attacker_id: Uuid,
     pub damage: i32,
+    #[cfg_attr(feature = "serialization", serde(default))]
+    pub calculation: Option<DamageCalculation>,
 }
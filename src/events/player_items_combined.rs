@@ -0,0 +1,16 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::components::items::{CharacterItem, RecipeName};
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct PlayerItemsCombined {
+    pub recipe: RecipeName,
+    pub consumed_item_ids: Vec<Uuid>,
+    pub crafted_item: CharacterItem,
+}
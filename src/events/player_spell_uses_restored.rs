@@ -7,6 +7,7 @@ use uuid::Uuid;
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "bevy_components", derive(Component))]
 #[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
-pub struct PlayerSpellForgotten {
+pub struct PlayerSpellUsesRestored {
     pub spell_id: Uuid,
+    pub uses: i32,
 }
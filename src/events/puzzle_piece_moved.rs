@@ -0,0 +1,16 @@
+#[cfg(feature = "bevy_components")]
+use bevy_ecs::prelude::Component;
+#[cfg(feature = "serialization")]
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::components::rooms::FixtureInteraction;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "bevy_components", derive(Component))]
+#[cfg_attr(feature = "serialization", derive(Deserialize, Serialize))]
+pub struct PuzzlePieceMoved {
+    pub fixture_id: Uuid,
+    pub interaction: FixtureInteraction,
+    pub position: u32,
+}
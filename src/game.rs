@@ -1,15 +1,17 @@
 use crate::{
     actions::{
-        Action, AttackNpc, CastSpellOnNpc, CastSpellOnPlayer, ExitRoom, InspectFixture, InspectNpc,
-        LookAtFixture, LookAtNpc, LootFixture, LootNpc, MovePlayerItem, OpenFixture,
-        OpenFixtureHiddenCompartment, UseItemOnPlayer,
+        Action, AttackNpc, CastSpellOnNpc, CastSpellOnPlayer, ChoosePerk, CombineItems, DisarmTrap,
+        ExitRoom, IdentifyItem, InspectFixture, InspectNpc, InteractWithFixture, LookAtFixture,
+        LookAtNpc, LootFixture, LootNpc, MovePlayerItem, OpenFixture, OpenFixtureHiddenCompartment,
+        PickLock, RepairPlayerItem, Rest, SearchRoom, TravelToRoom, UnlockWithKey, UseItemOnPlayer,
     },
     components::{
         games::GameState,
         items::{
-            ConsumableEffectName, {packed_tags_for_item_type, ready_tag_for_item_type},
+            ConsumableEffectName, Item, Recipe,
+            {packed_tags_for_item_type, ready_tag_for_item_type},
         },
-        PlayerCharacter,
+        Lock, PlayerCharacter,
     },
     errors::Error,
     events::Event,
@@ -34,7 +36,13 @@ impl Game {
         Ok(events)
     }
 
+    /// Once the final boss is beaten the run is over and there's nothing
+    /// left to do.
     pub fn current_actions(&self) -> Vec<Action> {
+        if self.state.final_boss_defeated() {
+            return Vec::new();
+        }
+
         let fixture_actions = self
             .state
             .current_room()
@@ -49,16 +57,44 @@ impl Game {
                     Action::InspectFixture(InspectFixture {
                         fixture_id: fixture.id.to_string(),
                         discover_hidden_compartment: true,
+                        discover_trap: true,
                     }),
                 ];
 
-                if fixture.can_be_opened && !fixture.open {
+                if let Some(lock) = fixture.lock.as_ref().filter(|lock| lock.locked) {
+                    actions.append(&mut self.unlock_actions(
+                        lock,
+                        Some(fixture.id.to_string()),
+                        None,
+                    ));
+                } else if fixture.can_be_opened && !fixture.open {
                     actions.push(Action::OpenFixture(OpenFixture {
                         fixture_id: fixture.id.to_string(),
                     }));
                 }
 
+                if let Some(piece) = self
+                    .state
+                    .current_room()
+                    .puzzle
+                    .as_ref()
+                    .filter(|puzzle| !puzzle.solved)
+                    .and_then(|puzzle| puzzle.find_piece(&fixture.id))
+                {
+                    actions.push(Action::InteractWithFixture(InteractWithFixture {
+                        fixture_id: fixture.id.to_string(),
+                        interaction: piece.interaction.clone(),
+                    }));
+                }
+
                 let knowledge = self.state.fixture_knowledge(&fixture.id);
+                if knowledge.knows_has_trap && fixture.trap.is_some() {
+                    actions.push(Action::DisarmTrap(DisarmTrap {
+                        fixture_id: Some(fixture.id.to_string()),
+                        exit_id: None,
+                    }));
+                }
+
                 if knowledge.knows_has_hidden_compartment
                     && !fixture.hidden_compartment_open
                     && fixture.has_hidden_compartment
@@ -70,15 +106,19 @@ impl Game {
                     ));
                 }
 
-                let mut item_ids: Vec<String> = Vec::new();
+                let mut items: Vec<&Item> = Vec::new();
 
-                if fixture.open {
+                if fixture.is_locked() {
+                    return actions;
+                }
+
+                if fixture.open || !fixture.can_be_opened {
                     for fixture_item in fixture
                         .items
                         .iter()
                         .filter(|fixture_item| fixture_item.is_inside)
                     {
-                        item_ids.push(fixture_item.item.id.to_string());
+                        items.push(&fixture_item.item);
                     }
                 }
 
@@ -88,16 +128,32 @@ impl Game {
                         .iter()
                         .filter(|fixture_item| fixture_item.is_in_hidden_compartment)
                     {
-                        item_ids.push(fixture_item.item.id.to_string());
+                        items.push(&fixture_item.item);
                     }
                 }
 
                 for fixture_item in fixture.items.iter().filter(|fixture_item| {
                     !fixture_item.is_inside && !fixture_item.is_in_hidden_compartment
                 }) {
-                    item_ids.push(fixture_item.item.id.to_string());
+                    items.push(&fixture_item.item);
                 }
 
+                let mut looted_weight: u32 = 0;
+                let item_ids: Vec<String> = items
+                    .into_iter()
+                    .filter(|item| {
+                        let fits = self
+                            .player
+                            .character
+                            .can_carry(looted_weight + item.weight());
+                        if fits {
+                            looted_weight += item.weight();
+                        }
+                        fits
+                    })
+                    .map(|item| item.id.to_string())
+                    .collect();
+
                 if !item_ids.is_empty() {
                     actions.push(Action::LootFixture(LootFixture {
                         fixture_id: fixture.id.to_string(),
@@ -132,18 +188,36 @@ impl Game {
                         npc_id: npc.id.to_string(),
                     }));
 
-                    for learned_spell in self.player.character.spell_memory.spells.iter() {
+                    for learned_spell in self
+                        .player
+                        .character
+                        .spell_memory
+                        .spells
+                        .iter()
+                        .filter(|learned_spell| learned_spell.spell.uses > 0)
+                    {
                         actions.push(Action::CastSpellOnNpc(CastSpellOnNpc {
                             spell_id: learned_spell.id.to_string(),
                             npc_id: npc.id.to_string(),
                         }));
                     }
                 } else {
+                    let mut looted_weight: u32 = 0;
                     let item_ids = npc
                         .character
                         .inventory
                         .equipment
                         .iter()
+                        .filter(|character_item| {
+                            let fits = self
+                                .player
+                                .character
+                                .can_carry(looted_weight + character_item.weight());
+                            if fits {
+                                looted_weight += character_item.weight();
+                            }
+                            fits
+                        })
                         .map(|character_item| character_item.item.id.to_string())
                         .collect();
 
@@ -156,11 +230,31 @@ impl Game {
                 actions
             });
 
-        let exit_actions = self.state.current_room_exits().into_iter().map(|id| {
-            Action::ExitRoom(ExitRoom {
-                exit_id: id.to_string(),
-            })
-        });
+        let exit_actions = self
+            .state
+            .current_room()
+            .exits
+            .iter()
+            .filter(|exit| self.state.knows_exit(exit))
+            .flat_map(|exit| {
+                let mut actions = match self.state.world.exit_lock(&exit.id) {
+                    Some(lock) => self.unlock_actions(lock, None, Some(exit.id.to_string())),
+                    None => vec![Action::ExitRoom(ExitRoom {
+                        exit_id: exit.id.to_string(),
+                    })],
+                };
+
+                if self.state.exit_knowledge(&exit.id).knows_has_trap
+                    && self.state.world.exit_trap(&exit.id).is_some()
+                {
+                    actions.push(Action::DisarmTrap(DisarmTrap {
+                        fixture_id: None,
+                        exit_id: Some(exit.id.to_string()),
+                    }));
+                }
+
+                actions
+            });
 
         let spell_actions = self
             .player
@@ -168,6 +262,7 @@ impl Game {
             .spell_memory
             .spells
             .iter()
+            .filter(|learned_spell| learned_spell.spell.uses > 0)
             .map(|learned_spell| {
                 Action::CastSpellOnPlayer(CastSpellOnPlayer {
                     spell_id: learned_spell.id.to_string(),
@@ -183,17 +278,29 @@ impl Game {
                 .flat_map(|character_item| {
                     let mut actions: Vec<Action> = Vec::new();
 
+                    if !self.state.player_knows_all
+                        && !self
+                            .state
+                            .item_knowledge(&character_item.item.id)
+                            .identified
+                    {
+                        actions.push(Action::IdentifyItem(IdentifyItem {
+                            item_id: character_item.item.id.to_string(),
+                        }));
+                    }
+
                     if character_item.is_consumable() {
-                        match &character_item.item.consumable {
-                            Some(consumable) => match &consumable.effect.name {
+                        if let Some(consumable) = &character_item.item.consumable {
+                            match &consumable.effect.name {
                                 ConsumableEffectName::LearnSpell
-                                | ConsumableEffectName::HealingGrog => {
+                                | ConsumableEffectName::HealingGrog
+                                | ConsumableEffectName::RemoveCurse => {
                                     actions.push(Action::UseItemOnPlayer(UseItemOnPlayer {
                                         item_id: character_item.item.id.to_string(),
                                     }));
                                 }
-                            },
-                            None => {}
+                                ConsumableEffectName::RepairKit | ConsumableEffectName::Poison => {}
+                            }
                         }
                     } else if character_item.is_packed() {
                         let location_tag = ready_tag_for_item_type(&character_item.item.item_type);
@@ -202,7 +309,7 @@ impl Game {
                             location_tag: Some(location_tag),
                             put_at_the_ready: true,
                         }));
-                    } else {
+                    } else if !character_item.item.is_cursed() {
                         for location_tag in
                             packed_tags_for_item_type(&character_item.item.item_type).into_iter()
                         {
@@ -217,11 +324,200 @@ impl Game {
                     actions
                 });
 
+        let repair_kit_ids: Vec<String> = self
+            .player
+            .character
+            .inventory
+            .equipment
+            .iter()
+            .filter(|character_item| {
+                character_item
+                    .item
+                    .consumable
+                    .as_ref()
+                    .map(|consumable| consumable.effect.repair_effect.is_some())
+                    .unwrap_or_default()
+            })
+            .map(|character_item| character_item.item.id.to_string())
+            .collect();
+
+        let repair_actions = self
+            .player
+            .character
+            .inventory
+            .equipment
+            .iter()
+            .filter(|character_item| character_item.item.needs_repair())
+            .flat_map(|character_item| {
+                let item_id = character_item.item.id.to_string();
+                let mut actions: Vec<Action> = self
+                    .state
+                    .current_room()
+                    .fixture_positions
+                    .iter()
+                    .filter(|fixture_position| {
+                        fixture_position.fixture.can_repair(&character_item.item)
+                    })
+                    .map(|fixture_position| {
+                        Action::RepairPlayerItem(RepairPlayerItem {
+                            item_id: item_id.clone(),
+                            fixture_id: Some(fixture_position.fixture.id.to_string()),
+                            repair_kit_id: None,
+                        })
+                    })
+                    .collect();
+
+                for repair_kit_id in repair_kit_ids.iter() {
+                    actions.push(Action::RepairPlayerItem(RepairPlayerItem {
+                        item_id: item_id.clone(),
+                        fixture_id: None,
+                        repair_kit_id: Some(repair_kit_id.clone()),
+                    }));
+                }
+
+                actions
+            });
+
+        let combine_actions = Recipe::all().into_iter().filter_map(|recipe| {
+            recipe
+                .find_ingredients(&self.player.character.inventory.equipment)
+                .map(|ingredients| {
+                    Action::CombineItems(CombineItems {
+                        item_ids: ingredients
+                            .iter()
+                            .map(|character_item| character_item.item.id.to_string())
+                            .collect(),
+                    })
+                })
+        });
+
+        let perk_actions = self
+            .player
+            .progression
+            .pending_perk_choices
+            .first()
+            .cloned()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|perk| !self.player.progression.perk_is_maxed(perk))
+            .map(|perk| Action::ChoosePerk(ChoosePerk { perk }));
+
+        let search_actions = [false, true]
+            .into_iter()
+            .map(|thorough| Action::SearchRoom(SearchRoom { thorough }));
+
+        let rest_actions = self
+            .state
+            .current_room()
+            .first_alive_npc()
+            .is_none()
+            .then_some(Action::Rest(Rest {}));
+
+        let rooms_within_reach = self.state.rooms_within_reach();
+        let travel_actions = self
+            .state
+            .rooms_seen
+            .iter()
+            .filter(|room_id| rooms_within_reach.contains(*room_id))
+            .map(|room_id| {
+                Action::TravelToRoom(TravelToRoom {
+                    room_id: room_id.to_string(),
+                })
+            });
+
         npc_actions
             .chain(exit_actions)
+            .chain(travel_actions)
+            .chain(search_actions)
+            .chain(rest_actions)
             .chain(fixture_actions)
             .chain(spell_actions)
             .chain(item_actions)
+            .chain(repair_actions)
+            .chain(combine_actions)
+            .chain(perk_actions)
             .collect()
     }
+
+    fn unlock_actions(
+        &self,
+        lock: &Lock,
+        fixture_id: Option<String>,
+        exit_id: Option<String>,
+    ) -> Vec<Action> {
+        let key_actions = self
+            .player
+            .character
+            .inventory
+            .equipment
+            .iter()
+            .filter(|character_item| character_item.item.is_key_for(lock))
+            .map(|character_item| {
+                Action::UnlockWithKey(UnlockWithKey {
+                    key_id: character_item.item.id.to_string(),
+                    fixture_id: fixture_id.clone(),
+                    exit_id: exit_id.clone(),
+                })
+            });
+
+        let pick_lock_action = if lock.unpickable {
+            None
+        } else {
+            Some(Action::PickLock(PickLock {
+                fixture_id: fixture_id.clone(),
+                exit_id: exit_id.clone(),
+            }))
+        };
+
+        key_actions.chain(pick_lock_action).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        actions::{Action, AttackNpc},
+        components::BossKind,
+        errors::Error,
+        events::Event,
+        generators::{
+            bosses::make_boss_lair, game::game_generator, generator::Generator,
+            players::player_generator,
+        },
+    };
+
+    use super::Game;
+
+    #[test]
+    fn killing_the_final_boss_ends_the_game() {
+        let mut player = player_generator(None, None, None).generate();
+        player.character.stats.health.max = 10_000;
+        player.character.stats.health.current = 10_000;
+        let mut state = game_generator().generate();
+        let npc_id = make_boss_lair(state.current_room_mut(), &BossKind::TheHollowKing, 1);
+        if let Some(npc_position) = state.current_room_mut().find_npc_mut(&npc_id) {
+            npc_position.npc.character.stats.health.current = 1;
+        }
+        let mut game = Game { state, player };
+
+        let attack = Action::AttackNpc(AttackNpc {
+            npc_id: npc_id.to_string(),
+        });
+        for _ in 0..100 {
+            let events = game.handle_action(&attack).unwrap();
+            if events
+                .iter()
+                .any(|event| matches!(event, Event::BossDefeated(_)))
+            {
+                break;
+            }
+        }
+
+        assert!(game.state.final_boss_defeated());
+        assert!(game.current_actions().is_empty());
+        assert!(matches!(
+            game.handle_action(&attack),
+            Err(Error::RunCompleteError)
+        ));
+    }
 }
@@ -0,0 +1,150 @@
+use rand::{prelude::ThreadRng, Rng};
+use strum::IntoEnumIterator;
+use uuid::Uuid;
+
+use crate::{
+    components::{
+        damage::AttackEffect,
+        items::{Artifact, Durability, Enchantment, Item, ItemType},
+        Attack, Defense, Dice, Material, Tagged,
+    },
+    utils::rolls::roll_d100,
+};
+
+const ARTIFACT_SPAWN_CHANCE: i32 = 5;
+
+/// Rolls for an artifact to show up in a newly generated room. Only artifacts
+/// that haven't already spawned and that the danger level allows are picked.
+pub fn roll_artifact(
+    rng: &mut ThreadRng,
+    danger_level: u32,
+    spawned: &[Artifact],
+) -> Option<Artifact> {
+    let eligible: Vec<Artifact> = Artifact::iter()
+        .filter(|artifact| {
+            artifact.min_danger_level() <= danger_level && !spawned.contains(artifact)
+        })
+        .collect();
+
+    if eligible.is_empty() || roll_d100(rng, 1, 0) > ARTIFACT_SPAWN_CHANCE {
+        return None;
+    }
+
+    let index = rng.gen_range(0..eligible.len());
+    eligible.get(index).cloned()
+}
+
+/// Every artifact is always the same item, only its id changes.
+pub fn artifact_item(artifact: &Artifact) -> Item {
+    let (item_type, material, enchantments, attack, defense) = match artifact {
+        Artifact::AegisOfTheDeep => (
+            ItemType::Shield,
+            Material::Steel,
+            vec![Enchantment::Warding],
+            None,
+            Some(Defense {
+                damage_resistance: 10,
+            }),
+        ),
+        Artifact::CrownOfTheHollowKing => (
+            ItemType::Crown,
+            Material::Gold,
+            vec![Enchantment::Warding],
+            None,
+            Some(Defense {
+                damage_resistance: 6,
+            }),
+        ),
+        Artifact::Emberfang => (
+            ItemType::LongSword,
+            Material::Steel,
+            vec![Enchantment::Flaming],
+            Some(Attack {
+                dice: Dice::d6(3, 1),
+                damage_type: ItemType::LongSword.damage_type(),
+                effects: Vec::new(),
+            }),
+            None,
+        ),
+        Artifact::Nightdrinker => (
+            ItemType::Dagger,
+            Material::Bone,
+            vec![Enchantment::Vampiric],
+            Some(Attack {
+                dice: Dice::d6(2, 1),
+                damage_type: ItemType::Dagger.damage_type(),
+                effects: Vec::new(),
+            }),
+            None,
+        ),
+        Artifact::SerpentsKiss => (
+            ItemType::Whip,
+            Material::Leather,
+            vec![Enchantment::Venom],
+            Some(Attack {
+                dice: Dice::d6(3, 0),
+                damage_type: ItemType::Whip.damage_type(),
+                effects: vec![AttackEffect::Sharp],
+            }),
+            None,
+        ),
+    };
+
+    Item {
+        id: Uuid::new_v4(),
+        name: Some(artifact.title().to_string()),
+        tags: item_type.tags(),
+        item_type,
+        descriptors: Vec::new(),
+        durability: Some(Durability::new(material.max_durability())),
+        material: Some(material),
+        attack,
+        defense,
+        consumable: None,
+        throwable: None,
+        enchantments,
+        artifact: Some(artifact.clone()),
+        curse: None,
+        curse_revealed: false,
+        unlocks: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use crate::components::items::Artifact;
+
+    use super::{artifact_item, roll_artifact};
+
+    #[test]
+    fn artifacts_respect_danger_level_and_only_spawn_once() {
+        let mut rng = rand::thread_rng();
+        let spawned = vec![Artifact::Emberfang];
+
+        for _ in 0..2000 {
+            assert!(roll_artifact(&mut rng, 0, &[]).is_none());
+            if let Some(artifact) = roll_artifact(&mut rng, 15, &spawned) {
+                assert!(artifact.min_danger_level() <= 15);
+                assert_ne!(artifact, Artifact::Emberfang);
+            }
+        }
+
+        let all: Vec<Artifact> = Artifact::iter().collect();
+        for _ in 0..2000 {
+            assert!(roll_artifact(&mut rng, 100, &all).is_none());
+        }
+    }
+
+    #[test]
+    fn artifacts_are_named_items_with_their_enchantments() {
+        for artifact in Artifact::iter() {
+            let item = artifact_item(&artifact);
+            assert_eq!(item.name.as_deref(), Some(artifact.title()));
+            assert_eq!(item.artifact, Some(artifact.clone()));
+            assert!(!item.enchantments.is_empty());
+            assert_ne!(artifact_item(&artifact).id, item.id);
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use std::ops::RangeInclusive;
+
+use rand::Rng;
+
+use crate::components::{Attributes, Species};
+
+use super::generator::Generator;
+
+pub struct AttributesPrototype {
+    pub strength: RangeInclusive<i32>,
+    pub agility: RangeInclusive<i32>,
+    pub wits: RangeInclusive<i32>,
+}
+
+pub fn attributes_for_species(species: &Species) -> AttributesPrototype {
+    let (strength, agility, wits) = match *species {
+        Species::Bugbear => (4..=5, 2..=4, 2..=3),
+        Species::Dragonkin => (4..=5, 2..=4, 3..=4),
+        Species::Frogkin => (2..=3, 4..=5, 2..=4),
+        Species::Goblin => (1..=3, 3..=5, 3..=4),
+        Species::Hobgoblin => (3..=4, 3..=4, 2..=4),
+        Species::Kobold => (1..=2, 4..=5, 3..=5),
+        Species::Lizardkin => (3..=4, 3..=5, 2..=3),
+        Species::Moblin => (3..=5, 2..=3, 1..=3),
+        Species::Ogre => (5..=6, 1..=2, 1..=2),
+        Species::Orc => (4..=5, 2..=4, 2..=3),
+        Species::Phantom => (1..=3, 4..=6, 4..=5),
+        Species::Rockoblin => (5..=6, 1..=2, 2..=3),
+        Species::Shadow => (2..=3, 5..=6, 4..=5),
+        Species::Turtlekin => (3..=4, 1..=2, 4..=6),
+    };
+
+    AttributesPrototype {
+        strength,
+        agility,
+        wits,
+    }
+}
+
+impl Generator<Attributes> for AttributesPrototype {
+    fn generate(&self) -> Attributes {
+        let mut rng = rand::thread_rng();
+
+        Attributes {
+            strength: rng.gen_range(self.strength.clone()),
+            agility: rng.gen_range(self.agility.clone()),
+            wits: rng.gen_range(self.wits.clone()),
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use rand::prelude::ThreadRng;
+use strum::IntoEnumIterator;
+use uuid::Uuid;
+
+use crate::{
+    components::{
+        items::{CharacterItem, LocationTag},
+        rooms::{GroupDescriptor, NpcPosition, Room},
+        Boss, BossKind, BossPhase, NonPlayer,
+    },
+    generators::{generator::Generator, items::item_generator_for_level},
+    utils::rolls::roll_d100,
+};
+
+use super::rooms::npcs::npc_prototype;
+
+const BOSS_SPAWN_CHANCE: i32 = 25;
+const BOSS_DANGER_BONUS: u32 = 10;
+const BOSS_HEALTH_MULTIPLIER: i32 = 3;
+
+/// Rolls for a boss to claim a newly generated room. Only the shallowest boss
+/// the depth allows that hasn't spawned yet is picked, and once the floor has
+/// no rooms left to generate the boss is guaranteed to show up.
+pub fn roll_boss(
+    rng: &mut ThreadRng,
+    depth: u32,
+    floor_is_full: bool,
+    spawned: &[BossKind],
+) -> Option<BossKind> {
+    let boss = BossKind::iter()
+        .filter(|kind| kind.depth() <= depth && !spawned.contains(kind))
+        .min_by_key(|kind| kind.depth())?;
+
+    if floor_is_full || roll_d100(rng, 1, 0) <= BOSS_SPAWN_CHANCE {
+        Some(boss)
+    } else {
+        None
+    }
+}
+
+/// Turns the room into the boss's lair, the boss scares off anyone else that
+/// would have been there but keeps any keys they were carrying.
+pub fn make_boss_lair(room: &mut Room, kind: &BossKind, danger_level: u32) -> Uuid {
+    let mut npc = boss_npc(kind, danger_level);
+    let npc_id = npc.id;
+
+    room.npc_positions
+        .iter()
+        .flat_map(|npc_position| npc_position.npc.character.inventory.equipment.iter())
+        .filter(|character_item| character_item.item.unlocks.is_some())
+        .for_each(|character_item| npc.character.add_item(character_item.clone()));
+
+    room.name = Some(kind.lair_name().to_string());
+    room.npc_positions = vec![NpcPosition {
+        group_descriptor: Some(GroupDescriptor::ALone),
+        npc,
+        position_descriptor: None,
+    }];
+
+    npc_id
+}
+
+fn boss_npc(kind: &BossKind, danger_level: u32) -> NonPlayer {
+    let boss_danger_level = danger_level + BOSS_DANGER_BONUS;
+    let mut npc = npc_prototype(&kind.species(), None, boss_danger_level).generate();
+    npc.set_name(kind.title());
+    npc.boss = Some(Boss {
+        kind: kind.clone(),
+        phase: BossPhase::Wary,
+    });
+
+    let health = &mut npc.character.stats.health;
+    health.max *= BOSS_HEALTH_MULTIPLIER;
+    health.current = health.max;
+
+    let mut loot = item_generator_for_level(&kind.loot_type(), false, boss_danger_level).generate();
+    loot.curse = None;
+    npc.character.add_item(CharacterItem {
+        item: loot,
+        is_hidden: false,
+        equipped_location: LocationTag::Packed,
+        is_multiple: false,
+        at_the_ready: false,
+        quantity: 1,
+    });
+
+    npc
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        components::{
+            items::{CharacterItem, ItemType, LocationTag},
+            rooms::NpcPosition,
+            BossKind,
+        },
+        generators::{
+            game::game_generator, generator::Generator, items::item_generator_for_level,
+            non_players::npc_generator,
+        },
+    };
+
+    use super::{make_boss_lair, roll_boss};
+
+    #[test]
+    fn bosses_wait_for_their_floor_and_show_up_once_it_is_full() {
+        let mut rng = rand::thread_rng();
+
+        assert_eq!(roll_boss(&mut rng, 2, true, &[]), None);
+        assert_eq!(
+            roll_boss(&mut rng, 3, true, &[]),
+            Some(BossKind::GrimjawTheGlutton)
+        );
+        assert_eq!(
+            roll_boss(&mut rng, 9, true, &[BossKind::GrimjawTheGlutton]),
+            Some(BossKind::TheDrownedMatron)
+        );
+        assert_eq!(
+            roll_boss(
+                &mut rng,
+                9,
+                true,
+                &[
+                    BossKind::GrimjawTheGlutton,
+                    BossKind::TheDrownedMatron,
+                    BossKind::TheHollowKing
+                ]
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn lairs_hold_the_boss_alone_with_the_keys_of_the_room() {
+        let state = game_generator().generate();
+        let mut room = state.current_room().clone();
+        let mut guard = npc_generator(None).generate();
+        let mut key = item_generator_for_level(&ItemType::Key, false, 1).generate();
+        key.unlocks = Some(Uuid::new_v4());
+        let key_id = key.id;
+        guard.character.add_item(CharacterItem {
+            item: key,
+            is_hidden: false,
+            equipped_location: LocationTag::Packed,
+            is_multiple: false,
+            at_the_ready: false,
+            quantity: 1,
+        });
+        room.npc_positions.push(NpcPosition {
+            group_descriptor: None,
+            npc: guard,
+            position_descriptor: None,
+        });
+
+        let kind = BossKind::GrimjawTheGlutton;
+        let npc_id = make_boss_lair(&mut room, &kind, 13);
+
+        assert_eq!(room.name.as_deref(), Some(kind.lair_name()));
+        assert_eq!(room.npc_positions.len(), 1);
+        let boss = &room.npc_positions[0].npc;
+        assert_eq!(boss.id, npc_id);
+        assert_eq!(boss.name.as_deref(), Some(kind.title()));
+        assert_eq!(boss.boss_kind(), Some(kind.clone()));
+        assert_eq!(
+            boss.character.stats.health.current,
+            boss.character.stats.health.max
+        );
+        assert!(boss.character.find_item(&key_id).is_some());
+        assert!(boss
+            .character
+            .inventory
+            .equipment
+            .iter()
+            .any(|character_item| character_item.item.item_type == kind.loot_type()));
+    }
+}
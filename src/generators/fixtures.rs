@@ -95,7 +95,7 @@ impl Generator<Fixture> for FixturePrototype {
                     .fixture_type
                     .tags()
                     .into_iter()
-                    .chain(material.tags().into_iter())
+                    .chain(material.tags())
                     .collect();
                 matches_tags(&tags)
             }
@@ -161,14 +161,13 @@ impl Generator<Fixture> for FixturePrototype {
             fixture_type: self.fixture_type.clone(),
             size,
             descriptors,
-            items: items
-                .into_iter()
-                .chain(hidden_compartment_items.into_iter())
-                .collect(),
+            items: items.into_iter().chain(hidden_compartment_items).collect(),
             has_hidden_compartment: self.has_hidden_compartment,
             can_be_opened: fixture_can_be_opened(&self.fixture_type),
             open: false,
             hidden_compartment_open: false,
+            lock: None,
+            trap: None,
         }
     }
 }
@@ -437,7 +436,7 @@ fn possible_item_types(fixture_type: &FixtureType, size: &Size) -> Vec<ItemType>
         | (FixtureType::WeaponRack, Size::Tall)
         | (FixtureType::WeaponRack, Size::Tiny)
         | (FixtureType::WeaponRack, Size::Wide) => ItemType::iter()
-            .filter(|item_type| !matches!(item_type, ItemType::Scroll))
+            .filter(|item_type| !matches!(item_type, ItemType::Key | ItemType::Scroll))
             .collect(),
         _ => Vec::new(),
     }
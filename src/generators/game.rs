@@ -42,8 +42,14 @@ impl Generator<GameState> for GamePrototype {
             player_knows_all: false,
             player_npc_knowledge: HashMap::new(),
             player_fixture_knowledge: HashMap::new(),
+            player_item_knowledge: HashMap::new(),
+            player_exit_knowledge: HashMap::new(),
             player_statistics: HashMap::new(),
             danger_level: 1,
+            spawned_artifacts: Vec::new(),
+            encountered_bosses: Vec::new(),
+            defeated_bosses: Vec::new(),
+            turn: 0,
         }
     }
 }
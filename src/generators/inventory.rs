@@ -8,10 +8,13 @@ use crate::{
         items::{
             CharacterItem, Consumable, HealingEffect, Item, ItemType, LocationTag, OilSplashEffect,
             Throwable, ThrowableEffect, ThrowableEffectName,
-            {ConsumableEffect, ConsumableEffectName, LearnSpellEffect},
+            {
+                ConsumableEffect, ConsumableEffectName, LearnSpellEffect, PoisonEffect,
+                RepairEffect,
+            },
         },
         spells::SpellName,
-        Inventory, Tagged, {Attack, Defense},
+        Dice, Inventory, Tagged, {Attack, Defense},
     },
     utils::rolls::roll_d100,
 };
@@ -89,6 +92,7 @@ impl InventoryPrototype {
                 item: weapon,
                 is_hidden: hidden_roll <= self.hidden_weapon_chance,
                 at_the_ready: tag.eq(&LocationTag::Hand),
+                quantity: 1,
                 equipped_location: tag,
             })
         }
@@ -145,6 +149,7 @@ impl InventoryPrototype {
                 item: wearable,
                 is_hidden: hidden_roll <= self.hidden_wearable_chance,
                 at_the_ready: true,
+                quantity: 1,
                 equipped_location: tag,
             })
         }
@@ -161,6 +166,7 @@ impl InventoryPrototype {
             | SpellName::PoisonDart
             | SpellName::TinyShield => rng.gen_range(1..=6),
             SpellName::Heal => rng.gen_range(1..=5),
+            SpellName::Identify => rng.gen_range(1..=3),
             SpellName::GreatHeal | SpellName::Phoenix => 1,
             SpellName::QuickHeal => rng.gen_range(3..=10),
             SpellName::Retribution => rng.gen_range(2..=3),
@@ -202,11 +208,18 @@ impl InventoryPrototype {
                 attack: None,
                 defense: None,
                 consumable: None,
+                durability: None,
+                enchantments: Vec::new(),
+                artifact: None,
+                curse: None,
+                curse_revealed: false,
+                unlocks: None,
                 throwable: Some(Throwable {
                     uses: 1,
                     effect: ThrowableEffect {
                         name: ThrowableEffectName::OilSplash,
                         oil_splash_effect: Some(OilSplashEffect { covers_all_enemies }),
+                        firebomb_effect: None,
                     },
                 }),
             },
@@ -214,6 +227,7 @@ impl InventoryPrototype {
             equipped_location: LocationTag::Packed,
             is_multiple: false,
             at_the_ready: false,
+            quantity: 1,
         }]
     }
 
@@ -229,11 +243,7 @@ impl InventoryPrototype {
         } else {
             5
         };
-        let healing = Attack {
-            num_rolls,
-            modifier: 0,
-            effects: Vec::new(),
-        };
+        let healing = Dice::d6(num_rolls, 0);
 
         let uses = rng.gen_range(1..=5);
 
@@ -242,11 +252,73 @@ impl InventoryPrototype {
                 name: ConsumableEffectName::HealingGrog,
                 learn_spell_effect: None,
                 healing_effect: Some(HealingEffect { healing }),
+                repair_effect: None,
+                poison_effect: None,
+            },
+            uses,
+        }
+    }
+
+    fn repair_kit_consumable(&self, rng: &mut ThreadRng) -> Consumable {
+        let amount = if (1..=10).contains(&self.danger_level) {
+            5
+        } else if (11..=25).contains(&self.danger_level) {
+            10
+        } else {
+            20
+        };
+
+        let uses = rng.gen_range(1..=3);
+
+        Consumable {
+            effect: ConsumableEffect {
+                name: ConsumableEffectName::RepairKit,
+                learn_spell_effect: None,
+                healing_effect: None,
+                repair_effect: Some(RepairEffect { amount }),
+                poison_effect: None,
             },
             uses,
         }
     }
 
+    fn poison_consumable(&self, rng: &mut ThreadRng) -> Consumable {
+        let damage = if (1..=10).contains(&self.danger_level) {
+            rng.gen_range(1..=2)
+        } else if (11..=30).contains(&self.danger_level) {
+            rng.gen_range(2..=4)
+        } else {
+            rng.gen_range(3..=6)
+        };
+
+        Consumable {
+            effect: ConsumableEffect {
+                name: ConsumableEffectName::Poison,
+                learn_spell_effect: None,
+                healing_effect: None,
+                repair_effect: None,
+                poison_effect: Some(PoisonEffect {
+                    damage,
+                    duration: rng.gen_range(2..=4),
+                }),
+            },
+            uses: 1,
+        }
+    }
+
+    fn remove_curse_consumable(&self) -> Consumable {
+        Consumable {
+            effect: ConsumableEffect {
+                name: ConsumableEffectName::RemoveCurse,
+                learn_spell_effect: None,
+                healing_effect: None,
+                repair_effect: None,
+                poison_effect: None,
+            },
+            uses: 1,
+        }
+    }
+
     fn spell_consumable(&self, rng: &mut ThreadRng) -> Consumable {
         let spell_names: Vec<SpellName> = SpellName::iter().collect();
         let index = rng.gen_range(0..spell_names.len());
@@ -270,8 +342,8 @@ impl InventoryPrototype {
                 20
             };
             Some(Attack {
-                num_rolls,
-                modifier: 0,
+                dice: Dice::d6(num_rolls, 0),
+                damage_type: spell_name.damage_type().unwrap_or_default(),
                 effects: Vec::new(),
             })
         } else if spell_name == &SpellName::Retribution {
@@ -288,8 +360,8 @@ impl InventoryPrototype {
             };
 
             Some(Attack {
-                num_rolls,
-                modifier: -1,
+                dice: Dice::d6(num_rolls, -1),
+                damage_type: spell_name.damage_type().unwrap_or_default(),
                 effects: Vec::new(),
             })
         } else if spell_name == &SpellName::QuickHeal {
@@ -305,8 +377,8 @@ impl InventoryPrototype {
                 5
             };
             Some(Attack {
-                num_rolls,
-                modifier: 0,
+                dice: Dice::d6(num_rolls, 0),
+                damage_type: spell_name.damage_type().unwrap_or_default(),
                 effects: Vec::new(),
             })
         } else if spell_name == &SpellName::Heal {
@@ -323,8 +395,8 @@ impl InventoryPrototype {
             };
 
             Some(Attack {
-                num_rolls,
-                modifier: 0,
+                dice: Dice::d6(num_rolls, 0),
+                damage_type: spell_name.damage_type().unwrap_or_default(),
                 effects: Vec::new(),
             })
         } else {
@@ -358,6 +430,8 @@ impl InventoryPrototype {
                     spell_uses,
                 }),
                 healing_effect: None,
+                repair_effect: None,
+                poison_effect: None,
             },
             uses: 1,
         }
@@ -371,11 +445,17 @@ impl InventoryPrototype {
         let consumable = match consumable_name {
             ConsumableEffectName::LearnSpell => self.spell_consumable(rng),
             ConsumableEffectName::HealingGrog => self.healing_grog_consumable(rng),
+            ConsumableEffectName::RepairKit => self.repair_kit_consumable(rng),
+            ConsumableEffectName::Poison => self.poison_consumable(rng),
+            ConsumableEffectName::RemoveCurse => self.remove_curse_consumable(),
         };
 
         let item_type = match consumable_name {
             ConsumableEffectName::LearnSpell => ItemType::Scroll,
             ConsumableEffectName::HealingGrog => ItemType::Flask,
+            ConsumableEffectName::RepairKit => ItemType::RepairKit,
+            ConsumableEffectName::Poison => ItemType::Flask,
+            ConsumableEffectName::RemoveCurse => ItemType::Scroll,
         };
 
         let possible_materials = super::utils::materials::possible_materials(&item_type);
@@ -411,11 +491,18 @@ impl InventoryPrototype {
                 defense: None,
                 consumable: Some(consumable),
                 throwable: None,
+                durability: None,
+                enchantments: Vec::new(),
+                artifact: None,
+                curse: None,
+                curse_revealed: false,
+                unlocks: None,
             },
             is_hidden: false,
             equipped_location: LocationTag::Packed,
             is_multiple: false,
             at_the_ready: false,
+            quantity: 1,
         }]
     }
 }
@@ -442,9 +529,9 @@ impl Generator<Inventory> for InventoryPrototype {
         Inventory {
             equipment: equipped_weapons
                 .into_iter()
-                .chain(equipped_wearables.into_iter())
-                .chain(consumables.into_iter())
-                .chain(pots.into_iter())
+                .chain(equipped_wearables)
+                .chain(consumables)
+                .chain(pots)
                 .collect(),
         }
     }
@@ -462,8 +549,7 @@ fn item_type_is_for_tags(item_type: &ItemType, tag: &LocationTag) -> bool {
         | ItemType::Morningstar
         | ItemType::Whip => tag.eq(&LocationTag::Hand) || tag.eq(&LocationTag::Hip),
         ItemType::Dagger | ItemType::ShortSword | ItemType::Dirk => {
-            tag.eq(&LocationTag::Hand)
-                || vec![LocationTag::Hip, LocationTag::HipSheath].contains(tag)
+            tag.eq(&LocationTag::Hand) || [LocationTag::Hip, LocationTag::HipSheath].contains(tag)
         }
         ItemType::Crown
         | ItemType::PlateHelmet
@@ -480,7 +566,7 @@ fn item_type_is_for_tags(item_type: &ItemType, tag: &LocationTag) -> bool {
         ItemType::LoinCloth => tag.eq(&LocationTag::Waist),
         ItemType::LongSword => {
             tag.eq(&LocationTag::Hand)
-                || vec![LocationTag::Hip, LocationTag::HipSheath].contains(tag)
+                || [LocationTag::Hip, LocationTag::HipSheath].contains(tag)
                 || tag.eq(&LocationTag::Back)
         }
         ItemType::Mask => tag.eq(&LocationTag::Face),
@@ -489,5 +575,7 @@ fn item_type_is_for_tags(item_type: &ItemType, tag: &LocationTag) -> bool {
         ItemType::Scroll => tag.eq(&LocationTag::Packed) | tag.eq(&LocationTag::Pockets),
         ItemType::Pot => tag.eq(&LocationTag::Packed),
         ItemType::Flask => tag.eq(&LocationTag::Packed),
+        ItemType::RepairKit => tag.eq(&LocationTag::Packed),
+        ItemType::Key => tag.eq(&LocationTag::Packed) | tag.eq(&LocationTag::Pockets),
     }
 }
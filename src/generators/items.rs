@@ -1,14 +1,23 @@
 use rand::{prelude::ThreadRng, Rng};
 use std::ops::RangeInclusive;
+use strum::IntoEnumIterator;
 use uuid::Uuid;
 
-use crate::components::{
-    damage::AttackEffect,
-    items::{Descriptor, Item, ItemType},
-    Material, Tagged, {Attack, Defense},
+use crate::{
+    components::{
+        damage::AttackEffect,
+        items::{Curse, Descriptor, Enchantment, Item, ItemType},
+        Dice, Material, Tagged, {Attack, Defense},
+    },
+    utils::rolls::roll_d100,
 };
 
-use super::generator::Generator;
+use super::{
+    generator::Generator,
+    utils::item_types::{type_is_for_weapon, type_is_for_wearable},
+};
+
+const CURSE_CHANCE: i32 = 5;
 
 pub fn item_generator_for_level(
     item_type: &ItemType,
@@ -40,6 +49,16 @@ impl Generator<Item> for ItemPrototype {
         let attack = self.attack(&mut rng);
         let defense = self.defense(&mut rng);
         let tags = self.item_type.tags();
+        let enchantments = self.enchantments(&mut rng);
+        let curse = self.curse(&mut rng);
+        let durability = if attack.is_some() || defense.is_some() {
+            Some(super::utils::durability::starting_durability(
+                &material,
+                &descriptors,
+            ))
+        } else {
+            None
+        };
 
         Item {
             id: Uuid::new_v4(),
@@ -52,6 +71,12 @@ impl Generator<Item> for ItemPrototype {
             defense,
             consumable: None,
             throwable: None,
+            durability,
+            enchantments,
+            artifact: None,
+            curse,
+            curse_revealed: false,
+            unlocks: None,
         }
     }
 }
@@ -94,7 +119,7 @@ impl ItemPrototype {
 
         descriptors
             .into_iter()
-            .chain(self.necessary_descriptors().into_iter())
+            .chain(self.necessary_descriptors())
             .collect()
     }
 
@@ -136,6 +161,8 @@ impl ItemPrototype {
             | ItemType::PlateGauntlets
             | ItemType::PlateHelmet
             | ItemType::Pot
+            | ItemType::RepairKit
+            | ItemType::Key
             | ItemType::Scroll
             | ItemType::Shirt
             | ItemType::Shackles
@@ -220,8 +247,8 @@ impl ItemPrototype {
         };
 
         Some(Attack {
-            num_rolls,
-            modifier,
+            dice: Dice::d6(num_rolls, modifier),
+            damage_type: self.item_type.damage_type(),
             effects,
         })
     }
@@ -260,6 +287,8 @@ impl ItemPrototype {
             | ItemType::Morningstar
             | ItemType::Pike
             | ItemType::Pot
+            | ItemType::RepairKit
+            | ItemType::Key
             | ItemType::Scroll
             | ItemType::ShortSword
             | ItemType::Spear
@@ -286,6 +315,61 @@ impl ItemPrototype {
         })
     }
 
+    fn enchantments(&self, rng: &mut ThreadRng) -> Vec<Enchantment> {
+        let is_weapon = type_is_for_weapon(&self.item_type);
+        let is_wearable = type_is_for_wearable(&self.item_type);
+        if !is_weapon && !is_wearable {
+            return Vec::new();
+        }
+
+        let level = self.level();
+        let enchantment_chance = if level <= 5 {
+            5
+        } else if (6..=15).contains(&level) {
+            10
+        } else if (16..=30).contains(&level) {
+            20
+        } else {
+            35
+        };
+
+        if roll_d100(rng, 1, 0) > enchantment_chance {
+            return Vec::new();
+        }
+
+        let possible_enchantments: Vec<Enchantment> = Enchantment::iter()
+            .filter(|enchantment| {
+                (is_weapon && enchantment.is_for_weapons())
+                    || (is_wearable && enchantment.is_for_wearables())
+            })
+            .collect();
+
+        if possible_enchantments.is_empty() {
+            Vec::new()
+        } else {
+            let index = rng.gen_range(0..possible_enchantments.len());
+            possible_enchantments
+                .get(index)
+                .cloned()
+                .into_iter()
+                .collect()
+        }
+    }
+
+    fn curse(&self, rng: &mut ThreadRng) -> Option<Curse> {
+        if !type_is_for_weapon(&self.item_type) && !type_is_for_wearable(&self.item_type) {
+            return None;
+        }
+
+        if roll_d100(rng, 1, 0) > CURSE_CHANCE {
+            return None;
+        }
+
+        let curses: Vec<Curse> = Curse::iter().collect();
+        let index = rng.gen_range(0..curses.len());
+        curses.get(index).cloned()
+    }
+
     fn level(&self) -> u32 {
         self.item_level.unwrap_or(1)
     }
@@ -1,3 +1,6 @@
+pub mod artifacts;
+pub mod attributes;
+pub mod bosses;
 pub mod characters;
 pub mod fixtures;
 pub mod game;
@@ -1,6 +1,6 @@
 use uuid::Uuid;
 
-use crate::components::{Character, NonPlayer};
+use crate::components::{Character, NonPlayer, NpcMovement};
 
 use super::{characters::random_character_generator, generator::Generator};
 
@@ -24,6 +24,10 @@ impl Generator<NonPlayer> for NonPlayerPrototype {
             character,
             id: Uuid::new_v4(),
             name: self.name.clone(),
+            boss: None,
+            died_on_turn: None,
+            movement: NpcMovement::Stationary,
+            home_room_id: None,
         }
     }
 }
@@ -4,13 +4,16 @@ use strum::IntoEnumIterator;
 use uuid::Uuid;
 
 use crate::components::{
-    items::{CharacterItem, Descriptor, Item, ItemType, LocationTag},
+    items::{CharacterItem, Descriptor, Durability, Item, ItemType, LocationTag},
     spells::{LearnedSpell, Spell, SpellMemory, SpellName},
-    Character, Effects, Inventory, Material, PlayerCharacter, Size, Species, Tag,
-    {Attack, Defense},
+    Character, Dice, Effects, Inventory, Material, PlayerCharacter, Progression, Size, Species,
+    Tag, {Attack, Defense},
 };
 
-use super::{generator::Generator, stats::build_specific_health};
+use super::{
+    attributes::attributes_for_species, generator::Generator, stats::build_specific_health,
+    utils::durability::starting_durability,
+};
 
 struct PlayerCharacterPrototype {
     pub character_name: Option<String>,
@@ -49,6 +52,8 @@ impl Generator<PlayerCharacter> for PlayerCharacterPrototype {
             }
         };
 
+        stats.attributes = attributes_for_species(&species).generate();
+
         let starter_weapon = starter_weapon(&mut rng);
         let starter_wearables = starter_wearables();
 
@@ -60,7 +65,7 @@ impl Generator<PlayerCharacter> for PlayerCharacterPrototype {
                 inventory: Inventory {
                     equipment: starter_wearables
                         .into_iter()
-                        .chain(vec![starter_weapon].into_iter())
+                        .chain(vec![starter_weapon])
                         .collect(),
                 },
                 current_effects: Effects::default(),
@@ -74,12 +79,14 @@ impl Generator<PlayerCharacter> for PlayerCharacterPrototype {
                             uses: 1,
                         },
                         learned_at: Utc::now(),
+                        learned_uses: 1,
                     }],
                 },
             },
             id: Uuid::new_v4(),
             name: self.character_name.clone(),
             gold: 0,
+            progression: Progression::default(),
         }
     }
 }
@@ -98,6 +105,12 @@ fn starter_wearables() -> Vec<CharacterItem> {
         }),
         consumable: None,
         throwable: None,
+        durability: Some(Durability::new(Material::Linen.max_durability())),
+        enchantments: Vec::new(),
+        artifact: None,
+        curse: None,
+        curse_revealed: false,
+        unlocks: None,
     };
 
     let shirt = Item {
@@ -113,6 +126,12 @@ fn starter_wearables() -> Vec<CharacterItem> {
         }),
         consumable: None,
         throwable: None,
+        durability: Some(Durability::new(Material::Linen.max_durability())),
+        enchantments: Vec::new(),
+        artifact: None,
+        curse: None,
+        curse_revealed: false,
+        unlocks: None,
     };
 
     let boots = Item {
@@ -128,6 +147,12 @@ fn starter_wearables() -> Vec<CharacterItem> {
         }),
         consumable: None,
         throwable: None,
+        durability: Some(Durability::new(Material::Leather.max_durability())),
+        enchantments: Vec::new(),
+        artifact: None,
+        curse: None,
+        curse_revealed: false,
+        unlocks: None,
     };
 
     vec![
@@ -136,6 +161,7 @@ fn starter_wearables() -> Vec<CharacterItem> {
             is_hidden: false,
             is_multiple: false,
             at_the_ready: true,
+            quantity: 1,
             equipped_location: LocationTag::Leg,
         },
         CharacterItem {
@@ -143,6 +169,7 @@ fn starter_wearables() -> Vec<CharacterItem> {
             is_hidden: false,
             is_multiple: false,
             at_the_ready: true,
+            quantity: 1,
             equipped_location: LocationTag::Body,
         },
         CharacterItem {
@@ -150,6 +177,7 @@ fn starter_wearables() -> Vec<CharacterItem> {
             is_hidden: false,
             is_multiple: false,
             at_the_ready: true,
+            quantity: 1,
             equipped_location: LocationTag::Feet,
         },
     ]
@@ -184,6 +212,9 @@ fn starter_weapon(rng: &mut ThreadRng) -> CharacterItem {
         vec![Descriptor::Rusty]
     };
 
+    let damage_type = item_type.damage_type();
+    let durability = starting_durability(&material, &descriptors);
+
     let item = Item {
         id: Uuid::new_v4(),
         name: None,
@@ -192,13 +223,19 @@ fn starter_weapon(rng: &mut ThreadRng) -> CharacterItem {
         material,
         descriptors,
         attack: Some(Attack {
-            num_rolls: 1,
-            modifier: -1,
+            dice: Dice::d6(1, -1),
+            damage_type,
             effects: Vec::new(),
         }),
         defense: None,
         consumable: None,
         throwable: None,
+        durability: Some(durability),
+        enchantments: Vec::new(),
+        artifact: None,
+        curse: None,
+        curse_revealed: false,
+        unlocks: None,
     };
 
     CharacterItem {
@@ -207,5 +244,6 @@ fn starter_weapon(rng: &mut ThreadRng) -> CharacterItem {
         equipped_location: LocationTag::Hand,
         is_multiple: false,
         at_the_ready: true,
+        quantity: 1,
     }
 }
@@ -1,7 +1,10 @@
 mod dimensions;
 mod exits;
 mod fixtures;
+mod locks;
 pub mod npcs;
+mod puzzles;
+mod traps;
 
 use std::ops::RangeInclusive;
 
@@ -9,11 +12,19 @@ use rand::Rng;
 use strum::IntoEnumIterator;
 use uuid::Uuid;
 
-use crate::components::rooms::{Descriptor, Flavour, Room, RoomType};
+use crate::components::{
+    rooms::{Descriptor, ExitType, Flavour, Room, RoomType},
+    worlds::{danger_level_for_depth, FINAL_DEPTH},
+};
 
 use self::{
-    dimensions::build_dimensions, exits::build_exits, fixtures::build_fixture_positions,
+    dimensions::build_dimensions,
+    exits::build_exits,
+    fixtures::build_fixture_positions,
+    locks::{lock_fixtures, place_keys},
     npcs::build_npc_positions,
+    puzzles::add_puzzle,
+    traps::trap_fixtures,
 };
 
 use super::generator::Generator;
@@ -23,7 +34,10 @@ struct RoomPrototype {
     pub room_type: RoomType,
     pub possible_descriptors: Vec<Descriptor>,
     pub entrance_id: Option<Uuid>,
+    pub entrance_type: Option<ExitType>,
     pub danger_level: u32,
+    pub depth: u32,
+    pub floor_is_full: bool,
 }
 
 impl Generator<Room> for RoomPrototype {
@@ -49,10 +63,12 @@ impl Generator<Room> for RoomPrototype {
         let index = rng.gen_range(0..flavour_options.len());
         let flavour = flavour_options.get(index).cloned();
 
-        let (fixture_positions, used_fixtures) =
+        let (mut fixture_positions, used_fixtures) =
             build_fixture_positions(&self.room_type, self.danger_level);
+        lock_fixtures(&mut rng, &mut fixture_positions, self.danger_level);
+        trap_fixtures(&mut rng, &mut fixture_positions, self.danger_level);
 
-        Room {
+        let mut room = Room {
             dimensions: build_dimensions(),
             descriptors,
             id: Uuid::new_v4(),
@@ -61,8 +77,24 @@ impl Generator<Room> for RoomPrototype {
             fixture_positions,
             npc_positions: build_npc_positions(&self.room_type, used_fixtures, self.danger_level),
             flavour,
-            exits: build_exits(&self.room_type, self.entrance_id),
-        }
+            exits: build_exits(
+                &self.room_type,
+                self.entrance_id.map(|id| (id, self.entrance_type.clone())),
+                self.danger_level,
+                self.depth,
+                self.floor_is_full,
+            ),
+            puzzle: None,
+            depth: self.depth,
+        };
+        let room_id = room.id;
+        room.npc_positions
+            .iter_mut()
+            .for_each(|npc_position| npc_position.npc.home_room_id = Some(room_id));
+        place_keys(&mut rng, &mut room);
+        add_puzzle(&mut rng, &mut room, self.danger_level);
+
+        room
     }
 }
 
@@ -72,7 +104,10 @@ pub fn room_generator(room_type: &RoomType, entrance_id: Option<Uuid>) -> impl G
         room_type: room_type.clone(),
         possible_descriptors: room_type.possible_descriptors(),
         entrance_id,
+        entrance_type: None,
         danger_level: 1,
+        depth: 0,
+        floor_is_full: false,
     }
 }
 
@@ -86,7 +121,10 @@ pub fn room_generator_for_danger_level(
         room_type: room_type.clone(),
         possible_descriptors: room_type.possible_descriptors(),
         entrance_id,
+        entrance_type: None,
         danger_level,
+        depth: 0,
+        floor_is_full: false,
     }
 }
 
@@ -111,6 +149,36 @@ pub fn random_room_generator_for_danger_level(
     room_generator_for_danger_level(room_type, entrance_id, danger_level)
 }
 
+/// Builds the room on the other side of an exit, on the floor the exit leads
+/// to. Once a floor is full its new rooms only lead further down, and the
+/// final floor is all temples and tombs.
+pub fn room_generator_for_floor(
+    entrance_id: Uuid,
+    entrance_type: ExitType,
+    depth: u32,
+    floor_is_full: bool,
+) -> impl Generator<Room> {
+    let room_types: Vec<RoomType> = if depth == FINAL_DEPTH {
+        vec![RoomType::Crypt, RoomType::Mausoleum, RoomType::TempleHall]
+    } else {
+        RoomType::iter().collect()
+    };
+    let mut rng = rand::thread_rng();
+    let index = rng.gen_range(0..room_types.len());
+    let room_type = room_types.get(index).cloned().unwrap_or(RoomType::Room);
+
+    RoomPrototype {
+        num_descriptors: 1..=2,
+        possible_descriptors: room_type.possible_descriptors(),
+        room_type,
+        entrance_id: Some(entrance_id),
+        entrance_type: Some(entrance_type),
+        danger_level: danger_level_for_depth(depth),
+        depth,
+        floor_is_full,
+    }
+}
+
 impl RoomType {
     fn possible_descriptors(&self) -> Vec<Descriptor> {
         Descriptor::iter().collect()
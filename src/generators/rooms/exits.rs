@@ -2,30 +2,61 @@ use rand::{prelude::ThreadRng, Rng};
 use strum::IntoEnumIterator;
 use uuid::Uuid;
 
-use crate::components::{
-    rooms::{Exit, ExitDescriptor, ExitType, RoomType},
-    Material, Size,
+use crate::{
+    components::{
+        rooms::{Exit, ExitDescriptor, ExitType, RoomType},
+        worlds::FINAL_DEPTH,
+        Material, Size,
+    },
+    utils::rolls::roll_d100,
 };
 
-pub fn build_exits(room_type: &RoomType, entrance_id: Option<Uuid>) -> Vec<Exit> {
+use super::{locks::exit_lock, traps::exit_trap};
+
+const HIDDEN_EXIT_CHANCE: i32 = 10;
+
+/// The first exit is the way in, when there is one it matches the exit the
+/// player came through. A full floor only adds a single way further down.
+pub fn build_exits(
+    room_type: &RoomType,
+    entrance: Option<(Uuid, Option<ExitType>)>,
+    danger_level: u32,
+    depth: u32,
+    floor_is_full: bool,
+) -> Vec<Exit> {
     let mut rng = rand::thread_rng();
-    let num_exits = num_exits(&mut rng, room_type);
+    let num_exits = match (floor_is_full, depth == FINAL_DEPTH) {
+        (true, true) => 1,
+        (true, false) => 2,
+        (false, _) => num_exits(&mut rng, room_type),
+    };
 
     (0..num_exits)
         .map(|index| {
-            let id = if index == 0 {
-                match entrance_id {
-                    Some(it) => it,
-                    None => Uuid::new_v4(),
-                }
-            } else {
-                Uuid::new_v4()
+            let (id, entrance_type) = match (index, &entrance) {
+                (0, Some((id, entrance_type))) => (*id, entrance_type.clone()),
+                _ => (Uuid::new_v4(), None),
             };
 
-            let exit_type = exit_type(&mut rng, room_type);
+            let exit_type = match entrance_type {
+                Some(it) => it.other_side(),
+                None if floor_is_full && index > 0 => ExitType::StaircaseDown,
+                None => exit_type(&mut rng, room_type, depth),
+            };
             let material = material(&mut rng, &exit_type);
             let size = size(&mut rng, &exit_type);
             let descriptors = descriptors(&mut rng, &exit_type, &material);
+            // The way in, or the first way out of the entry, is never locked,
+            // trapped or hidden.
+            let (lock, trap, hidden) = if index == 0 {
+                (None, None, false)
+            } else {
+                (
+                    exit_lock(&mut rng, &exit_type, danger_level),
+                    exit_trap(&mut rng, danger_level),
+                    roll_d100(&mut rng, 1, 0) <= HIDDEN_EXIT_CHANCE,
+                )
+            };
 
             Exit {
                 exit_type,
@@ -34,9 +65,11 @@ pub fn build_exits(room_type: &RoomType, entrance_id: Option<Uuid>) -> Vec<Exit>
                 descriptors,
                 id,
                 name: None,
+                lock,
+                trap,
+                hidden,
             }
         })
-        .into_iter()
         .collect()
 }
 
@@ -55,8 +88,8 @@ fn num_exits(rng: &mut ThreadRng, room_type: &RoomType) -> usize {
     }
 }
 
-fn exit_type(rng: &mut ThreadRng, room_type: &RoomType) -> ExitType {
-    let possible_types: Vec<ExitType> = match *room_type {
+fn exit_type(rng: &mut ThreadRng, room_type: &RoomType, depth: u32) -> ExitType {
+    let mut possible_types: Vec<ExitType> = match *room_type {
         RoomType::PrisonCell => vec![
             ExitType::DugOutTunnelEntrance,
             ExitType::Door,
@@ -66,6 +99,12 @@ fn exit_type(rng: &mut ThreadRng, room_type: &RoomType) -> ExitType {
         ],
         _ => ExitType::iter().collect(),
     };
+    // There's nothing above the entry floor and nothing below the final one.
+    possible_types.retain(|exit_type| match exit_type.depth_change() {
+        change if change < 0 => depth > 0,
+        change if change > 0 => depth < FINAL_DEPTH,
+        _ => true,
+    });
 
     let index = rng.gen_range(0..possible_types.len());
     possible_types.get(index).unwrap().to_owned()
@@ -119,7 +158,7 @@ fn descriptors(
 
     let mut possible_descriptors: Vec<ExitDescriptor> = exit_type_descriptors
         .into_iter()
-        .chain(material_descriptors.into_iter())
+        .chain(material_descriptors)
         .collect();
 
     if possible_descriptors.is_empty() {
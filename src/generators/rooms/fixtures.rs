@@ -1,4 +1,4 @@
-use rand::Rng;
+use rand::{prelude::ThreadRng, Rng};
 use strum::IntoEnumIterator;
 
 use crate::{
@@ -70,10 +70,24 @@ pub fn build_fixture_positions(
     (positions, used_fixtures)
 }
 
+/// Places a fixture that was generated on its own, outside of any group.
+pub fn single_fixture_position(rng: &mut ThreadRng, fixture: Fixture) -> FixturePosition {
+    let group_descriptors = single_group_descriptors();
+    let positions = single_possible_positions();
+
+    FixturePosition {
+        group_descriptor: group_descriptors
+            .get(rng.gen_range(0..group_descriptors.len()))
+            .cloned(),
+        fixture,
+        position_descriptor: positions.get(rng.gen_range(0..positions.len())).cloned(),
+    }
+}
+
 fn possible_positions(fixture_type: &FixtureType) -> Vec<FixturePositionDescriptor> {
     let mut possibilities = single_possible_positions();
 
-    let can_be_broken_on_ground = vec![
+    let can_be_broken_on_ground = [
         FixtureType::StatueWarrior,
         FixtureType::StatueTentacledMonstrosity,
         FixtureType::Pillar,
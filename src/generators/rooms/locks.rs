@@ -0,0 +1,153 @@
+use rand::{prelude::ThreadRng, Rng};
+use uuid::Uuid;
+
+use crate::{
+    components::{
+        items::{CharacterItem, FixtureItem, Item, ItemType, LocationTag},
+        rooms::{ExitType, FixturePosition, Room},
+        Lock, LockDifficulty, Material, Tagged,
+    },
+    utils::rolls::roll_d100,
+};
+
+const LOCKED_EXIT_CHANCE: i32 = 15;
+const LOCKED_FIXTURE_CHANCE: i32 = 20;
+const KEY_IN_ROOM_CHANCE: i32 = 70;
+
+pub fn exit_lock(rng: &mut ThreadRng, exit_type: &ExitType, danger_level: u32) -> Option<Lock> {
+    let can_be_locked = matches!(
+        *exit_type,
+        ExitType::Door | ExitType::StaircaseDown | ExitType::StaircaseUp
+    );
+
+    if can_be_locked && roll_d100(rng, 1, 0) <= LOCKED_EXIT_CHANCE {
+        Some(build_lock(rng, danger_level))
+    } else {
+        None
+    }
+}
+
+pub fn lock_fixtures(
+    rng: &mut ThreadRng,
+    fixture_positions: &mut [FixturePosition],
+    danger_level: u32,
+) {
+    for fixture in fixture_positions
+        .iter_mut()
+        .map(|fixture_position| &mut fixture_position.fixture)
+        .filter(|fixture| fixture.can_be_opened)
+    {
+        if roll_d100(rng, 1, 0) <= LOCKED_FIXTURE_CHANCE {
+            fixture.lock = Some(build_lock(rng, danger_level));
+        }
+    }
+}
+
+/// Most locks have their key somewhere in the same room, either carried by
+/// someone in the room or left on a fixture that isn't locked itself.
+pub fn place_keys(rng: &mut ThreadRng, room: &mut Room) {
+    let locks: Vec<Lock> = room
+        .fixture_positions
+        .iter()
+        .filter_map(|fixture_position| fixture_position.fixture.lock.clone())
+        .chain(room.exits.iter().filter_map(|exit| exit.lock.clone()))
+        .collect();
+
+    for lock in locks.iter() {
+        if roll_d100(rng, 1, 0) > KEY_IN_ROOM_CHANCE {
+            continue;
+        }
+
+        let num_npcs = room.npc_positions.len();
+        let unlocked_fixtures: Vec<usize> = room
+            .fixture_positions
+            .iter()
+            .enumerate()
+            .filter(|(_, fixture_position)| !fixture_position.fixture.is_locked())
+            .map(|(index, _)| index)
+            .collect();
+
+        let num_places = num_npcs + unlocked_fixtures.len();
+        if num_places == 0 {
+            continue;
+        }
+
+        let key = key_item(lock);
+        let place = rng.gen_range(0..num_places);
+        if place < num_npcs {
+            if let Some(npc_position) = room.npc_positions.get_mut(place) {
+                npc_position.npc.character.add_item(CharacterItem {
+                    item: key,
+                    is_hidden: false,
+                    equipped_location: LocationTag::Packed,
+                    is_multiple: false,
+                    at_the_ready: false,
+                    quantity: 1,
+                });
+            }
+        } else if let Some(fixture_position) = unlocked_fixtures
+            .get(place - num_npcs)
+            .and_then(|index| room.fixture_positions.get_mut(*index))
+        {
+            let fixture = &mut fixture_position.fixture;
+            fixture.items.push(FixtureItem {
+                item: key,
+                is_inside: fixture.can_be_opened,
+                is_in_hidden_compartment: false,
+            });
+        }
+    }
+}
+
+fn build_lock(rng: &mut ThreadRng, danger_level: u32) -> Lock {
+    let (simple_chance, sturdy_chance) = if (1..=10).contains(&danger_level) {
+        (70, 95)
+    } else if (11..=30).contains(&danger_level) {
+        (40, 80)
+    } else {
+        (20, 60)
+    };
+
+    let roll = roll_d100(rng, 1, 0);
+    let difficulty = if roll <= simple_chance {
+        LockDifficulty::Simple
+    } else if roll <= sturdy_chance {
+        LockDifficulty::Sturdy
+    } else {
+        LockDifficulty::Masterwork
+    };
+
+    Lock {
+        id: Uuid::new_v4(),
+        difficulty,
+        locked: true,
+        unpickable: false,
+    }
+}
+
+fn key_item(lock: &Lock) -> Item {
+    let material = match lock.difficulty {
+        LockDifficulty::Simple => Material::Iron,
+        LockDifficulty::Sturdy => Material::Steel,
+        LockDifficulty::Masterwork => Material::Bone,
+    };
+
+    Item {
+        id: Uuid::new_v4(),
+        name: None,
+        item_type: ItemType::Key,
+        tags: ItemType::Key.tags(),
+        descriptors: Vec::new(),
+        material: Some(material),
+        attack: None,
+        defense: None,
+        consumable: None,
+        throwable: None,
+        durability: None,
+        enchantments: Vec::new(),
+        artifact: None,
+        curse: None,
+        curse_revealed: false,
+        unlocks: Some(lock.id),
+    }
+}
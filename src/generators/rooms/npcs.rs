@@ -6,7 +6,7 @@ use crate::{
         fixtures::FixtureType,
         items::ItemType,
         rooms::{GroupDescriptor, NpcPosition, NpcPositionDescriptor, RoomType},
-        LifeModifier, Species,
+        LifeModifier, NpcMovement, Species,
     },
     generators::{
         characters::CharacterPrototype, generator::Generator, inventory::InventoryPrototype,
@@ -36,6 +36,7 @@ pub fn build_npc_positions(
             // Get the group size based on the species.
             let group_size = group_size(&starter_species);
             let life_modifier = life_modifier(&starter_species);
+            let movement = movement();
             let mut species = starter_species.clone();
             let mut prototype =
                 npc_prototype(&starter_species, life_modifier.clone(), danger_level);
@@ -52,11 +53,20 @@ pub fn build_npc_positions(
 
                 if position_descriptor == Some(NpcPositionDescriptor::IsLyingInPoolBlood)
                     && !matches!(&npc.character.species, Species::Phantom | Species::Shadow)
-                    && npc.character.life_modifier == None
+                    && npc.character.life_modifier.is_none()
                 {
                     npc.kill();
                 }
 
+                // Anyone asleep isn't going anywhere.
+                if !position_descriptor
+                    .as_ref()
+                    .map(|descriptor| descriptor.is_asleep())
+                    .unwrap_or_default()
+                {
+                    npc.movement = movement.clone();
+                }
+
                 npc_positions.push(NpcPosition {
                     group_descriptor: group_descriptor(),
                     npc,
@@ -69,6 +79,37 @@ pub fn build_npc_positions(
         .collect()
 }
 
+/// Someone passing through, who has just spotted the player.
+pub fn wandering_npc_position(danger_level: u32) -> NpcPosition {
+    let species = choose_species();
+    let life_modifier = life_modifier(&species);
+    let mut npc = npc_prototype(&species, life_modifier, danger_level).generate();
+    npc.movement = NpcMovement::Wandering;
+
+    NpcPosition {
+        group_descriptor: group_descriptor(),
+        npc,
+        position_descriptor: Some(NpcPositionDescriptor::IsGlaringAtYou),
+    }
+}
+
+const WANDERING_CHANCE: i32 = 10;
+const PATROLLING_CHANCE: i32 = 15;
+
+/// Most groups stay put, but some move about the dungeon together.
+fn movement() -> NpcMovement {
+    let mut rng = rand::thread_rng();
+    let roll = roll_d100(&mut rng, 1, 0);
+
+    if roll <= WANDERING_CHANCE {
+        NpcMovement::Wandering
+    } else if roll <= WANDERING_CHANCE + PATROLLING_CHANCE {
+        NpcMovement::Patrolling
+    } else {
+        NpcMovement::Stationary
+    }
+}
+
 fn num_groups(room_type: &RoomType) -> usize {
     let range = match *room_type {
         RoomType::PrisonCell => 0..=1,
@@ -244,7 +285,7 @@ fn life_modifier(species: &Species) -> Option<LifeModifier> {
     }
 }
 
-fn npc_prototype(
+pub fn npc_prototype(
     species: &Species,
     life_modifier: Option<LifeModifier>,
     danger_level: u32,
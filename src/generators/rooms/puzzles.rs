@@ -0,0 +1,174 @@
+use rand::{prelude::ThreadRng, Rng};
+use strum::IntoEnumIterator;
+use uuid::Uuid;
+
+use crate::{
+    components::{
+        fixtures::FixtureType,
+        items::{FixtureItem, Item, ItemType},
+        rooms::{FixturePositionDescriptor, Puzzle, PuzzlePiece, PuzzleReward, Room, RoomType},
+        Lock, LockDifficulty,
+    },
+    generators::{
+        fixtures::get_generator_for_level,
+        generator::Generator,
+        items::item_generator_for_level,
+        utils::item_types::{type_is_for_weapon, type_is_for_wearable},
+    },
+    utils::rolls::roll_d100,
+};
+
+use super::fixtures::single_fixture_position;
+
+const MIN_PIECES: usize = 2;
+const MAX_PIECES: usize = 4;
+
+/// Temple halls and crypts are where the old builders left their puzzles,
+/// anywhere else having one is rare.
+pub fn add_puzzle(rng: &mut ThreadRng, room: &mut Room, danger_level: u32) {
+    let puzzle_chance = match room.room_type {
+        RoomType::Crypt | RoomType::TempleHall => 60,
+        RoomType::Mausoleum => 25,
+        _ => 5,
+    };
+
+    if roll_d100(rng, 1, 0) > puzzle_chance {
+        return;
+    }
+
+    let mut piece_fixture_ids: Vec<Uuid> = room
+        .fixture_positions
+        .iter()
+        .filter(|fixture_position| {
+            fixture_position
+                .fixture
+                .fixture_type
+                .puzzle_interaction()
+                .is_some()
+                && !matches!(
+                    fixture_position.position_descriptor,
+                    Some(FixturePositionDescriptor::CrackedAndBrokenOnTheGround)
+                )
+        })
+        .map(|fixture_position| fixture_position.fixture.id)
+        .take(MAX_PIECES)
+        .collect();
+
+    if piece_fixture_ids.len() < MIN_PIECES {
+        let piece_types: Vec<FixtureType> = FixtureType::iter()
+            .filter(|fixture_type| fixture_type.puzzle_interaction().is_some())
+            .collect();
+        let index = rng.gen_range(0..piece_types.len());
+        let fixture_type = match piece_types.get(index) {
+            Some(it) => it,
+            None => return,
+        };
+        let num_to_add = rng.gen_range(MIN_PIECES..=MAX_PIECES) - piece_fixture_ids.len();
+
+        for _ in 0..num_to_add {
+            let fixture = get_generator_for_level(fixture_type, false, danger_level).generate();
+            piece_fixture_ids.push(fixture.id);
+            room.fixture_positions
+                .push(single_fixture_position(rng, fixture));
+        }
+    }
+
+    let mut pieces: Vec<PuzzlePiece> = Vec::new();
+    for fixture_id in piece_fixture_ids {
+        let interaction = match room
+            .find_fixture(&fixture_id)
+            .and_then(|fixture_position| fixture_position.fixture.fixture_type.puzzle_interaction())
+        {
+            Some(it) => it,
+            None => continue,
+        };
+        let num_positions = interaction.num_positions();
+        pieces.push(PuzzlePiece {
+            fixture_id,
+            position: rng.gen_range(0..num_positions),
+            target: rng.gen_range(0..num_positions),
+            interaction,
+        });
+    }
+
+    // A puzzle that starts out solved isn't much of one.
+    if pieces.iter().all(|piece| piece.is_in_place()) {
+        if let Some(piece) = pieces.first_mut() {
+            piece.position = piece.next_position();
+        }
+    }
+
+    let reward = build_reward(rng, room, &pieces, danger_level);
+    room.puzzle = Some(Puzzle {
+        id: Uuid::new_v4(),
+        pieces,
+        reward,
+        solved: false,
+    });
+}
+
+fn build_reward(
+    rng: &mut ThreadRng,
+    room: &mut Room,
+    pieces: &[PuzzlePiece],
+    danger_level: u32,
+) -> PuzzleReward {
+    let roll = roll_d100(rng, 1, 0);
+
+    if roll <= 35 {
+        // Never hide the way back in.
+        let exit = room
+            .exits
+            .iter_mut()
+            .skip(1)
+            .find(|exit| !exit.hidden && exit.lock.is_none());
+        if let Some(exit) = exit {
+            exit.hidden = true;
+            return PuzzleReward::HiddenExit(exit.id);
+        }
+    }
+
+    let reward_item = reward_item(rng, danger_level);
+
+    if roll <= 70 {
+        let vault = room
+            .fixture_positions
+            .iter_mut()
+            .map(|fixture_position| &mut fixture_position.fixture)
+            .find(|fixture| {
+                fixture.can_be_opened
+                    && fixture.lock.is_none()
+                    && !pieces.iter().any(|piece| piece.fixture_id.eq(&fixture.id))
+            });
+        if let Some(vault) = vault {
+            // A lock with no key anywhere, the puzzle is the key.
+            vault.lock = Some(Lock {
+                id: Uuid::new_v4(),
+                difficulty: LockDifficulty::Masterwork,
+                locked: true,
+                unpickable: true,
+            });
+            vault.items.push(FixtureItem {
+                item: reward_item,
+                is_inside: true,
+                is_in_hidden_compartment: false,
+            });
+            return PuzzleReward::Vault(vault.id);
+        }
+    }
+
+    PuzzleReward::Item(Box::new(reward_item))
+}
+
+fn reward_item(rng: &mut ThreadRng, danger_level: u32) -> Item {
+    let item_types: Vec<ItemType> = ItemType::iter()
+        .filter(|item_type| type_is_for_weapon(item_type) || type_is_for_wearable(item_type))
+        .collect();
+    let index = rng.gen_range(0..item_types.len());
+    let item_type = item_types.get(index).unwrap_or(&ItemType::Dagger);
+
+    // Whoever built the puzzle wouldn't guard a cursed item with it.
+    let mut item = item_generator_for_level(item_type, false, danger_level + 5).generate();
+    item.curse = None;
+    item
+}
@@ -0,0 +1,64 @@
+use rand::{prelude::ThreadRng, Rng};
+use strum::IntoEnumIterator;
+
+use crate::{
+    components::{rooms::FixturePosition, Dice, Trap, TrapType},
+    utils::rolls::roll_d100,
+};
+
+const TRAPPED_EXIT_CHANCE: i32 = 5;
+
+pub fn exit_trap(rng: &mut ThreadRng, danger_level: u32) -> Option<Trap> {
+    if roll_d100(rng, 1, 0) <= TRAPPED_EXIT_CHANCE {
+        Some(build_trap(rng, danger_level))
+    } else {
+        None
+    }
+}
+
+/// Only fixtures with something to open can be trapped, and traps get more
+/// common the more dangerous the world gets.
+pub fn trap_fixtures(
+    rng: &mut ThreadRng,
+    fixture_positions: &mut [FixturePosition],
+    danger_level: u32,
+) {
+    let trap_chance = if (1..=10).contains(&danger_level) {
+        5
+    } else if (11..=30).contains(&danger_level) {
+        10
+    } else {
+        20
+    };
+
+    for fixture in fixture_positions
+        .iter_mut()
+        .map(|fixture_position| &mut fixture_position.fixture)
+        .filter(|fixture| fixture.can_be_opened || fixture.has_hidden_compartment)
+    {
+        if roll_d100(rng, 1, 0) <= trap_chance {
+            fixture.trap = Some(build_trap(rng, danger_level));
+        }
+    }
+}
+
+fn build_trap(rng: &mut ThreadRng, danger_level: u32) -> Trap {
+    let trap_types: Vec<TrapType> = TrapType::iter().collect();
+    let index = rng.gen_range(0..trap_types.len());
+    let trap_type = trap_types.get(index).cloned().unwrap();
+
+    let num_rolls = if (1..=10).contains(&danger_level) {
+        1
+    } else if (11..=25).contains(&danger_level) {
+        2
+    } else if (26..=40).contains(&danger_level) {
+        3
+    } else {
+        4
+    };
+
+    Trap {
+        trap_type,
+        damage: Dice::d6(num_rolls, 0),
+    }
+}
@@ -1,17 +1,18 @@
 use rand::Rng;
 
 use crate::{
-    components::{size::Size, species::Species, stats::Stats, Health},
+    components::{size::Size, species::Species, stats::Stats, Attributes, Health},
     utils::rolls::{roll_d100, roll_d6},
 };
 
-use super::generator::Generator;
+use super::{attributes::attributes_for_species, generator::Generator};
 
 pub fn build_specific_health(max_health: i32) -> StatsPrototype {
     StatsPrototype {
         max_health: Some(max_health),
         num_health_rolls: 0,
         danger_level: 1,
+        species: None,
     }
 }
 
@@ -20,6 +21,7 @@ pub fn build_specific_health_danger_level(max_health: i32, danger_level: u32) ->
         max_health: Some(max_health),
         num_health_rolls: 0,
         danger_level,
+        species: None,
     }
 }
 
@@ -28,6 +30,7 @@ pub fn build(num_health_rolls: usize) -> StatsPrototype {
         max_health: None,
         num_health_rolls,
         danger_level: 1,
+        species: None,
     }
 }
 
@@ -36,6 +39,7 @@ pub fn build_danger_level(num_health_rolls: usize, danger_level: u32) -> StatsPr
         max_health: None,
         num_health_rolls,
         danger_level,
+        species: None,
     }
 }
 
@@ -50,6 +54,7 @@ pub fn build_default_health_rolls(species: &Species) -> StatsPrototype {
         max_health: None,
         num_health_rolls,
         danger_level: 1,
+        species: Some(species.clone()),
     }
 }
 
@@ -67,6 +72,7 @@ pub fn build_default_health_rolls_for_danger_level(
         max_health: None,
         num_health_rolls,
         danger_level,
+        species: Some(species.clone()),
     }
 }
 
@@ -74,6 +80,7 @@ pub struct StatsPrototype {
     pub max_health: Option<i32>,
     pub num_health_rolls: usize,
     pub danger_level: u32,
+    pub species: Option<Species>,
 }
 
 const NON_AVERAGE_HEIGHT_CHANCE: i32 = 40;
@@ -147,6 +154,15 @@ impl Generator<Stats> for StatsPrototype {
             max: max_health,
         };
 
-        Stats { health, height }
+        let attributes = match &self.species {
+            Some(species) => attributes_for_species(species).generate(),
+            None => Attributes::default(),
+        };
+
+        Stats {
+            health,
+            height,
+            attributes,
+        }
     }
 }
@@ -1,3 +1,4 @@
+pub mod durability;
 pub mod item_descriptors;
 pub mod item_types;
 pub mod materials;
@@ -0,0 +1,29 @@
+use crate::components::{
+    items::{Descriptor, Durability},
+    Material,
+};
+
+const DEFAULT_MAX_DURABILITY: i32 = 20;
+
+/// Starting durability for an item, taking the condition it was generated in
+/// into account. Broken items start with nothing left and other worn items
+/// start at half.
+pub fn starting_durability(material: &Option<Material>, descriptors: &[Descriptor]) -> Durability {
+    let max = material
+        .as_ref()
+        .map(|material| material.max_durability())
+        .unwrap_or(DEFAULT_MAX_DURABILITY);
+
+    let current = if descriptors.contains(&Descriptor::Broken) {
+        0
+    } else if descriptors
+        .iter()
+        .any(|descriptor| descriptor.is_condition())
+    {
+        max / 2
+    } else {
+        max
+    };
+
+    Durability { current, max }
+}
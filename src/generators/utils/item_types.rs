@@ -39,7 +39,9 @@ pub fn type_inherently_multiple(item_type: &ItemType) -> bool {
         | ItemType::Fedora
         | ItemType::Scroll
         | ItemType::Pot
-        | ItemType::Flask => false,
+        | ItemType::Flask
+        | ItemType::RepairKit
+        | ItemType::Key => false,
         ItemType::Trousers
         | ItemType::Breastplate
         | ItemType::Boots
@@ -121,6 +121,8 @@ pub fn possible_materials(item_type: &ItemType) -> Vec<Material> {
             ]
         }
         ItemType::Pot => vec![Material::Bone, Material::Ceramic],
+        ItemType::RepairKit => vec![Material::Hide, Material::Leather, Material::Linen],
+        ItemType::Key => vec![Material::Bone, Material::Iron, Material::Steel],
         ItemType::Flask => vec![
             Material::Ceramic,
             Material::Bone,
@@ -1,16 +1,28 @@
 use std::ops::RangeInclusive;
 
-use rand::Rng;
+use rand::{seq::SliceRandom, Rng};
 
 use crate::{
     actions::AttackNpc,
-    components::{damage::AttackEffect, games::GameState, PlayerCharacter, Species},
+    components::{
+        damage::AttackEffect, games::GameState, Character, Perk, PlayerCharacter, Species,
+    },
     errors::Error,
-    events::{DeadNpcBeaten, Event, NpcItemDestroyed, NpcMissed, NpcPoisoned},
+    events::{
+        DeadNpcBeaten, Event, NpcBleeding, NpcItemDestroyed, NpcMissed, NpcPoisoned,
+        PlayerCriticallyHitNpc,
+    },
     utils::{ids::parse_id, rolls::roll_d100},
 };
 
-use super::helpers::damage_npc;
+use super::{
+    damage::calculate_damage,
+    helpers::{
+        damage_npc, lifesteal_events, player_fumble_events, player_hit_wear_events,
+        roll_attack_outcome, AttackOutcome, BLEEDING_DURATION_RANGE, BLEEDING_RANGE,
+        ENCUMBERED_DODGE_PENALTY,
+    },
+};
 
 const TOXIC_RANGE: RangeInclusive<i32> = 3..=6;
 const TOXIC_DURATION_RANGE: RangeInclusive<i32> = 2..=4;
@@ -33,33 +45,77 @@ pub fn handle(
         None => return Err(Error::NpcNotFoundError(npc_id.to_string())),
     };
 
-    if npc.character.is_dead() {
+    let outcome = roll_attack_outcome(&mut rng);
+
+    if player.character.current_effects.stunned {
+        events.push(Event::PlayerStunRecovered);
+    } else if npc.character.is_dead() {
         events.push(Event::DeadNpcBeaten(DeadNpcBeaten {
             attacker_id: player.id,
             npc_id,
         }));
-    } else if npc_will_dodge(&npc.character.species) {
+    } else if npc_will_dodge(&npc.character) {
         events.push(Event::NpcMissed(NpcMissed {
             attacker_id: player.id,
             npc_id,
         }));
+    } else if matches!(outcome, AttackOutcome::Fumble) {
+        let mut fumble_events = player_fumble_events(&mut rng, player);
+        if fumble_events.is_empty() {
+            events.push(Event::NpcMissed(NpcMissed {
+                attacker_id: player.id,
+                npc_id,
+            }));
+        }
+        events.append(&mut fumble_events);
     } else {
+        let is_critical = matches!(outcome, AttackOutcome::Critical);
+        if is_critical {
+            events.push(Event::PlayerCriticallyHitNpc(PlayerCriticallyHitNpc {
+                attacker_id: player.id,
+                npc_id,
+            }));
+        }
+
         let attack_effects = player.character.attack_effects();
-        let defense = if attack_effects
-            .iter()
-            .any(|effect| matches!(effect, AttackEffect::Sharp))
-        {
-            npc.character.defense() / 2
-        } else {
-            npc.character.defense()
-        };
-        let attack = player.character.attack();
-        let attack_damage = (attack - defense).max(1);
-        let damage = attack_damage.min(npc.character.get_current_health());
-        let (mut damage_events, npc_dead) = damage_npc(player, npc, damage);
+        let attack =
+            player.character.attack() + player.progression.perk_count(&Perk::Brawler) as i32;
+        let calculation = calculate_damage(
+            &npc.character,
+            attack,
+            player.character.damage_type(),
+            &attack_effects,
+            is_critical,
+        );
+        let damage_dealt = calculation
+            .final_damage
+            .min(npc.character.get_current_health());
+        let (mut damage_events, npc_dead) = damage_npc(state, player, npc, calculation);
+        damage_events.append(&mut player_hit_wear_events(&mut rng, player, npc, npc_dead));
+        damage_events.append(&mut lifesteal_events(player, damage_dealt));
         // If npc is alive, handle any attack effects on player weapons
 
         if !npc_dead {
+            if is_critical {
+                if attack_effects
+                    .iter()
+                    .any(|effect| matches!(effect, AttackEffect::Sharp))
+                {
+                    events.push(Event::NpcBleeding(NpcBleeding {
+                        npc_id: npc.id,
+                        damage: rng.gen_range(BLEEDING_RANGE),
+                        duration: rng.gen_range(BLEEDING_DURATION_RANGE),
+                    }));
+                }
+
+                if attack_effects
+                    .iter()
+                    .any(|effect| matches!(effect, AttackEffect::Crushing))
+                {
+                    events.push(Event::NpcStunned(npc.id));
+                }
+            }
+
             for effect in attack_effects.iter() {
                 match effect {
                     AttackEffect::Toxic => {
@@ -72,8 +128,7 @@ pub fn handle(
                     AttackEffect::Acidic => {
                         if roll_d100(&mut rng, 1, 0) <= ACID_DESTROYS_ITEM_CHANCE {
                             let equipped_items = npc.character.inventory.readied_weapons();
-                            let index = rng.gen_range(0..equipped_items.len());
-                            if let Some(character_item) = equipped_items.get(index) {
+                            if let Some(character_item) = equipped_items.choose(&mut rng) {
                                 events.push(Event::NpcHitWithAcid(npc.id));
                                 events.push(Event::NpcItemDestroyed(NpcItemDestroyed {
                                     npc_id: npc.id,
@@ -96,13 +151,22 @@ pub fn handle(
 const PHANTOM_DODGE_CHANCE: i32 = 15;
 const SHADOW_DODGE_CHANCE: i32 = 25;
 
-fn npc_will_dodge(species: &Species) -> bool {
+fn npc_will_dodge(character: &Character) -> bool {
     let mut rng = rand::thread_rng();
     let dodge_roll = roll_d100(&mut rng, 1, 0);
 
-    match *species {
-        Species::Phantom => dodge_roll <= PHANTOM_DODGE_CHANCE,
-        Species::Shadow => dodge_roll <= SHADOW_DODGE_CHANCE,
-        _ => false,
-    }
+    let species_dodge_chance = match character.species {
+        Species::Phantom => PHANTOM_DODGE_CHANCE,
+        Species::Shadow => SHADOW_DODGE_CHANCE,
+        _ => 0,
+    };
+
+    let encumbrance_penalty = if character.is_encumbered() {
+        ENCUMBERED_DODGE_PENALTY
+    } else {
+        0
+    };
+
+    dodge_roll
+        <= species_dodge_chance + character.stats.attributes.dodge_chance() - encumbrance_penalty
 }
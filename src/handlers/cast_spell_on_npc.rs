@@ -1,25 +1,23 @@
-use std::ops::RangeInclusive;
-
-use rand::Rng;
+use rand::seq::SliceRandom;
 
 use crate::{
     actions::CastSpellOnNpc,
-    components::{games::GameState, spells::SpellName, PlayerCharacter},
+    components::{games::GameState, spells::SpellName, Dice, PlayerCharacter},
     errors::Error,
     events::{
         Event, NpcItemDestroyed, NpcPoisonEffectDurationChanged, NpcPoisonLevelChanged,
-        NpcPoisoned, PlayerSpellForgotten, PlayerSpellUsed,
+        NpcPoisoned, PlayerSpellUsed,
     },
     utils::{ids::parse_id, rolls::roll_d100},
 };
 
-use super::helpers::damage_npc;
+use super::{damage::calculate_damage, helpers::damage_npc, progression::SPELL_CAST_EXPERIENCE};
 
-const POISON_DART_DAMAGE_RANGE: RangeInclusive<i32> = 2..=6;
-const POISON_DART_DURATION_RANGE: RangeInclusive<i32> = 1..=4;
+const POISON_DART_DAMAGE: Dice = Dice::new(1, 5, 1);
+const POISON_DART_DURATION: Dice = Dice::new(1, 4, 0);
 
-const POISON_CLOUD_DAMAGE_RANGE: RangeInclusive<i32> = 1..=8;
-const POISON_CLOUD_DURATION_RANGE: RangeInclusive<i32> = 2..=5;
+const POISON_CLOUD_DAMAGE: Dice = Dice::new(1, 8, 0);
+const POISON_CLOUD_DURATION: Dice = Dice::new(1, 4, 1);
 
 const ACID_DESTROYS_ITEM_CHANCE: i32 = 75;
 
@@ -34,6 +32,11 @@ pub fn handle(
         None => return Err(Error::SpellNotFoundError(spell_id.to_string())),
     };
 
+    // Spent spells stay in memory until a rest brings them back.
+    if learned_spell.spell.uses <= 0 {
+        return Err(Error::SpellHasNoUsesError(spell_id.to_string()));
+    }
+
     let room = state.current_room();
     let npc_id = parse_id(&cast_spell_on_npc.npc_id)?;
     let npc = match room.find_npc(&npc_id) {
@@ -44,29 +47,27 @@ pub fn handle(
     let mut events: Vec<Event> = Vec::new();
 
     events.push(Event::PlayerSpellUsed(PlayerSpellUsed { spell_id }));
+    events.push(Event::PlayerGainedExperience(SPELL_CAST_EXPERIENCE));
 
     match learned_spell.spell.name {
-        SpellName::ElectricBlast => {
-            let spell_damage = learned_spell.spell.damage();
-            let damage = spell_damage.min(npc.character.get_current_health());
-            let (mut damage_events, _) = damage_npc(player, npc, damage);
-            events.append(&mut damage_events);
-        }
-        SpellName::RagingFireball => {
-            let spell_damage = if npc.character.current_effects.covered_in_oil {
-                learned_spell.spell.damage() * 2
-            } else {
-                learned_spell.spell.damage()
-            };
-            let damage = spell_damage.min(npc.character.get_current_health());
-            let (mut damage_events, _) = damage_npc(player, npc, damage);
+        // Oil soaked NPCs are vulnerable to fire, which is handled with the
+        // rest of their resistances.
+        SpellName::ElectricBlast | SpellName::RagingFireball => {
+            let calculation = calculate_damage(
+                &npc.character,
+                learned_spell.spell.damage(),
+                learned_spell.spell.name.damage_type().unwrap_or_default(),
+                &[],
+                false,
+            );
+            let (mut damage_events, _) = damage_npc(state, player, npc, calculation);
             events.append(&mut damage_events);
         }
         SpellName::PoisonDart => {
             if npc.character.current_effects.poison.is_none() {
                 let mut rng = rand::thread_rng();
-                let damage = rng.gen_range(POISON_CLOUD_DAMAGE_RANGE);
-                let duration = rng.gen_range(POISON_CLOUD_DURATION_RANGE);
+                let damage = POISON_CLOUD_DAMAGE.roll(&mut rng);
+                let duration = POISON_CLOUD_DURATION.roll(&mut rng);
 
                 events.push(Event::NpcPoisoned(NpcPoisoned {
                     npc_id,
@@ -75,8 +76,8 @@ pub fn handle(
                 }));
             } else {
                 let mut rng = rand::thread_rng();
-                let damage = rng.gen_range(POISON_CLOUD_DAMAGE_RANGE);
-                let duration = rng.gen_range(POISON_CLOUD_DURATION_RANGE);
+                let damage = POISON_CLOUD_DAMAGE.roll(&mut rng);
+                let duration = POISON_CLOUD_DURATION.roll(&mut rng);
 
                 events.push(Event::NpcPoisonLevelChanged(NpcPoisonLevelChanged {
                     npc_id,
@@ -91,8 +92,8 @@ pub fn handle(
             for npc_position in state.current_room().npc_positions.iter() {
                 if npc_position.npc.character.current_effects.poison.is_none() {
                     let mut rng = rand::thread_rng();
-                    let damage = rng.gen_range(POISON_DART_DAMAGE_RANGE);
-                    let duration = rng.gen_range(POISON_DART_DURATION_RANGE);
+                    let damage = POISON_DART_DAMAGE.roll(&mut rng);
+                    let duration = POISON_DART_DURATION.roll(&mut rng);
 
                     events.push(Event::NpcPoisoned(NpcPoisoned {
                         npc_id: npc_position.npc.id,
@@ -101,8 +102,8 @@ pub fn handle(
                     }));
                 } else {
                     let mut rng = rand::thread_rng();
-                    let damage = rng.gen_range(POISON_DART_DAMAGE_RANGE);
-                    let duration = rng.gen_range(POISON_DART_DURATION_RANGE);
+                    let damage = POISON_DART_DAMAGE.roll(&mut rng);
+                    let duration = POISON_DART_DURATION.roll(&mut rng);
 
                     events.push(Event::NpcPoisonLevelChanged(NpcPoisonLevelChanged {
                         npc_id: npc_position.npc.id,
@@ -121,8 +122,7 @@ pub fn handle(
             let mut rng = rand::thread_rng();
             if roll_d100(&mut rng, 1, 0) <= ACID_DESTROYS_ITEM_CHANCE {
                 let equipped_items = npc.character.inventory.readied_weapons();
-                let index = rng.gen_range(0..equipped_items.len());
-                if let Some(character_item) = equipped_items.get(index) {
+                if let Some(character_item) = equipped_items.choose(&mut rng) {
                     events.push(Event::NpcHitWithAcid(npc_id));
                     events.push(Event::NpcItemDestroyed(NpcItemDestroyed {
                         npc_id,
@@ -134,17 +134,12 @@ pub fn handle(
         // TODO: There are non-damage spells that someone could cast on NPCs.
         SpellName::GreatHeal
         | SpellName::Heal
+        | SpellName::Identify
         | SpellName::Phoenix
         | SpellName::QuickHeal
         | SpellName::Retribution
         | SpellName::TinyShield => {}
     }
 
-    if learned_spell.spell.uses - 1 == 0 {
-        events.push(Event::PlayerSpellForgotten(PlayerSpellForgotten {
-            spell_id,
-        }));
-    }
-
     Ok(events)
 }
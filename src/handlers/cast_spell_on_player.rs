@@ -1,19 +1,21 @@
-use rand::Rng;
+use rand::seq::SliceRandom;
 
 use crate::{
     actions::CastSpellOnPlayer,
     components::{
         spells::SpellName,
-        PlayerCharacter, {Attack, Defense},
+        DamageType, Dice, PlayerCharacter, {Attack, Defense},
     },
     errors::Error,
     events::{
-        Event, PlayerGainsRetributionAura, PlayerGainsShieldAura, PlayerHealed, PlayerHit,
-        PlayerPoisoned, PlayerSpellForgotten, PlayerSpellUsed,
+        Event, ItemIdentified, PlayerGainsRetributionAura, PlayerGainsShieldAura, PlayerHealed,
+        PlayerHit, PlayerPoisoned, PlayerSpellUsed,
     },
     utils::{ids::parse_id, rolls::roll_d100},
 };
 
+use super::{damage::calculate_damage, progression::SPELL_CAST_EXPERIENCE};
+
 const ACID_DESTROYS_ITEM_CHANCE: i32 = 75;
 
 pub fn handle(
@@ -26,16 +28,29 @@ pub fn handle(
         None => return Err(Error::SpellNotFoundError(spell_id.to_string())),
     };
 
+    // Spent spells stay in memory until a rest brings them back.
+    if learned_spell.spell.uses <= 0 {
+        return Err(Error::SpellHasNoUsesError(spell_id.to_string()));
+    }
+
     let mut events: Vec<Event> = Vec::new();
 
     events.push(Event::PlayerSpellUsed(PlayerSpellUsed { spell_id }));
+    events.push(Event::PlayerGainedExperience(SPELL_CAST_EXPERIENCE));
 
     match learned_spell.spell.name {
         SpellName::ElectricBlast | SpellName::RagingFireball => {
-            let damage = learned_spell.spell.damage();
+            let calculation = calculate_damage(
+                &player.character,
+                learned_spell.spell.damage(),
+                learned_spell.spell.name.damage_type().unwrap_or_default(),
+                &[],
+                false,
+            );
             events.push(Event::PlayerHit(PlayerHit {
                 attacker_id: player.id,
-                damage,
+                damage: calculation.final_damage,
+                calculation: Some(calculation),
             }));
         }
         SpellName::Heal | SpellName::QuickHeal => {
@@ -44,11 +59,18 @@ pub fn handle(
                 .min(player.character.stats.health.max - player.character.stats.health.current);
             events.push(Event::PlayerHealed(PlayerHealed { damage_healed }));
         }
+        SpellName::Identify => {
+            for character_item in player.character.inventory.equipment.iter() {
+                events.push(Event::ItemIdentified(ItemIdentified {
+                    item_id: character_item.item.id,
+                }));
+            }
+        }
         SpellName::Phoenix => events.push(Event::PlayerGainsResurrectionAura),
         SpellName::Retribution => {
             let attack = learned_spell.spell.attack.clone().unwrap_or(Attack {
-                num_rolls: 2,
-                modifier: 0,
+                dice: Dice::d6(2, 0),
+                damage_type: DamageType::Lightning,
                 effects: Vec::new(),
             });
             events.push(Event::PlayerGainsRetributionAura(
@@ -81,8 +103,7 @@ pub fn handle(
             let mut rng = rand::thread_rng();
             if roll_d100(&mut rng, 1, 0) <= ACID_DESTROYS_ITEM_CHANCE {
                 let equipped_items = player.character.inventory.readied_weapons();
-                let index = rng.gen_range(0..equipped_items.len());
-                if let Some(character_item) = equipped_items.get(index) {
+                if let Some(character_item) = equipped_items.choose(&mut rng) {
                     events.push(Event::PlayerHitWithAcid);
                     events.push(Event::PlayerItemDestroyed(character_item.item.id));
                 }
@@ -90,11 +111,46 @@ pub fn handle(
         }
     }
 
-    if learned_spell.spell.uses - 1 == 0 {
-        events.push(Event::PlayerSpellForgotten(PlayerSpellForgotten {
-            spell_id,
-        }));
-    }
-
     Ok(events)
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use crate::{
+        actions::CastSpellOnPlayer,
+        components::spells::{LearnedSpell, Spell, SpellName},
+        events::Event,
+        generators::{generator::Generator, players::player_generator},
+    };
+
+    #[test]
+    fn acid_splash_without_weapons_destroys_nothing() {
+        let mut player = player_generator(None, None, None).generate();
+        player.character.inventory.equipment.clear();
+        let spell_id = Uuid::new_v4();
+        player.character.spell_memory.spells = vec![LearnedSpell {
+            id: spell_id,
+            spell: Spell {
+                name: SpellName::AcidSplash,
+                attack: None,
+                defense: None,
+                uses: 1,
+            },
+            learned_at: Utc::now(),
+            learned_uses: 1,
+        }];
+        let cast = CastSpellOnPlayer {
+            spell_id: spell_id.to_string(),
+        };
+
+        for _ in 0..50 {
+            let events = super::handle(&cast, &player).unwrap();
+            assert!(!events
+                .iter()
+                .any(|event| matches!(event, Event::PlayerItemDestroyed(_))));
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{
+    actions::ChoosePerk,
+    components::{Perk, PlayerCharacter},
+    errors::Error,
+    events::{Event, PlayerPerkChosen},
+};
+
+const HARDY_MAX_HEALTH: i32 = 5;
+
+pub fn handle(choose_perk: &ChoosePerk, player: &PlayerCharacter) -> Result<Vec<Event>, Error> {
+    if !player.progression.can_choose_perk(&choose_perk.perk) {
+        return Err(Error::PerkNotAvailableError);
+    }
+
+    let mut events: Vec<Event> = vec![Event::PlayerPerkChosen(PlayerPerkChosen {
+        perk: choose_perk.perk.clone(),
+    })];
+
+    if matches!(choose_perk.perk, Perk::Hardy) {
+        events.push(Event::PlayerMaxHealthChanged(HARDY_MAX_HEALTH));
+    }
+
+    Ok(events)
+}
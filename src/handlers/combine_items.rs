@@ -0,0 +1,247 @@
+use uuid::Uuid;
+
+use crate::{
+    actions::CombineItems,
+    components::{
+        damage::AttackEffect,
+        items::{
+            CharacterItem, FirebombEffect, LocationTag, Recipe, RecipeName, Throwable,
+            ThrowableEffect, ThrowableEffectName,
+        },
+        Dice, PlayerCharacter,
+    },
+    errors::Error,
+    events::{Event, PlayerItemsCombined},
+    utils::ids::parse_id,
+};
+
+use super::helpers::take_one_from_stack;
+
+const FIREBOMB_DAMAGE_DICE: usize = 2;
+
+pub fn handle(combine_items: &CombineItems, player: &PlayerCharacter) -> Result<Vec<Event>, Error> {
+    let mut items: Vec<CharacterItem> = Vec::new();
+    for id in combine_items.item_ids.iter() {
+        let item_id = parse_id(id)?;
+        match player.character.find_item(&item_id) {
+            Some(it) => items.push(it),
+            None => return Err(Error::ItemNotFoundError(item_id.to_string())),
+        }
+    }
+
+    let (recipe, ingredients) = match Recipe::for_items(&items) {
+        Some(it) => it,
+        None => return Err(Error::NoMatchingRecipeError),
+    };
+
+    let mut events: Vec<Event> = Vec::new();
+    let mut consumed_item_ids: Vec<Uuid> = Vec::new();
+    for ingredient in ingredients.iter() {
+        let (item_id, mut split_events) = take_one_from_stack(ingredient);
+        events.append(&mut split_events);
+        consumed_item_ids.push(item_id);
+    }
+
+    let base = match ingredients.first() {
+        Some(it) => it,
+        None => return Err(Error::NoMatchingRecipeError),
+    };
+
+    events.push(Event::PlayerItemsCombined(PlayerItemsCombined {
+        crafted_item: craft(&recipe.name, base, &consumed_item_ids),
+        recipe: recipe.name,
+        consumed_item_ids,
+    }));
+
+    Ok(events)
+}
+
+/// Builds the new item from the base ingredient of the recipe.
+fn craft(
+    recipe_name: &RecipeName,
+    base: &CharacterItem,
+    consumed_item_ids: &[Uuid],
+) -> CharacterItem {
+    let mut crafted = base.clone();
+    crafted.quantity = 1;
+
+    match recipe_name {
+        RecipeName::Firebomb => {
+            crafted.item.id = Uuid::new_v4();
+            crafted.item.throwable = Some(Throwable {
+                uses: 1,
+                effect: ThrowableEffect {
+                    name: ThrowableEffectName::Firebomb,
+                    oil_splash_effect: None,
+                    firebomb_effect: Some(FirebombEffect {
+                        damage: Dice::d6(FIREBOMB_DAMAGE_DICE, 0),
+                    }),
+                },
+            });
+            crafted.equipped_location = LocationTag::Packed;
+            crafted.at_the_ready = false;
+        }
+        RecipeName::ToxicBlade => {
+            // The blade keeps its id unless it was split off a stack.
+            if let Some(id) = consumed_item_ids.first() {
+                crafted.item.id = *id;
+            }
+            if let Some(attack) = crafted.item.attack.as_mut() {
+                if !attack.effects.contains(&AttackEffect::Toxic) {
+                    attack.effects.push(AttackEffect::Toxic);
+                }
+            }
+        }
+    }
+
+    crafted
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        actions::CombineItems,
+        components::{
+            damage::AttackEffect,
+            items::{
+                CharacterItem, Consumable, ConsumableEffect, ConsumableEffectName, Item, ItemType,
+                LocationTag, OilSplashEffect, PoisonEffect, RecipeName, Throwable, ThrowableEffect,
+                ThrowableEffectName,
+            },
+            PlayerCharacter, Tag,
+        },
+        errors::Error,
+        events::{apply_events, Event},
+        generators::{
+            game::game_generator, generator::Generator, items::item_generator_for_level,
+            players::player_generator,
+        },
+    };
+
+    use super::handle;
+
+    fn add_item(player: &mut PlayerCharacter, item: Item) -> Uuid {
+        let item_id = item.id;
+        player.character.inventory.equipment.push(CharacterItem {
+            item,
+            is_hidden: false,
+            equipped_location: LocationTag::Packed,
+            is_multiple: false,
+            at_the_ready: false,
+            quantity: 1,
+        });
+        item_id
+    }
+
+    fn poison(player: &mut PlayerCharacter) -> Uuid {
+        let mut item = item_generator_for_level(&ItemType::Flask, false, 1).generate();
+        item.consumable = Some(Consumable {
+            uses: 1,
+            effect: ConsumableEffect {
+                name: ConsumableEffectName::Poison,
+                learn_spell_effect: None,
+                healing_effect: None,
+                repair_effect: None,
+                poison_effect: Some(PoisonEffect {
+                    damage: 2,
+                    duration: 3,
+                }),
+            },
+        });
+        add_item(player, item)
+    }
+
+    fn oil(player: &mut PlayerCharacter) -> Uuid {
+        let mut item = item_generator_for_level(&ItemType::Pot, false, 1).generate();
+        item.throwable = Some(Throwable {
+            uses: 1,
+            effect: ThrowableEffect {
+                name: ThrowableEffectName::OilSplash,
+                oil_splash_effect: Some(OilSplashEffect {
+                    covers_all_enemies: false,
+                }),
+                firebomb_effect: None,
+            },
+        });
+        add_item(player, item)
+    }
+
+    fn combine(item_ids: &[Uuid]) -> CombineItems {
+        CombineItems {
+            item_ids: item_ids.iter().map(|id| id.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn ingredients_match_in_any_order() {
+        let state = game_generator().generate();
+        let mut player = player_generator(None, None, None).generate();
+        let dagger = item_generator_for_level(&ItemType::Dagger, false, 1).generate();
+        let dagger_id = add_item(&mut player, dagger);
+        let poison_id = poison(&mut player);
+
+        let events = handle(&combine(&[poison_id, dagger_id]), &player).unwrap();
+        assert!(events.iter().any(|event| matches!(
+            event,
+            Event::PlayerItemsCombined(combined) if combined.recipe == RecipeName::ToxicBlade
+        )));
+
+        let (_, player) = apply_events(&events, &state, &player);
+        assert!(player.character.find_item(&poison_id).is_none());
+        let blade = player.character.find_item(&dagger_id).unwrap();
+        assert!(blade
+            .item
+            .attack
+            .unwrap()
+            .effects
+            .contains(&AttackEffect::Toxic));
+    }
+
+    #[test]
+    fn oil_and_cloth_make_a_firebomb() {
+        let state = game_generator().generate();
+        let mut player = player_generator(None, None, None).generate();
+        let oil_id = oil(&mut player);
+        let mut rag = item_generator_for_level(&ItemType::Shirt, false, 1).generate();
+        rag.tags.push(Tag::Cloth);
+        let rag_id = add_item(&mut player, rag);
+
+        let events = handle(&combine(&[rag_id, oil_id]), &player).unwrap();
+        let (_, player) = apply_events(&events, &state, &player);
+
+        assert!(player.character.find_item(&oil_id).is_none());
+        assert!(player.character.find_item(&rag_id).is_none());
+        assert!(player.character.inventory.equipment.iter().any(|item| {
+            item.item
+                .throwable
+                .as_ref()
+                .map(|throwable| throwable.effect.name == ThrowableEffectName::Firebomb)
+                .unwrap_or_default()
+        }));
+    }
+
+    #[test]
+    fn every_item_has_to_be_an_ingredient() {
+        let mut player = player_generator(None, None, None).generate();
+        let dagger = item_generator_for_level(&ItemType::Dagger, false, 1).generate();
+        let dagger_id = add_item(&mut player, dagger);
+        let other_dagger = item_generator_for_level(&ItemType::Dagger, false, 1).generate();
+        let other_dagger_id = add_item(&mut player, other_dagger);
+        let poison_id = poison(&mut player);
+
+        assert!(matches!(
+            handle(&combine(&[dagger_id, other_dagger_id]), &player),
+            Err(Error::NoMatchingRecipeError)
+        ));
+        assert!(matches!(
+            handle(&combine(&[dagger_id, poison_id, other_dagger_id]), &player),
+            Err(Error::NoMatchingRecipeError)
+        ));
+        assert!(matches!(
+            handle(&combine(&[dagger_id, Uuid::new_v4()]), &player),
+            Err(Error::ItemNotFoundError(_))
+        ));
+    }
+}
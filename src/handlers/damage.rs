@@ -0,0 +1,158 @@
+use crate::components::{
+    damage::AttackEffect, Character, DamageCalculation, DamageType, Resistance,
+};
+
+pub const CRITICAL_DAMAGE_MULTIPLIER: i32 = 2;
+
+/// Works out how much of a hit actually lands on the target. Armour soaks up
+/// physical damage, critical hits double what gets through, and then the
+/// target's resistances and vulnerabilities are applied.
+pub fn calculate_damage(
+    target: &Character,
+    base_damage: i32,
+    damage_type: DamageType,
+    attack_effects: &[AttackEffect],
+    is_critical: bool,
+) -> DamageCalculation {
+    let armour = if !damage_type.is_physical() {
+        0
+    } else if attack_effects
+        .iter()
+        .any(|effect| matches!(effect, AttackEffect::Sharp))
+    {
+        // Sharpness cuts through armour
+        target.defense() / 2
+    } else {
+        target.defense()
+    };
+
+    let mut damage = (base_damage - armour).max(1);
+    if is_critical {
+        damage *= CRITICAL_DAMAGE_MULTIPLIER;
+    }
+
+    let modifiers = target.damage_modifiers(&damage_type);
+    let resistant = modifiers
+        .iter()
+        .filter(|modifier| modifier.resistance == Resistance::Resistant)
+        .count();
+    let vulnerable = modifiers
+        .iter()
+        .filter(|modifier| modifier.resistance == Resistance::Vulnerable)
+        .count();
+
+    let final_damage = if modifiers
+        .iter()
+        .any(|modifier| modifier.resistance == Resistance::Immune)
+    {
+        0
+    } else if resistant > vulnerable {
+        (damage / 2).max(1)
+    } else if vulnerable > resistant {
+        damage * 2
+    } else {
+        damage
+    };
+
+    DamageCalculation {
+        damage_type,
+        base_damage,
+        armour,
+        is_critical,
+        modifiers,
+        final_damage,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        components::{
+            damage::AttackEffect,
+            items::{CharacterItem, ItemType, LocationTag},
+            Character, DamageType, Defense, Effects, LifeModifier, Species,
+        },
+        generators::{
+            generator::Generator, items::item_generator_for_level, non_players::npc_generator,
+        },
+    };
+
+    use super::calculate_damage;
+
+    fn bare_character(species: Species, life_modifier: Option<LifeModifier>) -> Character {
+        let mut character = npc_generator(None).generate().character;
+        character.species = species;
+        character.life_modifier = life_modifier;
+        character.inventory.equipment.clear();
+        character.current_effects = Effects::default();
+        character
+    }
+
+    #[test]
+    fn resistances_halve_and_vulnerabilities_double_damage() {
+        let ogre = bare_character(Species::Ogre, None);
+
+        let blunt = calculate_damage(&ogre, 10, DamageType::Blunt, &[], false);
+        assert_eq!(blunt.final_damage, 5);
+        assert_eq!(blunt.modifiers.len(), 1);
+
+        let piercing = calculate_damage(&ogre, 10, DamageType::Piercing, &[], false);
+        assert_eq!(piercing.final_damage, 20);
+
+        let fire = calculate_damage(&ogre, 10, DamageType::Fire, &[], false);
+        assert_eq!(fire.final_damage, 10);
+        assert!(fire.modifiers.is_empty());
+    }
+
+    #[test]
+    fn immunity_wins_and_opposing_modifiers_cancel_out() {
+        let zombie = bare_character(Species::Frogkin, Some(LifeModifier::Zombie));
+        let poison = calculate_damage(&zombie, 10, DamageType::Poison, &[], true);
+        assert_eq!(poison.final_damage, 0);
+
+        let skeleton = bare_character(Species::Ogre, Some(LifeModifier::Skeleton));
+        let blunt = calculate_damage(&skeleton, 10, DamageType::Blunt, &[], false);
+        assert_eq!(blunt.modifiers.len(), 2);
+        assert_eq!(blunt.final_damage, 10);
+    }
+
+    #[test]
+    fn armour_only_soaks_physical_damage_and_sharp_attacks_cut_through_it() {
+        let mut character = bare_character(Species::Goblin, None);
+        let mut character_item = CharacterItem {
+            item: item_generator_for_level(&ItemType::Breastplate, true, 1).generate(),
+            is_hidden: false,
+            equipped_location: LocationTag::Body,
+            is_multiple: false,
+            at_the_ready: false,
+            quantity: 1,
+        };
+        character_item.item.defense = Some(Defense {
+            damage_resistance: 4,
+        });
+        character_item.item.material = None;
+        character_item.item.durability = None;
+        character.inventory.equipment.push(character_item);
+
+        let blunt = calculate_damage(&character, 10, DamageType::Blunt, &[], false);
+        assert_eq!(blunt.armour, 4);
+        assert_eq!(blunt.final_damage, 6);
+
+        let sharp = calculate_damage(
+            &character,
+            10,
+            DamageType::Slashing,
+            &[AttackEffect::Sharp],
+            true,
+        );
+        assert_eq!(sharp.armour, 2);
+        assert_eq!(sharp.final_damage, 16);
+
+        let lightning = calculate_damage(&character, 10, DamageType::Lightning, &[], false);
+        assert_eq!(lightning.armour, 0);
+        assert_eq!(lightning.final_damage, 10);
+
+        let glancing = calculate_damage(&character, 2, DamageType::Blunt, &[], false);
+        assert_eq!(glancing.final_damage, 1);
+    }
+}
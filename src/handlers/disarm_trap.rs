@@ -0,0 +1,200 @@
+use uuid::Uuid;
+
+use crate::{
+    actions::DisarmTrap,
+    components::{games::GameState, PlayerCharacter, Trap},
+    errors::Error,
+    events::{Event, TrapDisarmed},
+    utils::{ids::parse_id, rolls::roll_d6},
+};
+
+use super::{helpers::spring_trap, progression::TRAP_DISARMED_EXPERIENCE};
+
+const DISARM_TRAP_CHANCE: i32 = 3;
+
+pub fn handle(
+    disarm_trap: &DisarmTrap,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
+    let (trap, fixture_id, exit_id) = find_trap(state, disarm_trap)?;
+
+    let mut rng = rand::thread_rng();
+    let modifier = player.character.stats.attributes.agility_modifier();
+
+    if roll_d6(&mut rng, 1, modifier) >= DISARM_TRAP_CHANCE {
+        return Ok(vec![
+            Event::TrapDisarmed(TrapDisarmed {
+                fixture_id,
+                exit_id,
+            }),
+            Event::PlayerGainedExperience(TRAP_DISARMED_EXPERIENCE),
+        ]);
+    }
+
+    Ok(spring_trap(trap, player, fixture_id, exit_id))
+}
+
+/// A trap can only be disarmed once the player knows it's there.
+fn find_trap<'a>(
+    state: &'a GameState,
+    disarm_trap: &DisarmTrap,
+) -> Result<(&'a Trap, Option<Uuid>, Option<Uuid>), Error> {
+    if let Some(fixture_id) = &disarm_trap.fixture_id {
+        let fixture_id = parse_id(fixture_id)?;
+        let fixture_position = match state.current_room().find_fixture(&fixture_id) {
+            Some(it) => it,
+            None => return Err(Error::FixtureNotFoundError(fixture_id.to_string())),
+        };
+
+        if !state.fixture_knowledge(&fixture_id).knows_has_trap {
+            return Err(Error::FixtureHasTrapUnknown(fixture_id.to_string()));
+        }
+
+        return match &fixture_position.fixture.trap {
+            Some(trap) => Ok((trap, Some(fixture_id), None)),
+            None => Err(Error::FixtureHasNoTrap(fixture_id.to_string())),
+        };
+    }
+
+    if let Some(exit_id) = &disarm_trap.exit_id {
+        let exit_id = parse_id(exit_id)?;
+        if !state
+            .current_room()
+            .exits
+            .iter()
+            .any(|exit| exit.id.eq(&exit_id) && state.knows_exit(exit))
+        {
+            return Err(Error::ExitNotFoundError(exit_id.to_string()));
+        }
+
+        if !state.exit_knowledge(&exit_id).knows_has_trap {
+            return Err(Error::ExitHasTrapUnknown(exit_id.to_string()));
+        }
+
+        return match state.world.exit_trap(&exit_id) {
+            Some(trap) => Ok((trap, None, Some(exit_id))),
+            None => Err(Error::ExitHasNoTrap(exit_id.to_string())),
+        };
+    }
+
+    Err(Error::NoTrapError)
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        actions::DisarmTrap,
+        components::{
+            fixtures::FixtureType, games::GameState, rooms::FixturePosition, Dice, Trap, TrapType,
+        },
+        errors::Error,
+        events::{apply_events, Event},
+        generators::{
+            fixtures::get_generator, game::game_generator, generator::Generator,
+            players::player_generator,
+        },
+    };
+
+    fn harmless_trap() -> Trap {
+        Trap {
+            trap_type: TrapType::FallingBlock,
+            damage: Dice::new(1, 1, -1),
+        }
+    }
+
+    fn trap_exit(state: &mut GameState) -> Uuid {
+        let exit_id = state.current_room().exits[0].id;
+        for exit in state.world.exit_sides_mut(exit_id) {
+            exit.hidden = false;
+            exit.trap = Some(harmless_trap());
+        }
+
+        exit_id
+    }
+
+    #[test]
+    fn exit_traps_have_to_be_found_first() {
+        let mut state = game_generator().generate();
+        let player = player_generator(None, None, None).generate();
+        let exit_id = trap_exit(&mut state);
+
+        let result = super::handle(
+            &DisarmTrap {
+                fixture_id: None,
+                exit_id: Some(exit_id.to_string()),
+            },
+            &state,
+            &player,
+        );
+
+        assert!(matches!(result, Err(Error::ExitHasTrapUnknown(_))));
+    }
+
+    #[test]
+    fn known_exit_traps_are_disarmed_or_sprung() {
+        let mut state = game_generator().generate();
+        let player = player_generator(None, None, None).generate();
+        let exit_id = trap_exit(&mut state);
+        let mut knowledge = state.exit_knowledge(&exit_id);
+        knowledge.knows_has_trap = true;
+        state.set_exit_knowledge(exit_id, knowledge);
+
+        let events = super::handle(
+            &DisarmTrap {
+                fixture_id: None,
+                exit_id: Some(exit_id.to_string()),
+            },
+            &state,
+            &player,
+        )
+        .unwrap();
+        let (new_state, _) = apply_events(&events, &state, &player);
+
+        assert!(events.iter().any(|event| match event {
+            Event::TrapDisarmed(disarmed) => disarmed.exit_id.eq(&Some(exit_id)),
+            Event::TrapSprung(sprung) => sprung.exit_id.eq(&Some(exit_id)),
+            _ => false,
+        }));
+        assert!(new_state.world.exit_trap(&exit_id).is_none());
+    }
+
+    #[test]
+    fn known_fixture_traps_are_disarmed_or_sprung() {
+        let mut state = game_generator().generate();
+        let player = player_generator(None, None, None).generate();
+        let mut chest = get_generator(&FixtureType::Chest, false).generate();
+        chest.trap = Some(harmless_trap());
+        let fixture_id = chest.id;
+        state
+            .current_room_mut()
+            .fixture_positions
+            .push(FixturePosition {
+                group_descriptor: None,
+                fixture: chest,
+                position_descriptor: None,
+            });
+        let mut knowledge = state.fixture_knowledge(&fixture_id);
+        knowledge.knows_has_trap = true;
+        state.set_fixture_knowledge(fixture_id, knowledge);
+
+        let events = super::handle(
+            &DisarmTrap {
+                fixture_id: Some(fixture_id.to_string()),
+                exit_id: None,
+            },
+            &state,
+            &player,
+        )
+        .unwrap();
+        let (new_state, _) = apply_events(&events, &state, &player);
+
+        assert!(new_state
+            .current_room()
+            .find_fixture(&fixture_id)
+            .and_then(|fixture_position| fixture_position.fixture.trap.as_ref())
+            .is_none());
+    }
+}
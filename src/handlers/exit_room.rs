@@ -1,13 +1,37 @@
 use crate::{
     actions::ExitRoom,
-    components::games::GameState,
+    components::{
+        games::GameState,
+        items::{CharacterItem, FixtureItem, Item, LocationTag},
+        rooms::{ExitType, Room},
+        worlds::{danger_level_for_depth, next_depth, room_budget_for_depth, FINAL_DEPTH},
+        Perk, PlayerCharacter,
+    },
     errors::Error,
-    events::{Event, RoomExited, RoomFirstSeen, RoomGenerated},
-    generators::{generator::Generator, rooms::random_room_generator_for_danger_level},
-    utils::ids::parse_id,
+    events::{
+        apply_events, ArtifactSpawned, BossEncountered, Event, FloorReached, PlayerFleeFailed,
+        RoomExited, RoomFirstSeen, RoomGenerated,
+    },
+    generators::{
+        artifacts::{artifact_item, roll_artifact},
+        bosses::{make_boss_lair, roll_boss},
+        generator::Generator,
+        rooms::room_generator_for_floor,
+    },
+    utils::{ids::parse_id, rolls::roll_d6},
 };
 
-pub fn handle(exit_room: &ExitRoom, state: &GameState) -> Result<Vec<Event>, Error> {
+use super::{
+    helpers::spring_trap, npc_movement::pursue, progression::ROOM_FIRST_SEEN_EXPERIENCE, NpcAction,
+};
+
+const FLEE_CHANCE: i32 = 3;
+
+pub fn handle(
+    exit_room: &ExitRoom,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
     // We need to check the exit maps for one with the room_id and exit.
     // If there's another exit id then find the room with that exit id and move
     // the player to that room.
@@ -16,7 +40,6 @@ pub fn handle(exit_room: &ExitRoom, state: &GameState) -> Result<Vec<Event>, Err
     // Then we will need to move the player to that room and exit their
     // current room.
 
-    let mut events: Vec<Event> = Vec::new();
     let exit_id = parse_id(&exit_room.exit_id)?;
     let exit_map = match state
         .world
@@ -28,19 +51,128 @@ pub fn handle(exit_room: &ExitRoom, state: &GameState) -> Result<Vec<Event>, Err
         None => return Err(Error::ExitNotFoundError(exit_id.to_string())),
     };
 
+    let exit = match state
+        .current_room()
+        .exits
+        .iter()
+        .find(|exit| exit.id.eq(&exit_id))
+    {
+        Some(it) => it,
+        None => return Err(Error::ExitNotFoundError(exit_id.to_string())),
+    };
+
+    // An exit the player hasn't found yet might as well not be there.
+    if !state.knows_exit(exit) {
+        return Err(Error::ExitNotFoundError(exit_id.to_string()));
+    }
+
+    if state.world.exit_is_locked(&exit_id) {
+        return Err(Error::ExitLocked(exit_id.to_string()));
+    }
+
+    // Turning your back on a fight gives whoever is ready for it a free
+    // swing, and the player only gets away if they live through it.
+    let mut events: Vec<Event> = match state.current_room().awake_npcs().first() {
+        Some(npc) => super::handle_npc_action(&NpcAction::AttackPlayer(npc.id), state, player)?,
+        None => Vec::new(),
+    };
+    let (attacked_state, attacked_player) = apply_events(&events, state, player);
+    if attacked_player.character.is_dead() {
+        return Ok(events);
+    }
+    let state = &attacked_state;
+    let player = &attacked_player;
+
+    // Slipping past anyone ready to fight takes some footwork, and the more
+    // of them there are the harder it gets.
+    let awake_npcs = state.current_room().awake_npcs().len() as i32;
+    if awake_npcs > 0 {
+        let mut rng = rand::thread_rng();
+        let mut modifier = player.character.stats.attributes.agility_modifier()
+            + player.progression.perk_count(&Perk::Nimble) as i32
+            - (awake_npcs - 1);
+        if player.character.is_encumbered() {
+            modifier -= 1;
+        }
+
+        if roll_d6(&mut rng, 1, modifier) < FLEE_CHANCE {
+            events.push(Event::PlayerFleeFailed(PlayerFleeFailed { exit_id }));
+            return Ok(events);
+        }
+    }
+
+    if let Some(trap) = state.world.exit_trap(&exit_id) {
+        events.append(&mut spring_trap(trap, player, None, Some(exit_id)));
+        if events
+            .iter()
+            .any(|event| matches!(event, Event::PlayerKilled(_)))
+        {
+            return Ok(events);
+        }
+    }
+
     let other_room_id = exit_map.other_room_id(state.current_room_id);
-    let room_id = match other_room_id {
-        Some(id) => id,
+    let (room_id, boss_in_room) = match other_room_id {
+        Some(id) => {
+            let boss_in_room = state
+                .world
+                .rooms
+                .iter()
+                .find(|room| room.id.eq(&id))
+                .and_then(|room| room.alive_boss())
+                .and_then(|npc| npc.boss_kind().map(|boss| (boss, npc.id)));
+            (id, boss_in_room)
+        }
         None => {
-            let room_generator =
-                random_room_generator_for_danger_level(Some(exit_id), state.danger_level);
-            let room = room_generator.generate();
+            let mut exit_type = exit.exit_type.clone();
+            let mut depth = next_depth(state.current_room().depth, exit_type.depth_change());
+            // Once a floor is full, the ways out of it that are still
+            // unexplored all turn out to lead down the stairs.
+            if exit_type.depth_change() == 0 && depth < FINAL_DEPTH && is_floor_full(state, depth) {
+                exit_type = ExitType::StaircaseDown;
+                depth = next_depth(depth, exit_type.depth_change());
+            }
+            let floor_is_full = is_floor_full(state, depth);
+            let mut room =
+                room_generator_for_floor(exit_id, exit_type, depth, floor_is_full).generate();
             let room_id = room.id;
+
+            if depth > state.world.deepest_depth() {
+                events.push(Event::FloorReached(FloorReached {
+                    depth,
+                    is_final: depth == FINAL_DEPTH,
+                }));
+            }
+
+            let danger_level = danger_level_for_depth(depth);
+            if danger_level > state.danger_level {
+                events.push(Event::GameDangerLevelIncreased(
+                    danger_level - state.danger_level,
+                ));
+            }
+
+            let mut rng = rand::thread_rng();
+            let boss_in_room = roll_boss(&mut rng, depth, floor_is_full, &state.encountered_bosses)
+                .map(|boss| {
+                    let npc_id = make_boss_lair(&mut room, &boss, danger_level);
+                    (boss, npc_id)
+                });
+            if let Some(artifact) = roll_artifact(&mut rng, danger_level, &state.spawned_artifacts)
+            {
+                let item = artifact_item(&artifact);
+                let item_id = item.id;
+                if place_artifact(&mut room, item) {
+                    events.push(Event::ArtifactSpawned(ArtifactSpawned {
+                        artifact,
+                        item_id,
+                    }));
+                }
+            }
             events.push(Event::RoomGenerated(RoomGenerated {
                 room,
                 entrance_id: exit_id,
             }));
-            room_id
+            (room_id, boss_in_room)
         }
     };
 
@@ -49,12 +181,245 @@ pub fn handle(exit_room: &ExitRoom, state: &GameState) -> Result<Vec<Event>, Err
         old_room_id: state.current_room_id,
         new_room_id: room_id,
     }));
+    events.append(&mut pursue(state, exit_id, room_id));
 
     if !state.rooms_seen.contains(&room_id) {
         events.push(Event::RoomFirstSeen(RoomFirstSeen { room_id }));
-        events.push(Event::GameDangerLevelIncreased(1));
-        events.push(Event::PlayerMaxHealthChanged(1))
+        events.push(Event::PlayerGainedExperience(ROOM_FIRST_SEEN_EXPERIENCE));
+    }
+
+    if let Some((boss, npc_id)) = boss_in_room {
+        if !state.encountered_bosses.contains(&boss) {
+            events.push(Event::BossEncountered(BossEncountered { boss, npc_id }));
+        }
     }
 
     Ok(events)
 }
+
+fn is_floor_full(state: &GameState, depth: u32) -> bool {
+    state.world.rooms_on_floor(depth) >= room_budget_for_depth(depth)
+}
+
+/// Tucks the artifact away in the room, preferring the best hidden spot on a
+/// fixture, then the pack of one of the NPCs.
+fn place_artifact(room: &mut Room, item: Item) -> bool {
+    let fixture = room
+        .fixture_positions
+        .iter_mut()
+        .map(|fixture_position| &mut fixture_position.fixture)
+        .max_by_key(|fixture| (fixture.has_hidden_compartment, fixture.can_be_opened));
+
+    if let Some(fixture) = fixture {
+        fixture.items.push(FixtureItem {
+            is_inside: fixture.can_be_opened,
+            is_in_hidden_compartment: fixture.has_hidden_compartment,
+            item,
+        });
+        return true;
+    }
+
+    match room.npc_positions.first_mut() {
+        Some(npc_position) => {
+            npc_position.npc.character.add_item(CharacterItem {
+                item,
+                is_hidden: false,
+                equipped_location: LocationTag::Packed,
+                is_multiple: false,
+                at_the_ready: false,
+                quantity: 1,
+            });
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        actions::ExitRoom,
+        components::{
+            games::GameState,
+            items::{ready_tag_for_item_type, CharacterItem, ItemType},
+            rooms::{ExitType, NpcPosition},
+            worlds::{room_budget_for_depth, ExitMap},
+        },
+        errors::Error,
+        events::{apply_events, Event},
+        generators::{
+            game::game_generator, generator::Generator, items::item_generator_for_level,
+            non_players::npc_generator, players::player_generator,
+        },
+    };
+
+    /// Clears the way out through the first exit of an empty room.
+    fn clear_exit(state: &mut GameState, exit_type: ExitType) -> Uuid {
+        let exit_id = state.current_room().exits[0].id;
+        for exit in state.world.exit_sides_mut(exit_id) {
+            exit.exit_type = exit_type.clone();
+            exit.hidden = false;
+            exit.lock = None;
+            exit.trap = None;
+        }
+        state.current_room_mut().npc_positions.clear();
+        exit_id
+    }
+
+    fn exit_room(exit_id: Uuid) -> ExitRoom {
+        ExitRoom {
+            exit_id: exit_id.to_string(),
+        }
+    }
+
+    #[test]
+    fn exits_outside_the_current_room_are_not_found() {
+        let mut state = game_generator().generate();
+        let player = player_generator(None, None, None).generate();
+        let exit_id = Uuid::new_v4();
+        state.world.exit_graph.push(ExitMap {
+            exit_id,
+            left_room_id: Some(Uuid::new_v4()),
+            right_room_id: None,
+        });
+
+        let result = super::handle(
+            &ExitRoom {
+                exit_id: exit_id.to_string(),
+            },
+            &state,
+            &player,
+        );
+
+        assert!(matches!(result, Err(Error::ExitNotFoundError(_))));
+    }
+
+    #[test]
+    fn players_killed_on_the_way_out_stay_in_the_room() {
+        let mut state = game_generator().generate();
+        let exit_id = state.current_room().exits[0].id;
+        for exit in state.world.exit_sides_mut(exit_id) {
+            exit.hidden = false;
+            exit.lock = None;
+            exit.trap = None;
+        }
+        let mut npc = npc_generator(None).generate();
+        npc.character.stats.attributes.strength = 6;
+        npc.character.inventory.equipment.clear();
+        npc.character.add_item(CharacterItem {
+            item: item_generator_for_level(&ItemType::Dagger, true, 1).generate(),
+            is_hidden: false,
+            equipped_location: ready_tag_for_item_type(&ItemType::Dagger),
+            is_multiple: false,
+            at_the_ready: true,
+            quantity: 1,
+        });
+        state.current_room_mut().npc_positions = vec![NpcPosition {
+            group_descriptor: None,
+            npc,
+            position_descriptor: None,
+        }];
+        let mut player = player_generator(None, None, None).generate();
+        player.character.inventory.equipment.clear();
+        player
+            .character
+            .damage(player.character.get_current_health() - 1);
+        let exit_room = ExitRoom {
+            exit_id: exit_id.to_string(),
+        };
+
+        let mut killed = false;
+        for _ in 0..500 {
+            let events = super::handle(&exit_room, &state, &player).unwrap();
+            let (new_state, new_player) = apply_events(&events, &state, &player);
+
+            if new_player.character.is_dead() {
+                killed = true;
+                assert!(!events
+                    .iter()
+                    .any(|event| matches!(event, Event::RoomExited(_))));
+                assert_eq!(new_state.current_room_id, state.current_room_id);
+            }
+        }
+
+        assert!(killed);
+    }
+
+    #[test]
+    fn stairs_down_lead_to_the_next_floor() {
+        let mut state = game_generator().generate();
+        let player = player_generator(None, None, None).generate();
+        let exit_id = clear_exit(&mut state, ExitType::StaircaseDown);
+
+        let events = super::handle(&exit_room(exit_id), &state, &player).unwrap();
+        assert!(events.iter().any(|event| matches!(
+            event,
+            Event::FloorReached(reached) if reached.depth == 1 && !reached.is_final
+        )));
+
+        let (state, _) = apply_events(&events, &state, &player);
+        assert_eq!(state.current_room().depth, 1);
+        assert_eq!(state.world.deepest_depth(), 1);
+    }
+
+    #[test]
+    fn full_floors_only_lead_down() {
+        let mut state = game_generator().generate();
+        let player = player_generator(None, None, None).generate();
+        let exit_id = clear_exit(&mut state, ExitType::Hallway);
+        let mut filler = state.current_room().clone();
+        filler.exits.clear();
+        while state.world.rooms_on_floor(0) < room_budget_for_depth(0) {
+            filler.id = Uuid::new_v4();
+            state.world.rooms.push(filler.clone());
+        }
+
+        let events = super::handle(&exit_room(exit_id), &state, &player).unwrap();
+        let (state, _) = apply_events(&events, &state, &player);
+
+        assert_eq!(state.current_room().depth, 1);
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, Event::FloorReached(_))));
+    }
+
+    #[test]
+    fn fleeing_a_crowd_can_fail() {
+        let mut state = game_generator().generate();
+        let exit_id = clear_exit(&mut state, ExitType::Hallway);
+        state.current_room_mut().npc_positions = (0..3)
+            .map(|_| NpcPosition {
+                group_descriptor: None,
+                npc: npc_generator(None).generate(),
+                position_descriptor: None,
+            })
+            .collect();
+        let mut player = player_generator(None, None, None).generate();
+        player.character.inventory.equipment.clear();
+        player.character.stats.attributes.agility = 0;
+        player.character.stats.health.max = 10_000;
+        player.character.stats.health.current = 10_000;
+
+        let events = super::handle(&exit_room(exit_id), &state, &player).unwrap();
+        assert!(events.iter().any(|event| matches!(
+            event,
+            Event::PlayerFleeFailed(failed) if failed.exit_id.eq(&exit_id)
+        )));
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, Event::RoomExited(_))));
+
+        for npc_position in state.current_room_mut().npc_positions.iter_mut() {
+            npc_position.npc.character.current_effects.stunned = true;
+        }
+        let events = super::handle(&exit_room(exit_id), &state, &player).unwrap();
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, Event::PlayerFleeFailed(_))));
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, Event::RoomExited(_))));
+    }
+}
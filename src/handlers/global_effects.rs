@@ -1,23 +1,29 @@
 use crate::{
-    components::{games::GameState, PlayerCharacter},
+    components::{
+        games::GameState, items::Curse, Character, DamageType, NonPlayer, PlayerCharacter, Poison,
+    },
     events::{
-        Event, NpcDamagedByPoison, NpcPoisonEffectDissipated, NpcPoisonEffectDurationChanged,
-        PlayerKilled, PlayerKilledNpc,
+        Event, NpcDamagedByBleeding, NpcDamagedByPoison, NpcPoisonEffectDissipated,
+        NpcPoisonEffectDurationChanged, PlayerKilled, PlayerKilledNpc,
     },
 };
 
+use super::{damage::calculate_damage, helpers::boss_damaged_events, progression::kill_experience};
+
+const CURSE_DRAIN_DAMAGE: i32 = 1;
+
 pub fn handle(state: &GameState, player: &PlayerCharacter) -> Vec<Event> {
     let mut events: Vec<Event> = Vec::new();
 
     if !player.character.is_dead() {
+        let mut player_health = player.character.get_current_health();
+
         if let Some(poison_effect) = &player.character.current_effects.poison {
-            let damage = player
-                .character
-                .get_current_health()
-                .min(poison_effect.damage);
+            let damage = player_health.min(poison_damage(&player.character, poison_effect));
             events.push(Event::PlayerDamagedByPoison(damage));
+            player_health -= damage;
 
-            if damage >= player.character.get_current_health() {
+            if player_health <= 0 {
                 events.push(Event::PlayerKilled(PlayerKilled { killer_id: None }));
             } else if poison_effect.duration - 1 <= 0 {
                 events.push(Event::PlayerPoisonDissipated);
@@ -25,6 +31,31 @@ pub fn handle(state: &GameState, player: &PlayerCharacter) -> Vec<Event> {
                 events.push(Event::PlayerPoisonDurationChanged(-1));
             }
         }
+
+        if let Some(bleeding) = &player.character.current_effects.bleeding {
+            if player_health > 0 {
+                let damage = player_health.min(bleeding.damage);
+                events.push(Event::PlayerDamagedByBleeding(damage));
+
+                if damage >= player_health {
+                    events.push(Event::PlayerKilled(PlayerKilled { killer_id: None }));
+                }
+                player_health -= damage;
+            }
+        }
+
+        let draining_items = player
+            .character
+            .readied_items_with_curse(&Curse::Draining)
+            .len() as i32;
+        if draining_items > 0 && player_health > 0 {
+            let damage = player_health.min(draining_items * CURSE_DRAIN_DAMAGE);
+            events.push(Event::PlayerDamagedByCurse(damage));
+
+            if damage >= player_health {
+                events.push(Event::PlayerKilled(PlayerKilled { killer_id: None }));
+            }
+        }
     }
 
     for npc in state
@@ -37,37 +68,88 @@ pub fn handle(state: &GameState, player: &PlayerCharacter) -> Vec<Event> {
             continue;
         }
 
-        if let Some(poison_effect) = &npc.character.current_effects.poison {
-            let damage = npc.character.get_current_health().min(poison_effect.damage);
-            events.push(Event::NpcDamagedByPoison(NpcDamagedByPoison {
-                npc_id: npc.id,
-                damage,
-            }));
+        events.append(&mut npc_effects(state, player, npc).0);
+    }
+
+    events
+}
 
-            if damage >= npc.character.get_current_health() {
-                events.push(Event::PlayerKilledNpc(PlayerKilledNpc {
-                    killer_id: player.id,
+/// Poison and bleeding work on an NPC the same way wherever it is. Returns the
+/// events along with the health the NPC has left afterwards.
+pub fn npc_effects(
+    state: &GameState,
+    player: &PlayerCharacter,
+    npc: &NonPlayer,
+) -> (Vec<Event>, i32) {
+    let mut events: Vec<Event> = Vec::new();
+    let mut npc_health = npc.character.get_current_health();
+
+    if let Some(poison_effect) = &npc.character.current_effects.poison {
+        let damage = npc_health.min(poison_damage(&npc.character, poison_effect));
+        events.push(Event::NpcDamagedByPoison(NpcDamagedByPoison {
+            npc_id: npc.id,
+            damage,
+        }));
+        npc_health -= damage;
+
+        if npc_health <= 0 {
+            events.append(&mut npc_killed_by_effect(state, player, npc));
+        }
+
+        if poison_effect.duration - 1 > 0 {
+            events.push(Event::NpcPoisonDurationChanged(
+                NpcPoisonEffectDurationChanged {
                     npc_id: npc.id,
-                }));
+                    duration: -1,
+                },
+            ));
+        } else {
+            events.push(Event::NpcPoisonEffectDissipated(
+                NpcPoisonEffectDissipated { npc_id: npc.id },
+            ));
+        }
+    }
 
-                events.push(Event::GameDangerLevelIncreased(1));
-                events.push(Event::PlayerMaxHealthChanged(1))
-            }
+    if let Some(bleeding) = &npc.character.current_effects.bleeding {
+        if npc_health > 0 {
+            let damage = npc_health.min(bleeding.damage);
+            events.push(Event::NpcDamagedByBleeding(NpcDamagedByBleeding {
+                npc_id: npc.id,
+                damage,
+            }));
 
-            if poison_effect.duration - 1 > 0 {
-                events.push(Event::NpcPoisonDurationChanged(
-                    NpcPoisonEffectDurationChanged {
-                        npc_id: npc.id,
-                        duration: -1,
-                    },
-                ));
-            } else {
-                events.push(Event::NpcPoisonEffectDissipated(
-                    NpcPoisonEffectDissipated { npc_id: npc.id },
-                ));
+            if damage >= npc_health {
+                events.append(&mut npc_killed_by_effect(state, player, npc));
             }
+            npc_health -= damage;
         }
     }
 
-    events
+    let damage_taken = npc.character.get_current_health() - npc_health;
+    if damage_taken > 0 {
+        events.append(&mut boss_damaged_events(npc, damage_taken));
+    }
+
+    (events, npc_health)
+}
+
+/// Whoever poisoned or cut the NPC gets the credit when it finishes them off.
+fn npc_killed_by_effect(
+    state: &GameState,
+    player: &PlayerCharacter,
+    npc: &NonPlayer,
+) -> Vec<Event> {
+    vec![
+        Event::PlayerKilledNpc(PlayerKilledNpc {
+            killer_id: player.id,
+            npc_id: npc.id,
+        }),
+        Event::PlayerGainedExperience(kill_experience(npc, state.danger_level)),
+    ]
+}
+
+/// Poison goes through the same resistances as any other damage, so the undead
+/// shrug it off entirely.
+fn poison_damage(character: &Character, poison: &Poison) -> i32 {
+    calculate_damage(character, poison.damage, DamageType::Poison, &[], false).final_damage
 }
@@ -1,8 +1,8 @@
 use uuid::Uuid;
 
 use crate::{
-    actions::Action,
-    components::{games::GameState, PlayerCharacter},
+    actions::{Action, SearchRoom},
+    components::{games::GameState, items::Curse, PlayerCharacter},
     errors::Error,
     events::{apply_events, Event},
 };
@@ -24,6 +24,19 @@ fn is_being_targeted(action: &Action, npc_id: &Uuid) -> bool {
     }
 }
 
+/// Looking around, shuffling items in the pack and picking perks happen
+/// between turns, so nothing in the room gets to act on them.
+fn takes_a_turn(action: &Action) -> bool {
+    !matches!(
+        action,
+        Action::ChoosePerk(_)
+            | Action::LookAtFixture(_)
+            | Action::LookAtNpc(_)
+            | Action::MovePlayerItem(_)
+            | Action::SellPlayerItem(_)
+    )
+}
+
 pub fn handle_action(
     action: &Action,
     state: &GameState,
@@ -35,6 +48,10 @@ pub fn handle_action(
         return Err(Error::PlayerIsDeadError);
     }
 
+    if state.final_boss_defeated() {
+        return Err(Error::RunCompleteError);
+    }
+
     let mut npc_actions: Vec<NpcAction> = Vec::new();
     for npc_position in state
         .current_room()
@@ -48,11 +65,16 @@ pub fn handle_action(
     }
 
     npc_actions.append(&mut match action {
-        Action::InspectFixture(_)
+        Action::DisarmTrap(_)
+        | Action::IdentifyItem(_)
+        | Action::InspectFixture(_)
+        | Action::InteractWithFixture(_)
         | Action::LootFixture(_)
         | Action::LootNpc(_)
         | Action::OpenFixture(_)
-        | Action::OpenFixtureHiddenCompartment(_) => {
+        | Action::OpenFixtureHiddenCompartment(_)
+        | Action::RepairPlayerItem(_)
+        | Action::SearchRoom(SearchRoom { thorough: true }) => {
             if let Some(npc) = state.current_room().first_alive_npc() {
                 vec![NpcAction::AttackPlayer(npc.id)]
             } else {
@@ -62,62 +84,214 @@ pub fn handle_action(
         _ => Vec::new(),
     });
 
+    // An enraged boss doesn't wait to be provoked, and gets a second swing in
+    // when it is.
+    if let Some(boss) = state
+        .current_room()
+        .alive_boss()
+        .filter(|_| takes_a_turn(action))
+    {
+        if boss
+            .boss
+            .as_ref()
+            .map(|it| it.is_enraged())
+            .unwrap_or_default()
+        {
+            npc_actions.push(NpcAction::AttackPlayer(boss.id));
+        }
+    }
+
+    // An encumbered player is too slow to act before anyone in the room, and a
+    // beckoning curse makes sure they're noticed.
+    if takes_a_turn(action)
+        && (player.character.is_encumbered()
+            || !player
+                .character
+                .readied_items_with_curse(&Curse::Beckoning)
+                .is_empty())
+    {
+        if let Some(npc) = state.current_room().first_alive_npc() {
+            if !npc_actions.iter().any(
+                |npc_action| matches!(npc_action, NpcAction::AttackPlayer(id) if id.eq(&npc.id)),
+            ) {
+                npc_actions.push(NpcAction::AttackPlayer(npc.id));
+            }
+        }
+    }
+
     let mut events: Vec<Event> = Vec::new();
+    let mut attacked_state = state.clone();
+    let mut attacked_player = player.clone();
 
-    // Go through all npc actions and handle those. I'll do them first.
+    // Go through all npc actions and handle those. I'll do them first, and
+    // the player only gets to act if they live through them.
     for npc_action in npc_actions.iter() {
-        events.append(&mut super::handle_npc_action(npc_action, state, player)?);
+        let mut npc_events =
+            super::handle_npc_action(npc_action, &attacked_state, &attacked_player)?;
+        (attacked_state, attacked_player) =
+            apply_events(&npc_events, &attacked_state, &attacked_player);
+        events.append(&mut npc_events);
+
+        if attacked_player.character.is_dead() {
+            break;
+        }
     }
 
-    events.append(&mut match action {
-        Action::ExitRoom(exit_room) => super::exit_room::handle(exit_room, state)?,
+    let mut action_events = if attacked_player.character.is_dead() {
+        Vec::new()
+    } else {
+        handle_player_action(action, &attacked_state, &attacked_player)?
+    };
+
+    let (intermediate_state, intermediate_player) =
+        apply_events(&action_events, &attacked_state, &attacked_player);
+    events.append(&mut action_events);
+
+    // Effects only wear on and the world only moves when a turn goes by, and
+    // resting has already let every turn it took go by.
+    let ticks_the_world = takes_a_turn(action) && !matches!(action, Action::Rest(_));
+    let (world_state, world_player) = if ticks_the_world {
+        let mut global_events =
+            super::global_effects::handle(&intermediate_state, &intermediate_player);
+        let (effects_state, effects_player) =
+            apply_events(&global_events, &intermediate_state, &intermediate_player);
+        events.append(&mut global_events);
+
+        let mut world_events = super::world_tick::handle(&effects_state, &effects_player);
+        let world = apply_events(&world_events, &effects_state, &effects_player);
+        events.append(&mut world_events);
+        world
+    } else {
+        (intermediate_state, intermediate_player)
+    };
+
+    let mut progression_events = super::progression::handle(&world_player);
+    let (new_state, new_player) = apply_events(&progression_events, &world_state, &world_player);
+    events.append(&mut progression_events);
+
+    Ok(HandledAction {
+        new_state,
+        new_player,
+        events,
+    })
+}
+
+fn handle_player_action(
+    action: &Action,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
+    Ok(match action {
+        Action::DisarmTrap(disarm_trap) => super::disarm_trap::handle(disarm_trap, state, player)?,
+        Action::ExitRoom(exit_room) => super::exit_room::handle(exit_room, state, player)?,
         Action::AttackNpc(attack_npc) => super::attack_npc::handle(attack_npc, state, player)?,
-        Action::LootNpc(loot_npc) => super::loot_npc::handle(loot_npc, state)?,
+        Action::LootNpc(loot_npc) => super::loot_npc::handle(loot_npc, state, player)?,
         Action::LookAtNpc(look_at_npc) => super::view_npc::handle(look_at_npc, state)?,
         Action::MovePlayerItem(move_player_item) => {
             super::move_player_item::handle(move_player_item, player)?
         }
-        Action::InspectNpc(inspect_npc) => super::inspect_npc::handle(inspect_npc, state)?,
+        Action::IdentifyItem(identify_item) => {
+            super::identify_item::handle(identify_item, state, player)?
+        }
+        Action::InspectNpc(inspect_npc) => super::inspect_npc::handle(inspect_npc, state, player)?,
         Action::InspectFixture(inspect_fixture) => {
-            super::inspect_fixture::handle(inspect_fixture, state)?
+            super::inspect_fixture::handle(inspect_fixture, state, player)?
+        }
+        Action::InteractWithFixture(interact_with_fixture) => {
+            super::interact_with_fixture::handle(interact_with_fixture, state)?
         }
         Action::LookAtFixture(look_at_fixture) => {
             super::view_fixture::handle(look_at_fixture, state)?
         }
-        Action::LootFixture(loot_fixture) => super::loot_fixture::handle(loot_fixture, state)?,
+        Action::LootFixture(loot_fixture) => {
+            super::loot_fixture::handle(loot_fixture, state, player)?
+        }
         Action::CastSpellOnNpc(cast_spell_on_npc) => {
             super::cast_spell_on_npc::handle(cast_spell_on_npc, state, player)?
         }
         Action::CastSpellOnPlayer(cast_spell_on_player) => {
             super::cast_spell_on_player::handle(cast_spell_on_player, player)?
         }
+        Action::ChoosePerk(choose_perk) => super::choose_perk::handle(choose_perk, player)?,
+        Action::CombineItems(combine_items) => super::combine_items::handle(combine_items, player)?,
         Action::UseItemOnPlayer(use_item_on_player) => {
             super::use_item_on_player::handle(use_item_on_player, player)?
         }
-        Action::OpenFixture(open_fixture) => super::open_fixture::handle(open_fixture, state)?,
+        Action::OpenFixture(open_fixture) => {
+            super::open_fixture::handle(open_fixture, state, player)?
+        }
         Action::OpenFixtureHiddenCompartment(open_fixture_hidden_compartment) => {
-            super::open_fixture_hidden_compartment::handle(open_fixture_hidden_compartment, state)?
+            super::open_fixture_hidden_compartment::handle(
+                open_fixture_hidden_compartment,
+                state,
+                player,
+            )?
+        }
+        Action::PickLock(pick_lock) => super::pick_lock::handle(pick_lock, state, player)?,
+        Action::RepairPlayerItem(repair_player_item) => {
+            super::repair_player_item::handle(repair_player_item, state, player)?
         }
+        Action::Rest(rest) => super::rest::handle(rest, state, player)?,
+        Action::SearchRoom(search_room) => super::search_room::handle(search_room, state, player),
         Action::SellPlayerItem(sell_player_item) => {
             super::sell_player_item::handle(sell_player_item, player)?
         }
         Action::ThrowItemAtNpc(throw_item_at_npc) => {
             super::throw_item_at_npc::handle(throw_item_at_npc, state, player)?
         }
-    });
+        Action::TravelToRoom(travel_to_room) => {
+            super::travel_to_room::handle(travel_to_room, state, player)?
+        }
+        Action::UnlockWithKey(unlock_with_key) => {
+            super::unlock_with_key::handle(unlock_with_key, state, player)?
+        }
+    })
+}
 
-    let (intermediate_state, intermediate_player) = apply_events(&events, state, player);
+#[cfg(test)]
+mod tests {
+    use crate::{
+        actions::{Action, LookAtFixture},
+        components::{fixtures::FixtureType, rooms::FixturePosition, Poison},
+        generators::{
+            fixtures::get_generator, game::game_generator, generator::Generator,
+            players::player_generator,
+        },
+    };
 
-    let mut global_events =
-        super::global_effects::handle(&intermediate_state, &intermediate_player);
-    let (new_state, new_player) =
-        apply_events(&global_events, &intermediate_state, &intermediate_player);
+    use super::{handle_action, HandledAction};
 
-    events.append(&mut global_events);
+    #[test]
+    fn actions_outside_of_turns_leave_the_world_alone() {
+        let mut state = game_generator().generate();
+        let mut player = player_generator(None, None, None).generate();
+        player.character.current_effects.poison = Some(Poison {
+            damage: 1,
+            duration: 5,
+        });
+        let fixture = get_generator(&FixtureType::Chest, false).generate();
+        let action = Action::LookAtFixture(LookAtFixture {
+            fixture_id: fixture.id.to_string(),
+        });
+        state
+            .current_room_mut()
+            .fixture_positions
+            .push(FixturePosition {
+                group_descriptor: None,
+                fixture,
+                position_descriptor: None,
+            });
 
-    Ok(HandledAction {
-        new_state,
-        new_player,
-        events,
-    })
+        let HandledAction {
+            new_state,
+            new_player,
+            ..
+        } = handle_action(&action, &state, &player).unwrap();
+
+        assert_eq!(new_state.turn, state.turn);
+        assert_eq!(
+            new_player.character.get_current_health(),
+            player.character.get_current_health()
+        );
+    }
 }
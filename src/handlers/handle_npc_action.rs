@@ -18,7 +18,7 @@ pub fn handle_npc_action(
                 None => return Err(Error::NpcNotFoundError(npc_id.to_string())),
             };
 
-            Ok(npc_attack_player(player, npc, true))
+            Ok(npc_attack_player(state, player, npc, true))
         }
     }
 }
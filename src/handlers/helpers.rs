@@ -1,32 +1,114 @@
 use std::ops::RangeInclusive;
 
-use rand::Rng;
+use rand::{prelude::ThreadRng, seq::SliceRandom, Rng};
+use uuid::Uuid;
 
 use crate::{
-    components::{damage::AttackEffect, NonPlayer, PlayerCharacter},
+    components::{
+        damage::AttackEffect,
+        games::GameState,
+        items::{CharacterItem, Enchantment},
+        Character, DamageCalculation, Lock, NonPlayer, Perk, PlayerCharacter, Trap, TrapType,
+    },
+    errors::Error,
     events::{
-        Event, NpcWeaponReadied, PlayerHit, PlayerHitNpc, PlayerKilled, PlayerKilledNpc,
-        PlayerMissed, PlayerPoisoned,
+        BossDefeated, BossEnraged, Event, ExitDiscovered, ExitUnlocked, FixtureUnlocked,
+        NpcDroppedWeapon, NpcHurtSelf, NpcItemBroken, NpcItemWorn, NpcWeaponReadied,
+        PlayerBleeding, PlayerCriticallyHit, PlayerDroppedWeapon, PlayerHealed, PlayerHit,
+        PlayerHitNpc, PlayerItemSplit, PlayerItemWorn, PlayerKilled, PlayerKilledNpc, PlayerMissed,
+        PlayerPoisoned, TrapSprung,
+    },
+    utils::{
+        ids::parse_id,
+        rolls::{roll_d100, roll_d6},
     },
-    utils::rolls::{roll_d100, roll_d6},
 };
 
-const PLAYER_DODGE_CHANCE: i32 = 1;
+use super::{
+    damage::calculate_damage,
+    progression::{kill_experience, BOSS_DEFEATED_EXPERIENCE, HIDDEN_EXIT_DISCOVERED_EXPERIENCE},
+};
+
+/// Percent chance for the player to dodge an NPC's attack, about the 1 in 6 it
+/// has always been. Agility and perks only add to it.
+const PLAYER_DODGE_CHANCE: i32 = 17;
+const NIMBLE_DODGE_CHANCE: i32 = 5;
+pub const ENCUMBERED_DODGE_PENALTY: i32 = 15;
 
 const TOXIC_RANGE: RangeInclusive<i32> = 3..=6;
 const TOXIC_DURATION_RANGE: RangeInclusive<i32> = 2..=4;
 
 const ACID_DESTROYS_ITEM_CHANCE: i32 = 20;
 
+const CRITICAL_HIT_CHANCE: i32 = 5;
+const FUMBLE_CHANCE: i32 = 3;
+const FUMBLE_DROPS_WEAPON_CHANCE: i32 = 50;
+
+const VAMPIRIC_LIFESTEAL_DIVISOR: i32 = 2;
+
+const WEAR_PER_HIT: i32 = 1;
+const CRUSHING_ARMOUR_WEAR: i32 = 2;
+
+pub const BLEEDING_RANGE: RangeInclusive<i32> = 1..=3;
+pub const BLEEDING_DURATION_RANGE: RangeInclusive<i32> = 2..=4;
+
+pub enum AttackOutcome {
+    Critical,
+    Fumble,
+    Normal,
+}
+
+pub fn roll_attack_outcome(rng: &mut ThreadRng) -> AttackOutcome {
+    let roll = roll_d100(rng, 1, 0);
+
+    if roll <= FUMBLE_CHANCE {
+        AttackOutcome::Fumble
+    } else if roll > 100 - CRITICAL_HIT_CHANCE {
+        AttackOutcome::Critical
+    } else {
+        AttackOutcome::Normal
+    }
+}
+
+/// What goes wrong when a fumbling attacker either loses their grip on a weapon
+/// or catches themselves with it. Fumbles are never fatal on their own.
+pub enum Fumble {
+    DroppedWeapon(uuid::Uuid),
+    HurtSelf(i32),
+}
+
+pub fn roll_fumble(rng: &mut ThreadRng, character: &Character) -> Option<Fumble> {
+    let weapons = character.inventory.readied_weapons();
+    if !weapons.is_empty() && roll_d100(rng, 1, 0) <= FUMBLE_DROPS_WEAPON_CHANCE {
+        let index = rng.gen_range(0..weapons.len());
+        return weapons
+            .get(index)
+            .map(|character_item| Fumble::DroppedWeapon(character_item.item.id));
+    }
+
+    let damage = roll_d6(rng, 1, 0).min(character.get_current_health() - 1);
+    if damage > 0 {
+        Some(Fumble::HurtSelf(damage))
+    } else {
+        None
+    }
+}
+
 pub fn npc_attack_player(
+    state: &GameState,
     player: &PlayerCharacter,
     npc: &NonPlayer,
     npc_can_ready: bool,
 ) -> Vec<Event> {
     let mut rng = rand::thread_rng();
-    let dodge_roll = roll_d6(&mut rng, 1, 0);
 
-    if dodge_roll <= PLAYER_DODGE_CHANCE {
+    if npc.character.current_effects.stunned {
+        return vec![Event::NpcStunRecovered(npc.id)];
+    }
+
+    let dodge_roll = roll_d100(&mut rng, 1, 0);
+
+    if dodge_roll <= player_dodge_chance(player) {
         return vec![Event::PlayerMissed(PlayerMissed {
             attacker_id: npc.id,
         })];
@@ -34,18 +116,42 @@ pub fn npc_attack_player(
 
     let mut events: Vec<Event> = Vec::new();
     if npc.character.has_weapons_readied() {
+        let outcome = roll_attack_outcome(&mut rng);
+        if matches!(outcome, AttackOutcome::Fumble) {
+            match roll_fumble(&mut rng, &npc.character) {
+                Some(Fumble::DroppedWeapon(item_id)) => {
+                    events.push(Event::NpcDroppedWeapon(NpcDroppedWeapon {
+                        npc_id: npc.id,
+                        item_id,
+                    }))
+                }
+                Some(Fumble::HurtSelf(damage)) => events.push(Event::NpcHurtSelf(NpcHurtSelf {
+                    npc_id: npc.id,
+                    damage,
+                })),
+                None => events.push(Event::PlayerMissed(PlayerMissed {
+                    attacker_id: npc.id,
+                })),
+            }
+            return events;
+        }
+
+        let is_critical = matches!(outcome, AttackOutcome::Critical);
+        if is_critical {
+            events.push(Event::PlayerCriticallyHit(PlayerCriticallyHit {
+                attacker_id: npc.id,
+            }));
+        }
+
         let attack_effects = npc.character.attack_effects();
-        // Sharpness cuts through armour
-        let player_defense = if attack_effects
-            .iter()
-            .any(|effect| matches!(effect, AttackEffect::Sharp))
-        {
-            player.character.defense() / 2
-        } else {
-            player.character.defense()
-        };
-        let character_attack = npc.character.attack();
-        let mut player_damage = (character_attack - player_defense).max(1);
+        let calculation = calculate_damage(
+            &player.character,
+            npc.character.attack(),
+            npc.character.damage_type(),
+            &attack_effects,
+            is_critical,
+        );
+        let mut player_damage = calculation.final_damage;
 
         if let Some(defense_aura) = &player.character.current_effects.shield_aura {
             let actual_damage = player_damage - defense_aura.damage_resistance;
@@ -68,7 +174,14 @@ pub fn npc_attack_player(
             events.push(Event::PlayerHit(PlayerHit {
                 attacker_id: npc.id,
                 damage: player_damage,
+                calculation: Some(calculation),
             }));
+            events.append(&mut npc_hit_wear_events(
+                &mut rng,
+                player,
+                npc,
+                &attack_effects,
+            ));
         }
 
         if player_damage >= player.character.get_current_health() {
@@ -80,6 +193,10 @@ pub fn npc_attack_player(
                 events.push(Event::PlayerResurrected);
             }
         } else {
+            if is_critical && player_damage > 0 {
+                events.append(&mut critical_hit_player_effects(&mut rng, &attack_effects));
+            }
+
             // Handle any other attack effects that weren't previously handled.
             for effect in attack_effects.iter() {
                 match effect {
@@ -92,8 +209,7 @@ pub fn npc_attack_player(
                     AttackEffect::Acidic => {
                         if roll_d100(&mut rng, 1, 0) <= ACID_DESTROYS_ITEM_CHANCE {
                             let equipped_items = player.character.inventory.readied_weapons();
-                            let index = rng.gen_range(0..equipped_items.len());
-                            if let Some(character_item) = equipped_items.get(index) {
+                            if let Some(character_item) = equipped_items.choose(&mut rng) {
                                 events.push(Event::PlayerHitWithAcid);
                                 events.push(Event::PlayerItemDestroyed(character_item.item.id));
                             }
@@ -106,16 +222,22 @@ pub fn npc_attack_player(
 
         if let Some(retribution_aura) = &player.character.current_effects.retribution_aura {
             let mut rng = rand::thread_rng();
-            let damage = retribution_aura.attack_roll(&mut rng);
-            let (mut damage_events, _) = damage_npc(player, npc, damage);
+            let calculation = calculate_damage(
+                &npc.character,
+                retribution_aura.attack_roll(&mut rng),
+                retribution_aura.damage_type.clone(),
+                &retribution_aura.effects,
+                false,
+            );
+            let (mut damage_events, _) = damage_npc(state, player, npc, calculation);
             events.append(&mut damage_events);
             events.push(Event::PlayerRetributionAuraDissipated);
         }
     } else if npc_can_ready {
         // If there are no weapons readied, then all the NPC does is ready the weapon.
         let mut weapons = npc.character.inventory.non_readied_weapons();
-        weapons.sort_by(|a, b| a.item.num_attack_rolls().cmp(&b.item.num_attack_rolls()));
-        if let Some(weapon) = weapons.get(0) {
+        weapons.sort_by_key(|a| a.item.num_attack_rolls());
+        if let Some(weapon) = weapons.first() {
             events.push(Event::NpcWeaponReadied(NpcWeaponReadied {
                 npc_id: npc.id,
                 item_id: weapon.item.id,
@@ -131,11 +253,20 @@ pub fn npc_attack_player(
     events
 }
 
-pub fn damage_npc(player: &PlayerCharacter, npc: &NonPlayer, damage: i32) -> (Vec<Event>, bool) {
+pub fn damage_npc(
+    state: &GameState,
+    player: &PlayerCharacter,
+    npc: &NonPlayer,
+    calculation: DamageCalculation,
+) -> (Vec<Event>, bool) {
+    let damage = calculation
+        .final_damage
+        .min(npc.character.get_current_health());
     let mut events: Vec<Event> = vec![Event::PlayerHitNpc(PlayerHitNpc {
         npc_id: npc.id,
         damage,
         attacker_id: player.id,
+        calculation: Some(calculation),
     })];
 
     let npc_dead = damage >= npc.character.get_current_health();
@@ -150,10 +281,524 @@ pub fn damage_npc(player: &PlayerCharacter, npc: &NonPlayer, damage: i32) -> (Ve
             .iter()
             .any(|event| matches!(event, Event::PlayerKilled(_)))
         {
-            events.push(Event::GameDangerLevelIncreased(1));
-            events.push(Event::PlayerMaxHealthChanged(1))
+            events.push(Event::PlayerGainedExperience(kill_experience(
+                npc,
+                state.danger_level,
+            )));
         }
     }
 
+    events.append(&mut boss_damaged_events(npc, damage));
+
     (events, npc_dead)
 }
+
+pub fn player_dodge_chance(player: &PlayerCharacter) -> i32 {
+    let mut dodge_chance = PLAYER_DODGE_CHANCE
+        + player.character.stats.attributes.dodge_chance()
+        + player.progression.perk_count(&Perk::Nimble) as i32 * NIMBLE_DODGE_CHANCE;
+
+    if player.character.is_encumbered() {
+        dodge_chance -= ENCUMBERED_DODGE_PENALTY;
+    }
+
+    dodge_chance
+}
+
+/// Bosses drop their guard once they're down to half their health, and beating
+/// one is worth far more than the kill alone.
+pub fn boss_damaged_events(npc: &NonPlayer, damage: i32) -> Vec<Event> {
+    let boss = match &npc.boss {
+        Some(it) => it,
+        None => return Vec::new(),
+    };
+
+    let health_left = npc.character.get_current_health() - damage;
+
+    if health_left <= 0 {
+        return vec![
+            Event::BossDefeated(BossDefeated {
+                boss: boss.kind.clone(),
+                npc_id: npc.id,
+            }),
+            Event::PlayerGainedExperience(BOSS_DEFEATED_EXPERIENCE),
+        ];
+    }
+
+    if !boss.is_enraged() && health_left * 2 <= npc.character.stats.health.max {
+        return vec![Event::BossEnraged(BossEnraged { npc_id: npc.id })];
+    }
+
+    Vec::new()
+}
+
+/// Critical hits from sharp weapons always draw blood, and crushing ones leave
+/// the target reeling.
+fn critical_hit_player_effects(rng: &mut ThreadRng, attack_effects: &[AttackEffect]) -> Vec<Event> {
+    let mut events: Vec<Event> = Vec::new();
+
+    if attack_effects
+        .iter()
+        .any(|effect| matches!(effect, AttackEffect::Sharp))
+    {
+        events.push(Event::PlayerBleeding(PlayerBleeding {
+            damage: rng.gen_range(BLEEDING_RANGE),
+            duration: rng.gen_range(BLEEDING_DURATION_RANGE),
+        }));
+    }
+
+    if attack_effects
+        .iter()
+        .any(|effect| matches!(effect, AttackEffect::Crushing))
+    {
+        events.push(Event::PlayerStunned);
+    }
+
+    events
+}
+
+pub fn player_fumble_events(rng: &mut ThreadRng, player: &PlayerCharacter) -> Vec<Event> {
+    match roll_fumble(rng, &player.character) {
+        Some(Fumble::DroppedWeapon(item_id)) => {
+            // A cursed weapon won't leave the hand holding it.
+            let is_cursed = player
+                .character
+                .find_item(&item_id)
+                .map(|character_item| character_item.item.is_cursed())
+                .unwrap_or_default();
+            if is_cursed {
+                Vec::new()
+            } else {
+                vec![Event::PlayerDroppedWeapon(PlayerDroppedWeapon { item_id })]
+            }
+        }
+        Some(Fumble::HurtSelf(damage)) => vec![Event::PlayerHurtSelf(damage)],
+        None => Vec::new(),
+    }
+}
+
+/// Every readied weapon that can still wear down from landing a hit.
+fn weapons_worn_by_hit(character: &Character) -> Vec<CharacterItem> {
+    character
+        .inventory
+        .readied_weapons()
+        .into_iter()
+        .filter(|character_item| {
+            character_item.item.durability.is_some() && !character_item.item.is_broken()
+        })
+        .collect()
+}
+
+/// A random piece of equipped armour takes the brunt of a hit.
+fn armour_worn_by_hit(rng: &mut ThreadRng, character: &Character) -> Option<CharacterItem> {
+    let armour: Vec<CharacterItem> = character
+        .inventory
+        .equipped_wearables()
+        .into_iter()
+        .filter(|character_item| {
+            character_item.item.durability.is_some()
+                && character_item.item.usable_defense().is_some()
+        })
+        .collect();
+
+    if armour.is_empty() {
+        None
+    } else {
+        armour.get(rng.gen_range(0..armour.len())).cloned()
+    }
+}
+
+fn armour_wear(attack_effects: &[AttackEffect]) -> i32 {
+    if attack_effects
+        .iter()
+        .any(|effect| matches!(effect, AttackEffect::Crushing))
+    {
+        CRUSHING_ARMOUR_WEAR
+    } else {
+        WEAR_PER_HIT
+    }
+}
+
+fn breaks_from_wear(character_item: &CharacterItem, amount: i32) -> bool {
+    character_item
+        .item
+        .durability
+        .as_ref()
+        .map(|durability| durability.current <= amount)
+        .unwrap_or_default()
+}
+
+fn player_item_wear_events(character_item: &CharacterItem, amount: i32) -> Vec<Event> {
+    let item_id = character_item.item.id;
+    let mut events = vec![Event::PlayerItemWorn(PlayerItemWorn { item_id, amount })];
+    if breaks_from_wear(character_item, amount) {
+        events.push(Event::PlayerItemBroken(item_id));
+    }
+    events
+}
+
+fn npc_item_wear_events(
+    npc: &NonPlayer,
+    character_item: &CharacterItem,
+    amount: i32,
+) -> Vec<Event> {
+    let item_id = character_item.item.id;
+    let mut events = vec![Event::NpcItemWorn(NpcItemWorn {
+        npc_id: npc.id,
+        item_id,
+        amount,
+    })];
+    if breaks_from_wear(character_item, amount) {
+        events.push(Event::NpcItemBroken(NpcItemBroken {
+            npc_id: npc.id,
+            item_id,
+        }));
+    }
+    events
+}
+
+/// Landing a hit on an NPC wears down the player's weapons, and the NPC's
+/// armour if it is still standing.
+pub fn player_hit_wear_events(
+    rng: &mut ThreadRng,
+    player: &PlayerCharacter,
+    npc: &NonPlayer,
+    npc_dead: bool,
+) -> Vec<Event> {
+    let mut events: Vec<Event> = weapons_worn_by_hit(&player.character)
+        .iter()
+        .flat_map(|character_item| player_item_wear_events(character_item, WEAR_PER_HIT))
+        .collect();
+
+    if !npc_dead {
+        if let Some(armour) = armour_worn_by_hit(rng, &npc.character) {
+            let amount = armour_wear(&player.character.attack_effects());
+            events.append(&mut npc_item_wear_events(npc, &armour, amount));
+        }
+    }
+
+    events
+}
+
+/// Being hit by an NPC wears down the player's armour and the NPC's weapons.
+fn npc_hit_wear_events(
+    rng: &mut ThreadRng,
+    player: &PlayerCharacter,
+    npc: &NonPlayer,
+    attack_effects: &[AttackEffect],
+) -> Vec<Event> {
+    let mut events: Vec<Event> = weapons_worn_by_hit(&npc.character)
+        .iter()
+        .flat_map(|character_item| npc_item_wear_events(npc, character_item, WEAR_PER_HIT))
+        .collect();
+
+    if let Some(armour) = armour_worn_by_hit(rng, &player.character) {
+        events.append(&mut player_item_wear_events(
+            &armour,
+            armour_wear(attack_effects),
+        ));
+    }
+
+    events
+}
+
+/// Stacked items are used, sold or thrown one at a time. Splits a single item
+/// off the stack when needed, returning the id to act on.
+pub fn take_one_from_stack(character_item: &CharacterItem) -> (Uuid, Vec<Event>) {
+    if character_item.quantity > 1 {
+        let new_item_id = Uuid::new_v4();
+        (
+            new_item_id,
+            vec![Event::PlayerItemSplit(PlayerItemSplit {
+                item_id: character_item.item.id,
+                new_item_id,
+                quantity: 1,
+            })],
+        )
+    } else {
+        (character_item.item.id, Vec::new())
+    }
+}
+
+/// Vampiric weapons give the player back a share of the damage they dealt.
+pub fn lifesteal_events(player: &PlayerCharacter, damage_dealt: i32) -> Vec<Event> {
+    if !player
+        .character
+        .has_readied_enchantment(&Enchantment::Vampiric)
+    {
+        return Vec::new();
+    }
+
+    let damage_healed = (damage_dealt / VAMPIRIC_LIFESTEAL_DIVISOR)
+        .min(player.character.stats.health.max - player.character.stats.health.current);
+
+    if damage_healed > 0 {
+        vec![Event::PlayerHealed(PlayerHealed { damage_healed })]
+    } else {
+        Vec::new()
+    }
+}
+
+/// Finds the locked fixture or exit in the current room, along with the event
+/// that unlocks it.
+pub fn find_lock(
+    state: &GameState,
+    fixture_id: &Option<String>,
+    exit_id: &Option<String>,
+) -> Result<(Lock, Event), Error> {
+    if let Some(fixture_id) = fixture_id {
+        let fixture_id = parse_id(fixture_id)?;
+        let fixture_position = match state.current_room().find_fixture(&fixture_id) {
+            Some(it) => it,
+            None => return Err(Error::FixtureNotFoundError(fixture_id.to_string())),
+        };
+
+        return match &fixture_position.fixture.lock {
+            Some(lock) if lock.locked => Ok((
+                lock.clone(),
+                Event::FixtureUnlocked(FixtureUnlocked { fixture_id }),
+            )),
+            _ => Err(Error::NoLockError),
+        };
+    }
+
+    if let Some(exit_id) = exit_id {
+        let exit_id = parse_id(exit_id)?;
+        if !state
+            .current_room()
+            .exits
+            .iter()
+            .any(|exit| exit.id.eq(&exit_id) && state.knows_exit(exit))
+        {
+            return Err(Error::ExitNotFoundError(exit_id.to_string()));
+        }
+
+        return match state.world.exit_lock(&exit_id) {
+            Some(lock) => Ok((lock.clone(), Event::ExitUnlocked(ExitUnlocked { exit_id }))),
+            None => Err(Error::NoLockError),
+        };
+    }
+
+    Err(Error::NoLockError)
+}
+
+/// Springs the trap on whoever set it off, a trap hits the same way an attack
+/// does, then adds whatever extra nastiness the kind of trap carries.
+pub fn spring_trap(
+    trap: &Trap,
+    player: &PlayerCharacter,
+    fixture_id: Option<Uuid>,
+    exit_id: Option<Uuid>,
+) -> Vec<Event> {
+    let mut rng = rand::thread_rng();
+    let mut events = vec![Event::TrapSprung(TrapSprung {
+        trap_type: trap.trap_type.clone(),
+        fixture_id,
+        exit_id,
+    })];
+
+    let calculation = calculate_damage(
+        &player.character,
+        trap.damage.roll(&mut rng),
+        trap.trap_type.damage_type(),
+        &[],
+        false,
+    );
+    let damage = calculation.final_damage;
+
+    if damage > 0 {
+        events.push(Event::PlayerHit(PlayerHit {
+            attacker_id: fixture_id.or(exit_id).unwrap_or_default(),
+            damage,
+            calculation: Some(calculation),
+        }));
+    }
+
+    if damage >= player.character.get_current_health() {
+        events.push(Event::PlayerKilled(PlayerKilled { killer_id: None }));
+
+        if player.character.current_effects.resurrection_aura {
+            events.push(Event::PlayerResurrected);
+        }
+
+        return events;
+    }
+
+    match trap.trap_type {
+        TrapType::AcidSpray => events.push(Event::PlayerHitWithAcid),
+        TrapType::PoisonNeedle => events.push(Event::PlayerPoisoned(PlayerPoisoned {
+            damage: rng.gen_range(TOXIC_RANGE),
+            duration: rng.gen_range(TOXIC_DURATION_RANGE),
+        })),
+        TrapType::FallingBlock => {}
+    }
+
+    events
+}
+
+/// Rolls separately for every hidden exit in the room the player hasn't found
+/// yet, discovering the ones where the roll meets the target.
+pub fn discover_hidden_exits(state: &GameState, modifier: i32, target: i32) -> Vec<Event> {
+    let mut rng = rand::thread_rng();
+
+    state
+        .current_room()
+        .exits
+        .iter()
+        .filter(|exit| !state.knows_exit(exit))
+        .filter(|_| roll_d6(&mut rng, 1, modifier) >= target)
+        .flat_map(|exit| {
+            vec![
+                Event::ExitDiscovered(ExitDiscovered { exit_id: exit.id }),
+                Event::PlayerGainedExperience(HIDDEN_EXIT_DISCOVERED_EXPERIENCE),
+            ]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        components::{
+            games::ItemKnowledge,
+            items::{CharacterItem, Durability, Enchantment, ItemType, LocationTag},
+            Attributes, Boss, BossKind, BossPhase, PlayerCharacter,
+        },
+        events::{apply_events, Event},
+        generators::{
+            game::game_generator, generator::Generator, items::item_generator_for_level,
+            non_players::npc_generator, players::player_generator,
+        },
+    };
+
+    use super::{
+        boss_damaged_events, lifesteal_events, player_dodge_chance, player_hit_wear_events,
+        take_one_from_stack, PLAYER_DODGE_CHANCE, WEAR_PER_HIT,
+    };
+
+    #[test]
+    fn agility_only_adds_to_the_base_dodge_chance() {
+        let mut player = player_generator(None, None, None).generate();
+        player.character.inventory.equipment.clear();
+        player.character.stats.attributes = Attributes::default();
+        assert_eq!(player_dodge_chance(&player), PLAYER_DODGE_CHANCE);
+
+        player.character.stats.attributes.agility = 1;
+        assert_eq!(player_dodge_chance(&player), PLAYER_DODGE_CHANCE);
+
+        player.character.stats.attributes.agility = 5;
+        assert!(player_dodge_chance(&player) > PLAYER_DODGE_CHANCE);
+    }
+
+    #[test]
+    fn items_split_off_a_stack_keep_what_the_player_knows() {
+        let mut state = game_generator().generate();
+        let mut player = player_generator(None, None, None).generate();
+        let character_item = player.character.inventory.equipment.get_mut(0).unwrap();
+        character_item.quantity = 2;
+        let character_item = character_item.clone();
+        state.set_item_knowledge(character_item.item.id, ItemKnowledge { identified: true });
+
+        let (new_item_id, events) = take_one_from_stack(&character_item);
+        let (new_state, new_player) = apply_events(&events, &state, &player);
+
+        assert!(new_state.item_knowledge(&new_item_id).identified);
+        assert!(new_player.character.find_item(&new_item_id).is_some());
+    }
+
+    #[test]
+    fn hits_wear_readied_weapons_down_until_they_break() {
+        let state = game_generator().generate();
+        let mut player = player_generator(None, None, None).generate();
+        let npc = npc_generator(None).generate();
+        let mut dagger = item_generator_for_level(&ItemType::Dagger, true, 1).generate();
+        dagger.durability = Some(Durability::new(WEAR_PER_HIT * 2));
+        let dagger_id = dagger.id;
+        player.character.inventory.equipment = vec![CharacterItem {
+            item: dagger,
+            is_hidden: false,
+            equipped_location: LocationTag::Hand,
+            is_multiple: false,
+            at_the_ready: true,
+            quantity: 1,
+        }];
+
+        let mut rng = rand::thread_rng();
+        let events = player_hit_wear_events(&mut rng, &player, &npc, true);
+        assert!(events
+            .iter()
+            .all(|event| matches!(event, Event::PlayerItemWorn(_))));
+        let (_, player) = apply_events(&events, &state, &player);
+        let dagger = &player.character.find_item(&dagger_id).unwrap().item;
+        assert!(dagger.durability.as_ref().unwrap().is_worn());
+        assert!(dagger.usable_attack().is_some());
+
+        let events = player_hit_wear_events(&mut rng, &player, &npc, true);
+        assert!(events.iter().any(
+            |event| matches!(event, Event::PlayerItemBroken(item_id) if *item_id == dagger_id)
+        ));
+        let (_, player) = apply_events(&events, &state, &player);
+        let dagger = &player.character.find_item(&dagger_id).unwrap().item;
+        assert!(dagger.is_broken());
+        assert!(dagger.usable_attack().is_none());
+    }
+
+    #[test]
+    fn vampiric_weapons_heal_a_share_of_the_damage_dealt() {
+        let mut player = player_generator(None, None, None).generate();
+        let mut dagger = item_generator_for_level(&ItemType::Dagger, true, 1).generate();
+        dagger.enchantments = vec![Enchantment::Vampiric];
+        dagger.durability = Some(Durability::new(10));
+        player.character.inventory.equipment = vec![CharacterItem {
+            item: dagger,
+            is_hidden: false,
+            equipped_location: LocationTag::Hand,
+            is_multiple: false,
+            at_the_ready: true,
+            quantity: 1,
+        }];
+        player.character.stats.health.max = 20;
+        player.character.stats.health.current = 10;
+
+        fn healed(player: &PlayerCharacter) -> i32 {
+            match lifesteal_events(player, 8).first() {
+                Some(Event::PlayerHealed(healed)) => healed.damage_healed,
+                _ => 0,
+            }
+        }
+        assert_eq!(healed(&player), 4);
+
+        player.character.stats.health.current = 19;
+        assert_eq!(healed(&player), 1);
+
+        player.character.inventory.equipment[0].at_the_ready = false;
+        assert_eq!(healed(&player), 0);
+    }
+
+    #[test]
+    fn bosses_enrage_at_half_health_and_are_defeated_once() {
+        let mut npc = npc_generator(None).generate();
+        npc.boss = Some(Boss {
+            kind: BossKind::GrimjawTheGlutton,
+            phase: BossPhase::Wary,
+        });
+        npc.character.stats.health.max = 20;
+        npc.character.stats.health.current = 20;
+
+        assert!(boss_damaged_events(&npc, 5).is_empty());
+        assert!(matches!(
+            boss_damaged_events(&npc, 10).as_slice(),
+            [Event::BossEnraged(_)]
+        ));
+        assert!(matches!(
+            boss_damaged_events(&npc, 20).as_slice(),
+            [Event::BossDefeated(_), Event::PlayerGainedExperience(_)]
+        ));
+
+        npc.boss.as_mut().unwrap().phase = BossPhase::Enraged;
+        assert!(boss_damaged_events(&npc, 12).is_empty());
+
+        npc.boss = None;
+        assert!(boss_damaged_events(&npc, 20).is_empty());
+    }
+}
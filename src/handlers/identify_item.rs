@@ -0,0 +1,33 @@
+use crate::{
+    actions::IdentifyItem,
+    components::{games::GameState, PlayerCharacter},
+    errors::Error,
+    events::{Event, ItemIdentified},
+    utils::{ids::parse_id, rolls::roll_d6},
+};
+
+const IDENTIFY_ITEM_CHANCE: i32 = 4;
+
+pub fn handle(
+    identify_item: &IdentifyItem,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
+    let item_id = parse_id(&identify_item.item_id)?;
+    if player.character.find_item(&item_id).is_none() {
+        return Err(Error::ItemNotFoundError(item_id.to_string()));
+    }
+
+    if state.item_knowledge(&item_id).identified {
+        return Ok(Vec::new());
+    }
+
+    let mut rng = rand::thread_rng();
+    let modifier = player.character.stats.attributes.wits_modifier();
+
+    if roll_d6(&mut rng, 1, modifier) >= IDENTIFY_ITEM_CHANCE {
+        Ok(vec![Event::ItemIdentified(ItemIdentified { item_id })])
+    } else {
+        Ok(Vec::new())
+    }
+}
@@ -1,29 +1,71 @@
 use crate::{
     actions::InspectFixture,
-    components::games::GameState,
+    components::{games::GameState, Perk, PlayerCharacter},
     errors::Error,
-    events::{Event, FixtureHasHiddenCompartmentDiscovered},
+    events::{Event, FixtureHasHiddenCompartmentDiscovered, FixtureHasTrapDiscovered},
     utils::{ids::parse_id, rolls::roll_d6},
 };
 
+use super::{
+    helpers::discover_hidden_exits,
+    progression::{HIDDEN_COMPARTMENT_DISCOVERED_EXPERIENCE, TRAP_DISCOVERED_EXPERIENCE},
+};
+
 const DISCOVER_HIDDEN_COMPARTMENT_CHANCE: i32 = 2;
+const DISCOVER_TRAP_CHANCE: i32 = 3;
+const DISCOVER_CONCEALED_EXIT_CHANCE: i32 = 3;
 
-pub fn handle(inspect_fixture: &InspectFixture, state: &GameState) -> Result<Vec<Event>, Error> {
+pub fn handle(
+    inspect_fixture: &InspectFixture,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
     let mut events: Vec<Event> = Vec::new();
     let fixture_id = parse_id(&inspect_fixture.fixture_id)?;
 
-    if state.current_room().find_fixture(&fixture_id).is_none() {
-        return Err(Error::FixtureNotFoundError(fixture_id.to_string()));
-    }
+    let fixture_position = match state.current_room().find_fixture(&fixture_id) {
+        Some(it) => it,
+        None => return Err(Error::FixtureNotFoundError(fixture_id.to_string())),
+    };
 
     let mut rng = rand::thread_rng();
+    let modifier = player.character.stats.attributes.wits_modifier()
+        + player.progression.perk_count(&Perk::Observant) as i32;
 
     if inspect_fixture.discover_hidden_compartment
-        && roll_d6(&mut rng, 1, 0) >= DISCOVER_HIDDEN_COMPARTMENT_CHANCE
+        && roll_d6(&mut rng, 1, modifier) >= DISCOVER_HIDDEN_COMPARTMENT_CHANCE
     {
         events.push(Event::FixtureHasHiddenCompartmentDiscovered(
             FixtureHasHiddenCompartmentDiscovered { fixture_id },
         ));
+
+        let already_known = state
+            .fixture_knowledge(&fixture_id)
+            .knows_has_hidden_compartment;
+        if fixture_position.fixture.has_hidden_compartment && !already_known {
+            events.push(Event::PlayerGainedExperience(
+                HIDDEN_COMPARTMENT_DISCOVERED_EXPERIENCE,
+            ));
+        }
+    }
+
+    if inspect_fixture.discover_trap && roll_d6(&mut rng, 1, modifier) >= DISCOVER_TRAP_CHANCE {
+        events.push(Event::FixtureHasTrapDiscovered(FixtureHasTrapDiscovered {
+            fixture_id,
+        }));
+
+        let already_known = state.fixture_knowledge(&fixture_id).knows_has_trap;
+        if fixture_position.fixture.trap.is_some() && !already_known {
+            events.push(Event::PlayerGainedExperience(TRAP_DISCOVERED_EXPERIENCE));
+        }
+    }
+
+    if fixture_position.fixture.fixture_type.can_conceal_exit() {
+        events.append(&mut discover_hidden_exits(
+            state,
+            modifier,
+            DISCOVER_CONCEALED_EXIT_CHANCE,
+        ));
     }
 
     Ok(events)
@@ -1,6 +1,6 @@
 use crate::{
     actions::InspectNpc,
-    components::games::GameState,
+    components::{games::GameState, Perk, PlayerCharacter},
     errors::Error,
     events::{Event, NpcHealthDiscovered, NpcHiddenDiscovered, NpcPackedDiscovered},
     utils::{ids::parse_id, rolls::roll_d6},
@@ -10,7 +10,11 @@ const DISCOVER_HEALTH_CHANCE: i32 = 5;
 const DISCOVER_PACKED_CHANCE: i32 = 4;
 const DISCOVER_HIDDEN_CHANCE: i32 = 2;
 
-pub fn handle(inspect_npc: &InspectNpc, state: &GameState) -> Result<Vec<Event>, Error> {
+pub fn handle(
+    inspect_npc: &InspectNpc,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
     let mut events: Vec<Event> = Vec::new();
     let npc_id = parse_id(&inspect_npc.npc_id)?;
 
@@ -25,16 +29,22 @@ pub fn handle(inspect_npc: &InspectNpc, state: &GameState) -> Result<Vec<Event>,
         events.push(Event::NpcHiddenDiscovered(NpcHiddenDiscovered { npc_id }));
     } else {
         let mut rng = rand::thread_rng();
+        let modifier = player.character.stats.attributes.wits_modifier()
+            + player.progression.perk_count(&Perk::Observant) as i32;
 
-        if inspect_npc.discover_health && roll_d6(&mut rng, 1, 0) >= DISCOVER_HEALTH_CHANCE {
+        if inspect_npc.discover_health && roll_d6(&mut rng, 1, modifier) >= DISCOVER_HEALTH_CHANCE {
             events.push(Event::NpcHealthDiscovered(NpcHealthDiscovered { npc_id }));
         }
 
-        if inspect_npc.discover_packed_items && roll_d6(&mut rng, 1, 0) >= DISCOVER_PACKED_CHANCE {
+        if inspect_npc.discover_packed_items
+            && roll_d6(&mut rng, 1, modifier) >= DISCOVER_PACKED_CHANCE
+        {
             events.push(Event::NpcPackedDiscovered(NpcPackedDiscovered { npc_id }));
         }
 
-        if inspect_npc.discover_hidden_items && roll_d6(&mut rng, 1, 0) >= DISCOVER_HIDDEN_CHANCE {
+        if inspect_npc.discover_hidden_items
+            && roll_d6(&mut rng, 1, modifier) >= DISCOVER_HIDDEN_CHANCE
+        {
             events.push(Event::NpcHiddenDiscovered(NpcHiddenDiscovered { npc_id }));
         }
     }
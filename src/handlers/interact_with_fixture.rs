@@ -0,0 +1,232 @@
+use crate::{
+    actions::InteractWithFixture,
+    components::{games::GameState, rooms::PuzzleReward},
+    errors::Error,
+    events::{Event, ExitDiscovered, FixtureUnlocked, PuzzlePieceMoved, PuzzleSolved},
+    utils::ids::parse_id,
+};
+
+use super::progression::PUZZLE_SOLVED_EXPERIENCE;
+
+pub fn handle(
+    interact_with_fixture: &InteractWithFixture,
+    state: &GameState,
+) -> Result<Vec<Event>, Error> {
+    let fixture_id = parse_id(&interact_with_fixture.fixture_id)?;
+    if state.current_room().find_fixture(&fixture_id).is_none() {
+        return Err(Error::FixtureNotFoundError(fixture_id.to_string()));
+    }
+
+    let puzzle = match state
+        .current_room()
+        .puzzle
+        .as_ref()
+        .filter(|puzzle| !puzzle.solved)
+    {
+        Some(it) => it,
+        None => return Err(Error::FixtureInteractionNotPossible(fixture_id.to_string())),
+    };
+
+    let piece = match puzzle
+        .find_piece(&fixture_id)
+        .filter(|piece| piece.interaction.eq(&interact_with_fixture.interaction))
+    {
+        Some(it) => it,
+        None => return Err(Error::FixtureInteractionNotPossible(fixture_id.to_string())),
+    };
+
+    let position = piece.next_position();
+    let mut events = vec![Event::PuzzlePieceMoved(PuzzlePieceMoved {
+        fixture_id,
+        interaction: piece.interaction.clone(),
+        position,
+    })];
+
+    let solved = puzzle.pieces.iter().all(|other| {
+        if other.fixture_id.eq(&fixture_id) {
+            position == other.target
+        } else {
+            other.is_in_place()
+        }
+    });
+
+    if solved {
+        events.push(Event::PuzzleSolved(PuzzleSolved {
+            puzzle_id: puzzle.id,
+        }));
+        match &puzzle.reward {
+            PuzzleReward::HiddenExit(exit_id) => {
+                events.push(Event::ExitDiscovered(ExitDiscovered { exit_id: *exit_id }))
+            }
+            PuzzleReward::Vault(fixture_id) => {
+                events.push(Event::FixtureUnlocked(FixtureUnlocked {
+                    fixture_id: *fixture_id,
+                }))
+            }
+            // The item shows up when the puzzle solved event is applied.
+            PuzzleReward::Item(_) => {}
+        }
+        events.push(Event::PlayerGainedExperience(PUZZLE_SOLVED_EXPERIENCE));
+    }
+
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        actions::InteractWithFixture,
+        components::{
+            fixtures::FixtureType,
+            games::GameState,
+            items::ItemType,
+            rooms::{FixtureInteraction, FixturePosition, Puzzle, PuzzlePiece, PuzzleReward},
+            Lock, LockDifficulty,
+        },
+        errors::Error,
+        events::{apply_events, Event},
+        generators::{
+            fixtures::get_generator, game::game_generator, generator::Generator,
+            items::item_generator_for_level, players::player_generator,
+        },
+    };
+
+    use super::handle;
+
+    fn add_fixture(state: &mut GameState, fixture_type: &FixtureType) -> Uuid {
+        let fixture = get_generator(fixture_type, false).generate();
+        let fixture_id = fixture.id;
+        state
+            .current_room_mut()
+            .fixture_positions
+            .push(FixturePosition {
+                group_descriptor: None,
+                fixture,
+                position_descriptor: None,
+            });
+        fixture_id
+    }
+
+    /// A pillar to turn twice and a statue to push once.
+    fn add_puzzle(state: &mut GameState, reward: PuzzleReward) -> (Uuid, Uuid) {
+        let pillar_id = add_fixture(state, &FixtureType::Pillar);
+        let statue_id = add_fixture(state, &FixtureType::StatueWarrior);
+        state.current_room_mut().puzzle = Some(Puzzle {
+            id: Uuid::new_v4(),
+            pieces: vec![
+                PuzzlePiece {
+                    fixture_id: pillar_id,
+                    interaction: FixtureInteraction::Rotate,
+                    position: 0,
+                    target: 2,
+                },
+                PuzzlePiece {
+                    fixture_id: statue_id,
+                    interaction: FixtureInteraction::Push,
+                    position: 0,
+                    target: 1,
+                },
+            ],
+            reward,
+            solved: false,
+        });
+        (pillar_id, statue_id)
+    }
+
+    fn interact(fixture_id: Uuid, interaction: FixtureInteraction) -> InteractWithFixture {
+        InteractWithFixture {
+            fixture_id: fixture_id.to_string(),
+            interaction,
+        }
+    }
+
+    fn is_solved(events: &[Event]) -> bool {
+        events
+            .iter()
+            .any(|event| matches!(event, Event::PuzzleSolved(_)))
+    }
+
+    #[test]
+    fn setting_every_piece_opens_the_vault() {
+        let mut state = game_generator().generate();
+        let player = player_generator(None, None, None).generate();
+        let vault_id = add_fixture(&mut state, &FixtureType::Chest);
+        state
+            .current_room_mut()
+            .find_fixture_mut(&vault_id)
+            .unwrap()
+            .fixture
+            .lock = Some(Lock {
+            id: Uuid::new_v4(),
+            difficulty: LockDifficulty::Masterwork,
+            locked: true,
+            unpickable: true,
+        });
+        let (pillar_id, statue_id) = add_puzzle(&mut state, PuzzleReward::Vault(vault_id));
+
+        assert!(matches!(
+            handle(&interact(pillar_id, FixtureInteraction::Push), &state),
+            Err(Error::FixtureInteractionNotPossible(_))
+        ));
+        assert!(matches!(
+            handle(&interact(vault_id, FixtureInteraction::Push), &state),
+            Err(Error::FixtureInteractionNotPossible(_))
+        ));
+
+        for (fixture_id, interaction) in [
+            (pillar_id, FixtureInteraction::Rotate),
+            (statue_id, FixtureInteraction::Push),
+        ] {
+            let events = handle(&interact(fixture_id, interaction), &state).unwrap();
+            assert!(!is_solved(&events));
+            state = apply_events(&events, &state, &player).0;
+        }
+
+        let events = handle(&interact(pillar_id, FixtureInteraction::Rotate), &state).unwrap();
+        assert!(is_solved(&events));
+        assert!(events.iter().any(|event| matches!(
+            event,
+            Event::FixtureUnlocked(unlocked) if unlocked.fixture_id == vault_id
+        )));
+
+        let (state, _) = apply_events(&events, &state, &player);
+        assert!(state.current_room().puzzle.as_ref().unwrap().solved);
+        let vault = &state
+            .current_room()
+            .find_fixture(&vault_id)
+            .unwrap()
+            .fixture;
+        assert!(!vault.lock.as_ref().unwrap().locked);
+        assert!(matches!(
+            handle(&interact(statue_id, FixtureInteraction::Push), &state),
+            Err(Error::FixtureInteractionNotPossible(_))
+        ));
+    }
+
+    #[test]
+    fn item_rewards_appear_on_the_first_piece() {
+        let mut state = game_generator().generate();
+        let player = player_generator(None, None, None).generate();
+        let item = item_generator_for_level(&ItemType::Crown, false, 1).generate();
+        let item_id = item.id;
+        let (pillar_id, statue_id) = add_puzzle(&mut state, PuzzleReward::Item(Box::new(item)));
+        let puzzle = state.current_room_mut().puzzle.as_mut().unwrap();
+        puzzle.find_piece_mut(&pillar_id).unwrap().position = 2;
+
+        let events = handle(&interact(statue_id, FixtureInteraction::Push), &state).unwrap();
+        assert!(is_solved(&events));
+
+        let (state, _) = apply_events(&events, &state, &player);
+        let pillar = &state
+            .current_room()
+            .find_fixture(&pillar_id)
+            .unwrap()
+            .fixture;
+        assert!(pillar
+            .items
+            .iter()
+            .any(|fixture_item| fixture_item.item.id == item_id));
+    }
+}
@@ -2,38 +2,56 @@ use uuid::Uuid;
 
 use crate::{
     actions::LootFixture,
-    components::games::GameState,
+    components::{games::GameState, PlayerCharacter},
     errors::Error,
     events::{Event, ItemTakenFromFixture},
     utils::ids::parse_id,
 };
 
-pub fn handle(loot_fixture: &LootFixture, state: &GameState) -> Result<Vec<Event>, Error> {
+pub fn handle(
+    loot_fixture: &LootFixture,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
     let fixture_id = parse_id(&loot_fixture.fixture_id)?;
     let fixture_position = match state.current_room().find_fixture(&fixture_id) {
         Some(it) => it,
         None => return Err(Error::FixtureNotFoundError(fixture_id.to_string())),
     };
 
+    let fixture = &fixture_position.fixture;
+    if fixture.is_locked() {
+        return Err(Error::FixtureLocked(fixture_id.to_string()));
+    }
+
     let item_ids: Vec<Uuid> = loot_fixture
         .item_ids
         .iter()
-        .flat_map(|i| match parse_id(i) {
-            Ok(it) => Some(it),
-            Err(_) => None,
-        })
+        .flat_map(|i| parse_id(i).ok())
         .collect();
-    let matching_items = fixture_position
-        .fixture
+    let matching_items = fixture
         .items
         .iter()
         .filter(|fixture_item| item_ids.contains(&fixture_item.item.id));
 
-    // TODO: I should probably check to make sure the fixture is actually open here.
-    // But for now I'm just going to blindly move the items from the fixture to the player.
-
     let mut events: Vec<Event> = Vec::new();
+    let mut looted_weight: u32 = 0;
     for matching_item in matching_items {
+        // Anything shut away has to be opened up first, which is also when
+        // any trap on the fixture goes off.
+        if (matching_item.is_inside && fixture.can_be_opened && !fixture.open)
+            || (matching_item.is_in_hidden_compartment && !fixture.hidden_compartment_open)
+        {
+            return Err(Error::FixtureNotOpen(fixture_id.to_string()));
+        }
+
+        looted_weight += matching_item.item.weight();
+        if !player.character.can_carry(looted_weight) {
+            return Err(Error::TooHeavyToCarryError(
+                matching_item.item.id.to_string(),
+            ));
+        }
+
         events.push(Event::ItemTakenFromFixture(ItemTakenFromFixture {
             fixture_id,
             item_id: matching_item.item.id,
@@ -42,3 +60,94 @@ pub fn handle(loot_fixture: &LootFixture, state: &GameState) -> Result<Vec<Event
 
     Ok(events)
 }
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        actions::LootFixture,
+        components::{
+            fixtures::FixtureType,
+            games::GameState,
+            items::{FixtureItem, ItemType},
+            rooms::FixturePosition,
+            Lock, LockDifficulty,
+        },
+        errors::Error,
+        events::Event,
+        generators::{
+            fixtures::get_generator, game::game_generator, generator::Generator,
+            items::item_generator_for_level, players::player_generator,
+        },
+    };
+
+    fn add_chest(state: &mut GameState, locked: bool, open: bool) -> LootFixture {
+        let mut chest = get_generator(&FixtureType::Chest, false).generate();
+        let item = item_generator_for_level(&ItemType::Dagger, false, 1).generate();
+        let loot = LootFixture {
+            fixture_id: chest.id.to_string(),
+            item_ids: vec![item.id.to_string()],
+        };
+        chest.items = vec![FixtureItem {
+            item,
+            is_inside: true,
+            is_in_hidden_compartment: false,
+        }];
+        chest.open = open;
+        if locked {
+            chest.lock = Some(Lock {
+                id: Uuid::new_v4(),
+                difficulty: LockDifficulty::Simple,
+                locked: true,
+                unpickable: false,
+            });
+        }
+        state
+            .current_room_mut()
+            .fixture_positions
+            .push(FixturePosition {
+                group_descriptor: None,
+                fixture: chest,
+                position_descriptor: None,
+            });
+
+        loot
+    }
+
+    #[test]
+    fn locked_fixtures_cannot_be_looted() {
+        let mut state = game_generator().generate();
+        let player = player_generator(None, None, None).generate();
+        let loot = add_chest(&mut state, true, false);
+
+        let result = super::handle(&loot, &state, &player);
+
+        assert!(matches!(result, Err(Error::FixtureLocked(_))));
+    }
+
+    #[test]
+    fn closed_fixtures_must_be_opened_first() {
+        let mut state = game_generator().generate();
+        let player = player_generator(None, None, None).generate();
+        let loot = add_chest(&mut state, false, false);
+
+        let result = super::handle(&loot, &state, &player);
+
+        assert!(matches!(result, Err(Error::FixtureNotOpen(_))));
+    }
+
+    #[test]
+    fn open_fixtures_can_be_looted() {
+        let mut state = game_generator().generate();
+        let player = player_generator(None, None, None).generate();
+        let loot = add_chest(&mut state, false, true);
+
+        let events = super::handle(&loot, &state, &player).unwrap();
+
+        assert!(matches!(
+            events.as_slice(),
+            [Event::ItemTakenFromFixture(_)]
+        ));
+    }
+}
@@ -1,12 +1,16 @@
 use crate::{
     actions::LootNpc,
-    components::games::GameState,
+    components::{games::GameState, PlayerCharacter},
     errors::Error,
     events::{Event, ItemTakenFromNpc},
     utils::ids::parse_id,
 };
 
-pub fn handle(loot_npc: &LootNpc, state: &GameState) -> Result<Vec<Event>, Error> {
+pub fn handle(
+    loot_npc: &LootNpc,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
     let npc_id = parse_id(&loot_npc.npc_id)?;
 
     let room = state.current_room();
@@ -19,15 +23,23 @@ pub fn handle(loot_npc: &LootNpc, state: &GameState) -> Result<Vec<Event>, Error
     let mut events: Vec<Event> = Vec::new();
 
     if npc.character.is_dead() {
+        let mut looted_weight: u32 = 0;
         for id in &loot_npc.item_ids {
             let item_id = parse_id(id)?;
-            match npc.character.find_item(&item_id) {
-                Some(_) => events.push(Event::ItemTakenFromNpc(ItemTakenFromNpc {
-                    item_id,
-                    npc_id,
-                })),
+            let character_item = match npc.character.find_item(&item_id) {
+                Some(it) => it,
                 None => return Err(Error::ItemNotFoundError(item_id.to_string())),
+            };
+
+            looted_weight += character_item.weight();
+            if !player.character.can_carry(looted_weight) {
+                return Err(Error::TooHeavyToCarryError(item_id.to_string()));
             }
+
+            events.push(Event::ItemTakenFromNpc(ItemTakenFromNpc {
+                item_id,
+                npc_id,
+            }));
         }
     }
 
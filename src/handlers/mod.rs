@@ -1,24 +1,39 @@
 mod attack_npc;
 mod cast_spell_on_npc;
 mod cast_spell_on_player;
+mod choose_perk;
+mod combine_items;
+mod damage;
+mod disarm_trap;
 mod exit_room;
 mod global_effects;
 mod handle;
 mod handle_npc_action;
 mod helpers;
+mod identify_item;
 mod inspect_fixture;
 mod inspect_npc;
+mod interact_with_fixture;
 mod loot_fixture;
 mod loot_npc;
 mod move_player_item;
 mod npc_action;
+mod npc_movement;
 mod open_fixture;
 mod open_fixture_hidden_compartment;
+mod pick_lock;
+mod progression;
+mod repair_player_item;
+mod rest;
+mod search_room;
 mod sell_player_item;
 mod throw_item_at_npc;
+mod travel_to_room;
+mod unlock_with_key;
 mod use_item_on_player;
 mod view_fixture;
 mod view_npc;
+mod world_tick;
 
 use handle_npc_action::handle_npc_action;
 use npc_action::NpcAction;
@@ -3,16 +3,17 @@ use rand::Rng;
 use crate::{
     actions::MovePlayerItem,
     components::{
-        items::{packed_tags_for_item_type, ready_tag_for_item_type},
-        PlayerCharacter,
+        items::{packed_tags_for_item_type, ready_tag_for_item_type, Enchantment},
+        Defense, PlayerCharacter,
     },
     errors::Error,
-    events::{Event, PlayerItemMoved},
+    events::{Event, PlayerGainsShieldAura, PlayerItemCurseRevealed, PlayerItemMoved},
     utils::ids::parse_id,
 };
 
 const MAX_WEAPONS_AT_READY: usize = 2;
 const MAX_WEARABLES_AT_READY: usize = 8;
+const WARDING_AURA_RESISTANCE: i32 = 5;
 
 pub fn handle(
     move_player_item: &MovePlayerItem,
@@ -32,6 +33,10 @@ pub fn handle(
         return Ok(Vec::new());
     }
 
+    if character_item.is_at_the_ready() && character_item.item.is_cursed() {
+        return Err(Error::ItemIsCursed(item_id.to_string()));
+    }
+
     if character_item.is_weapon()
         && player.character.count_weapons_at_ready() >= MAX_WEAPONS_AT_READY
         && move_player_item.put_at_the_ready
@@ -60,9 +65,148 @@ pub fn handle(
         }
     };
 
-    Ok(vec![Event::PlayerItemMoved(PlayerItemMoved {
+    let mut events = vec![Event::PlayerItemMoved(PlayerItemMoved {
         item_id,
         at_the_ready: move_player_item.put_at_the_ready,
         location,
-    })])
+    })];
+
+    if move_player_item.put_at_the_ready && !character_item.item.curse_revealed {
+        if let Some(curse) = &character_item.item.curse {
+            events.push(Event::PlayerItemCurseRevealed(PlayerItemCurseRevealed {
+                item_id,
+                curse: curse.clone(),
+            }));
+        }
+    }
+
+    // Warding items only raise a new aura, they don't top up one that's already there.
+    if move_player_item.put_at_the_ready
+        && character_item.item.has_enchantment(&Enchantment::Warding)
+        && !character_item.item.is_broken()
+        && player.character.current_effects.shield_aura.is_none()
+    {
+        events.push(Event::PlayerGainsShieldAura(PlayerGainsShieldAura {
+            defense: Defense {
+                damage_resistance: WARDING_AURA_RESISTANCE,
+            },
+        }));
+    }
+
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        actions::MovePlayerItem,
+        components::{
+            items::{CharacterItem, Curse, Durability, Enchantment, ItemType, LocationTag},
+            PlayerCharacter,
+        },
+        errors::Error,
+        events::{apply_events, Event},
+        generators::{
+            game::game_generator, generator::Generator, items::item_generator_for_level,
+            players::player_generator,
+        },
+    };
+
+    use super::{handle, WARDING_AURA_RESISTANCE};
+
+    fn packed_player_with(item_type: &ItemType) -> (PlayerCharacter, Uuid) {
+        let mut player = player_generator(None, None, None).generate();
+        let mut item = item_generator_for_level(item_type, false, 1).generate();
+        item.enchantments.clear();
+        item.curse = None;
+        item.durability = Some(Durability::new(10));
+        let item_id = item.id;
+        player.character.inventory.equipment = vec![CharacterItem {
+            item,
+            is_hidden: false,
+            equipped_location: LocationTag::Packed,
+            is_multiple: false,
+            at_the_ready: false,
+            quantity: 1,
+        }];
+        (player, item_id)
+    }
+
+    fn move_item(item_id: Uuid, put_at_the_ready: bool) -> MovePlayerItem {
+        MovePlayerItem {
+            item_id: item_id.to_string(),
+            location_tag: None,
+            put_at_the_ready,
+        }
+    }
+
+    fn raises_shield_aura(events: &[Event]) -> bool {
+        events.iter().any(|event| {
+            matches!(
+                event,
+                Event::PlayerGainsShieldAura(aura)
+                    if aura.defense.damage_resistance == WARDING_AURA_RESISTANCE
+            )
+        })
+    }
+
+    #[test]
+    fn readying_a_warding_item_raises_a_shield_aura() {
+        let (mut player, item_id) = packed_player_with(&ItemType::Breastplate);
+        assert!(!raises_shield_aura(
+            &handle(&move_item(item_id, true), &player).unwrap()
+        ));
+
+        let character_item = player.character.find_item_mut(&item_id).unwrap();
+        character_item.item.enchantments.push(Enchantment::Warding);
+        assert!(raises_shield_aura(
+            &handle(&move_item(item_id, true), &player).unwrap()
+        ));
+
+        player
+            .character
+            .find_item_mut(&item_id)
+            .unwrap()
+            .item
+            .wear(10);
+        assert!(!raises_shield_aura(
+            &handle(&move_item(item_id, true), &player).unwrap()
+        ));
+    }
+
+    #[test]
+    fn cursed_items_stay_readied_until_the_curse_is_lifted() {
+        let state = game_generator().generate();
+        let (mut player, item_id) = packed_player_with(&ItemType::LongSword);
+        player.character.find_item_mut(&item_id).unwrap().item.curse = Some(Curse::Draining);
+
+        let events = handle(&move_item(item_id, true), &player).unwrap();
+        assert!(events.iter().any(|event| matches!(
+            event,
+            Event::PlayerItemCurseRevealed(revealed) if revealed.item_id == item_id
+        )));
+        let (_, player) = apply_events(&events, &state, &player);
+        assert!(
+            player
+                .character
+                .find_item(&item_id)
+                .unwrap()
+                .item
+                .curse_revealed
+        );
+
+        assert!(matches!(
+            handle(&move_item(item_id, false), &player),
+            Err(Error::ItemIsCursed(_))
+        ));
+
+        let (_, player) = apply_events(&[Event::PlayerItemCurseLifted(item_id)], &state, &player);
+        let events = handle(&move_item(item_id, false), &player).unwrap();
+        assert!(events.iter().any(|event| matches!(
+            event,
+            Event::PlayerItemMoved(moved) if !moved.at_the_ready
+        )));
+    }
 }
@@ -0,0 +1,258 @@
+use rand::{prelude::ThreadRng, Rng};
+use uuid::Uuid;
+
+use crate::{
+    components::{games::GameState, NonPlayer, NpcMovement},
+    events::{Event, NpcEnteredRoom, NpcLeftRoom},
+    utils::rolls::roll_d100,
+};
+
+const WANDER_CHANCE: i32 = 10;
+const PATROL_CHANCE: i32 = 20;
+const PURSUIT_CHANCE: i32 = 40;
+
+/// Wanderers and patrols in the rooms the player isn't in take their turn to
+/// move. Nobody leaves the player's room on their own, but anyone can walk
+/// into it.
+pub fn handle(state: &GameState) -> Vec<Event> {
+    let mut rng = rand::thread_rng();
+
+    state
+        .world
+        .rooms
+        .iter()
+        .filter(|room| room.id.ne(&state.current_room_id))
+        .flat_map(|room| {
+            room.npc_positions
+                .iter()
+                .map(move |npc_position| (room.id, &npc_position.npc))
+        })
+        .filter(|(_, npc)| {
+            !npc.character.is_dead() && !npc.character.current_effects.stunned && npc.boss.is_none()
+        })
+        .filter_map(|(room_id, npc)| {
+            next_exit(&mut rng, state, room_id, npc).map(|exit_id| (room_id, npc.id, exit_id))
+        })
+        .flat_map(|(room_id, npc_id, exit_id)| {
+            match state
+                .world
+                .exit_graph
+                .iter()
+                .find(|exit_map| exit_map.exit_id.eq(&exit_id))
+                .and_then(|exit_map| exit_map.other_room_id(room_id))
+            {
+                Some(to_room_id) => move_events(npc_id, room_id, to_room_id, exit_id),
+                None => Vec::new(),
+            }
+        })
+        .collect()
+}
+
+/// When the player leaves a room, anyone there that has already been hurt
+/// may come after them.
+pub fn pursue(state: &GameState, exit_id: Uuid, to_room_id: Uuid) -> Vec<Event> {
+    let mut rng = rand::thread_rng();
+
+    state
+        .current_room()
+        .npc_positions
+        .iter()
+        .map(|npc_position| &npc_position.npc)
+        .filter(|npc| {
+            !npc.character.is_dead()
+                && !npc.character.current_effects.stunned
+                && npc.boss.is_none()
+                && npc.character.get_current_health() < npc.character.stats.health.max
+        })
+        .filter(|_| roll_d100(&mut rng, 1, 0) <= PURSUIT_CHANCE)
+        .flat_map(|npc| move_events(npc.id, state.current_room_id, to_room_id, exit_id))
+        .collect()
+}
+
+fn next_exit(
+    rng: &mut ThreadRng,
+    state: &GameState,
+    room_id: Uuid,
+    npc: &NonPlayer,
+) -> Option<Uuid> {
+    match npc.movement {
+        NpcMovement::Stationary => None,
+        NpcMovement::Wandering => {
+            if roll_d100(rng, 1, 0) > WANDER_CHANCE {
+                return None;
+            }
+            random_exit(rng, state, room_id)
+        }
+        NpcMovement::Patrolling => {
+            if roll_d100(rng, 1, 0) > PATROL_CHANCE {
+                return None;
+            }
+
+            match npc.home_room_id {
+                Some(home_room_id) if home_room_id.ne(&room_id) => state
+                    .world
+                    .shortest_path(room_id, home_room_id, |exit_map| {
+                        !state.world.exit_is_locked(&exit_map.exit_id)
+                    })
+                    .and_then(|path| path.first().cloned()),
+                _ => random_exit(rng, state, room_id),
+            }
+        }
+    }
+}
+
+fn random_exit(rng: &mut ThreadRng, state: &GameState, room_id: Uuid) -> Option<Uuid> {
+    let neighbours = state.world.open_neighbours(room_id);
+    if neighbours.is_empty() {
+        return None;
+    }
+
+    neighbours
+        .get(rng.gen_range(0..neighbours.len()))
+        .map(|(exit_id, _)| *exit_id)
+}
+
+fn move_events(npc_id: Uuid, room_id: Uuid, to_room_id: Uuid, exit_id: Uuid) -> Vec<Event> {
+    vec![
+        Event::NpcLeftRoom(NpcLeftRoom {
+            npc_id,
+            room_id,
+            exit_id,
+        }),
+        Event::NpcEnteredRoom(NpcEnteredRoom {
+            npc_id,
+            room_id: to_room_id,
+            exit_id,
+        }),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        components::{
+            games::GameState, rooms::NpcPosition, worlds::ExitMap, Boss, BossKind, BossPhase,
+            NonPlayer, NpcMovement,
+        },
+        events::{apply_events, Event},
+        generators::{
+            game::game_generator, generator::Generator, non_players::npc_generator,
+            players::player_generator,
+        },
+    };
+
+    use super::{handle, pursue};
+
+    /// Builds a room next to the player's, joined to it by a new exit.
+    fn room_next_door(state: &mut GameState) -> (Uuid, Uuid) {
+        let mut room = state.current_room().clone();
+        room.id = Uuid::new_v4();
+        room.exits.clear();
+        room.npc_positions.clear();
+        let room_id = room.id;
+        let exit_id = Uuid::new_v4();
+        state.world.rooms.push(room);
+        state.world.exit_graph.push(ExitMap {
+            exit_id,
+            left_room_id: Some(state.current_room_id),
+            right_room_id: Some(room_id),
+        });
+        (room_id, exit_id)
+    }
+
+    fn place_npc(state: &mut GameState, room_id: Uuid, npc: NonPlayer) {
+        if let Some(room) = state
+            .world
+            .rooms
+            .iter_mut()
+            .find(|room| room.id.eq(&room_id))
+        {
+            room.npc_positions.push(NpcPosition {
+                group_descriptor: None,
+                npc,
+                position_descriptor: None,
+            });
+        }
+    }
+
+    fn moves(events: &[Event], npc_id: Uuid) -> bool {
+        events
+            .iter()
+            .any(|event| matches!(event, Event::NpcLeftRoom(left) if left.npc_id.eq(&npc_id)))
+    }
+
+    #[test]
+    fn wanderers_walk_into_the_players_room() {
+        let mut state = game_generator().generate();
+        let player = player_generator(None, None, None).generate();
+        let (room_id, exit_id) = room_next_door(&mut state);
+        let mut npc = npc_generator(None).generate();
+        npc.movement = NpcMovement::Wandering;
+        let npc_id = npc.id;
+        place_npc(&mut state, room_id, npc);
+
+        let events = (0..1000)
+            .map(|_| handle(&state))
+            .find(|events| moves(events, npc_id))
+            .unwrap();
+        assert!(events.iter().any(|event| matches!(
+            event,
+            Event::NpcEnteredRoom(entered)
+                if entered.npc_id.eq(&npc_id)
+                    && entered.room_id.eq(&state.current_room_id)
+                    && entered.exit_id.eq(&exit_id)
+        )));
+
+        let (state, _) = apply_events(&events, &state, &player);
+        assert!(state.current_room().find_npc(&npc_id).is_some());
+    }
+
+    #[test]
+    fn stationary_npcs_and_bosses_stay_put() {
+        let mut state = game_generator().generate();
+        let (room_id, _) = room_next_door(&mut state);
+        let mut guard = npc_generator(None).generate();
+        guard.movement = NpcMovement::Stationary;
+        let guard_id = guard.id;
+        place_npc(&mut state, room_id, guard);
+        let mut boss = npc_generator(None).generate();
+        boss.movement = NpcMovement::Wandering;
+        boss.boss = Some(Boss {
+            kind: BossKind::GrimjawTheGlutton,
+            phase: BossPhase::Wary,
+        });
+        let boss_id = boss.id;
+        place_npc(&mut state, room_id, boss);
+
+        for _ in 0..500 {
+            let events = handle(&state);
+            assert!(!moves(&events, guard_id));
+            assert!(!moves(&events, boss_id));
+        }
+    }
+
+    #[test]
+    fn only_the_wounded_give_chase() {
+        let mut state = game_generator().generate();
+        let (room_id, exit_id) = room_next_door(&mut state);
+        let mut npc = npc_generator(None).generate();
+        npc.movement = NpcMovement::Stationary;
+        npc.character.stats.health.current = npc.character.stats.health.max;
+        let npc_id = npc.id;
+        state.current_room_mut().npc_positions = vec![NpcPosition {
+            group_descriptor: None,
+            npc,
+            position_descriptor: None,
+        }];
+
+        for _ in 0..200 {
+            assert!(pursue(&state, exit_id, room_id).is_empty());
+        }
+
+        let npc = &mut state.current_room_mut().find_npc_mut(&npc_id).unwrap().npc;
+        npc.character.damage(1);
+        assert!((0..500).any(|_| moves(&pursue(&state, exit_id, room_id), npc_id)));
+    }
+}
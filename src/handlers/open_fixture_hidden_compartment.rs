@@ -1,14 +1,17 @@
 use crate::{
     actions::OpenFixtureHiddenCompartment,
-    components::games::GameState,
+    components::{games::GameState, PlayerCharacter},
     errors::Error,
     events::{Event, FixtureHiddenCompartmentOpened},
     utils::ids::parse_id,
 };
 
+use super::helpers::spring_trap;
+
 pub fn handle(
     open_fixture: &OpenFixtureHiddenCompartment,
     state: &GameState,
+    player: &PlayerCharacter,
 ) -> Result<Vec<Event>, Error> {
     let fixture_id = parse_id(&open_fixture.fixture_id)?;
     let fixture_position = match state.current_room().find_fixture(&fixture_id) {
@@ -28,7 +31,13 @@ pub fn handle(
         return Err(Error::FixtureHasNoHiddenCompartment(fixture_id.to_string()));
     }
 
-    Ok(vec![Event::FixtureHiddenCompartmentOpened(
+    let mut events: Vec<Event> = match &fixture_position.fixture.trap {
+        Some(trap) => spring_trap(trap, player, Some(fixture_id), None),
+        None => Vec::new(),
+    };
+    events.push(Event::FixtureHiddenCompartmentOpened(
         FixtureHiddenCompartmentOpened { fixture_id },
-    )])
+    ));
+
+    Ok(events)
 }
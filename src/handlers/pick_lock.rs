@@ -0,0 +1,148 @@
+use crate::{
+    actions::PickLock,
+    components::{games::GameState, PlayerCharacter},
+    errors::Error,
+    events::{Event, LockPickFailed},
+    utils::rolls::roll_d6,
+};
+
+use super::{helpers::find_lock, NpcAction};
+
+pub fn handle(
+    pick_lock: &PickLock,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
+    let (lock, unlock_event) = find_lock(state, &pick_lock.fixture_id, &pick_lock.exit_id)?;
+    if lock.unpickable {
+        return Err(Error::LockCannotBePickedError(lock.id.to_string()));
+    }
+
+    let mut rng = rand::thread_rng();
+    let modifier = player.character.stats.attributes.agility_modifier();
+
+    if roll_d6(&mut rng, 1, modifier) >= lock.difficulty.pick_target() {
+        return Ok(vec![unlock_event]);
+    }
+
+    let mut events = vec![Event::LockPickFailed(LockPickFailed { lock_id: lock.id })];
+
+    // The rattling draws the attention of whoever is in the room.
+    if let Some(npc) = state.current_room().first_alive_npc() {
+        events.append(&mut super::handle_npc_action(
+            &NpcAction::AttackPlayer(npc.id),
+            state,
+            player,
+        )?);
+    }
+
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        actions::PickLock,
+        components::{
+            fixtures::FixtureType,
+            games::GameState,
+            rooms::{FixturePosition, NpcPosition},
+            Lock, LockDifficulty,
+        },
+        errors::Error,
+        events::{apply_events, Event},
+        generators::{
+            fixtures::get_generator, game::game_generator, generator::Generator,
+            non_players::npc_generator, players::player_generator,
+        },
+    };
+
+    use super::handle;
+
+    fn add_locked_chest(
+        state: &mut GameState,
+        difficulty: LockDifficulty,
+        unpickable: bool,
+    ) -> Uuid {
+        let mut chest = get_generator(&FixtureType::Chest, false).generate();
+        chest.lock = Some(Lock {
+            id: Uuid::new_v4(),
+            difficulty,
+            locked: true,
+            unpickable,
+        });
+        let fixture_id = chest.id;
+        let room = state.current_room_mut();
+        room.npc_positions.clear();
+        room.fixture_positions.push(FixturePosition {
+            group_descriptor: None,
+            fixture: chest,
+            position_descriptor: None,
+        });
+        fixture_id
+    }
+
+    fn pick(fixture_id: Uuid) -> PickLock {
+        PickLock {
+            fixture_id: Some(fixture_id.to_string()),
+            exit_id: None,
+        }
+    }
+
+    #[test]
+    fn nimble_fingers_open_simple_locks() {
+        let mut state = game_generator().generate();
+        let mut player = player_generator(None, None, None).generate();
+        player.character.stats.attributes.agility = 8;
+        let fixture_id = add_locked_chest(&mut state, LockDifficulty::Simple, false);
+
+        let events = handle(&pick(fixture_id), &state, &player).unwrap();
+        assert!(matches!(events.as_slice(), [Event::FixtureUnlocked(_)]));
+
+        let (state, _) = apply_events(&events, &state, &player);
+        let fixture = &state
+            .current_room()
+            .find_fixture(&fixture_id)
+            .unwrap()
+            .fixture;
+        assert!(!fixture.lock.as_ref().unwrap().locked);
+        assert!(matches!(
+            handle(&pick(fixture_id), &state, &player),
+            Err(Error::NoLockError)
+        ));
+    }
+
+    #[test]
+    fn failed_picks_draw_the_room_to_attack() {
+        let mut state = game_generator().generate();
+        let mut player = player_generator(None, None, None).generate();
+        player.character.stats.attributes.agility = 0;
+        let fixture_id = add_locked_chest(&mut state, LockDifficulty::Masterwork, false);
+
+        let events = handle(&pick(fixture_id), &state, &player).unwrap();
+        assert!(matches!(events.as_slice(), [Event::LockPickFailed(_)]));
+
+        state.current_room_mut().npc_positions.push(NpcPosition {
+            group_descriptor: None,
+            npc: npc_generator(None).generate(),
+            position_descriptor: None,
+        });
+        let events = handle(&pick(fixture_id), &state, &player).unwrap();
+        assert!(matches!(events.first(), Some(Event::LockPickFailed(_))));
+        assert!(events.len() > 1);
+    }
+
+    #[test]
+    fn unpickable_locks_need_their_key() {
+        let mut state = game_generator().generate();
+        let player = player_generator(None, None, None).generate();
+        let fixture_id = add_locked_chest(&mut state, LockDifficulty::Simple, true);
+
+        assert!(matches!(
+            handle(&pick(fixture_id), &state, &player),
+            Err(Error::LockCannotBePickedError(_))
+        ));
+    }
+}
@@ -0,0 +1,127 @@
+use rand::seq::SliceRandom;
+use strum::IntoEnumIterator;
+
+use crate::{
+    components::{Attribute, NonPlayer, Perk, PlayerCharacter, Progression, Species},
+    events::{Event, PlayerAttributeIncreased, PlayerLeveledUp},
+};
+
+pub const ROOM_FIRST_SEEN_EXPERIENCE: u32 = 10;
+pub const BOSS_DEFEATED_EXPERIENCE: u32 = 100;
+pub const HIDDEN_COMPARTMENT_DISCOVERED_EXPERIENCE: u32 = 5;
+pub const HIDDEN_EXIT_DISCOVERED_EXPERIENCE: u32 = 10;
+pub const PUZZLE_SOLVED_EXPERIENCE: u32 = 25;
+pub const SPELL_CAST_EXPERIENCE: u32 = 3;
+pub const TRAP_DISCOVERED_EXPERIENCE: u32 = 5;
+pub const TRAP_DISARMED_EXPERIENCE: u32 = 10;
+
+const KILL_EXPERIENCE_PER_DANGER_LEVEL: u32 = 2;
+const LIFE_MODIFIER_KILL_EXPERIENCE: u32 = 5;
+const LEVEL_UP_MAX_HEALTH: i32 = 3;
+const NUM_PERK_CHOICES: usize = 3;
+
+/// Experience for a kill grows with how dangerous the NPC was and how deep
+/// into the game the player already is.
+pub fn kill_experience(npc: &NonPlayer, danger_level: u32) -> u32 {
+    let species_experience = match npc.character.species {
+        Species::Ogre => 25,
+        Species::Dragonkin | Species::Phantom | Species::Rockoblin | Species::Shadow => 20,
+        Species::Bugbear | Species::Hobgoblin | Species::Orc | Species::Moblin => 15,
+        Species::Frogkin
+        | Species::Goblin
+        | Species::Kobold
+        | Species::Lizardkin
+        | Species::Turtlekin => 10,
+    };
+
+    let life_modifier_experience = if npc.character.life_modifier.is_some() {
+        LIFE_MODIFIER_KILL_EXPERIENCE
+    } else {
+        0
+    };
+
+    species_experience + life_modifier_experience + danger_level * KILL_EXPERIENCE_PER_DANGER_LEVEL
+}
+
+/// Checks whether the player has earned enough experience to level up,
+/// possibly more than once, and offers a set of perks for each level gained.
+/// Every level also raises the player's weakest attribute that isn't maxed.
+pub fn handle(player: &PlayerCharacter) -> Vec<Event> {
+    let mut events: Vec<Event> = Vec::new();
+    if player.character.is_dead() {
+        return events;
+    }
+
+    let mut rng = rand::thread_rng();
+    let mut level = player.progression.level;
+    let mut attributes = player.character.stats.attributes.clone();
+    while player.progression.experience >= Progression::experience_for_level(level + 1) {
+        level += 1;
+
+        let mut perks: Vec<Perk> = Perk::iter()
+            .filter(|perk| !player.progression.perk_is_maxed(perk))
+            .collect();
+        perks.shuffle(&mut rng);
+        perks.truncate(NUM_PERK_CHOICES);
+
+        events.push(Event::PlayerLeveledUp(PlayerLeveledUp {
+            level,
+            perk_choices: perks,
+        }));
+        events.push(Event::PlayerMaxHealthChanged(LEVEL_UP_MAX_HEALTH));
+
+        let increasable: Vec<Attribute> = Attribute::iter()
+            .filter(|attribute| attributes.can_increase(attribute))
+            .collect();
+        let weakest = increasable
+            .iter()
+            .map(|attribute| attributes.score(attribute))
+            .min();
+        let choices: Vec<&Attribute> = increasable
+            .iter()
+            .filter(|attribute| Some(attributes.score(attribute)) == weakest)
+            .collect();
+        if let Some(attribute) = choices.choose(&mut rng) {
+            attributes.increase(attribute);
+            events.push(Event::PlayerAttributeIncreased(PlayerAttributeIncreased {
+                attribute: (*attribute).clone(),
+            }));
+        }
+    }
+
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        components::{Perk, Progression},
+        events::Event,
+        generators::{generator::Generator, players::player_generator},
+    };
+
+    #[test]
+    fn maxed_perks_are_no_longer_offered() {
+        let mut player = player_generator(None, None, None).generate();
+        player.progression.perks = vec![Perk::Nimble; Perk::Nimble.max_ranks() + 2];
+        player.progression.experience = Progression::experience_for_level(10);
+
+        assert_eq!(
+            player.progression.perk_count(&Perk::Nimble),
+            Perk::Nimble.max_ranks()
+        );
+
+        let events = super::handle(&player);
+        let offered: Vec<&Perk> = events
+            .iter()
+            .filter_map(|event| match event {
+                Event::PlayerLeveledUp(leveled_up) => Some(&leveled_up.perk_choices),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+
+        assert!(!offered.is_empty());
+        assert!(!offered.contains(&&Perk::Nimble));
+    }
+}
@@ -0,0 +1,247 @@
+use crate::{
+    actions::RepairPlayerItem,
+    components::{games::GameState, PlayerCharacter},
+    errors::Error,
+    events::{Event, PlayerItemRemoved, PlayerItemRepaired, PlayerItemUsed},
+    utils::ids::parse_id,
+};
+
+use super::helpers::take_one_from_stack;
+
+pub fn handle(
+    repair_player_item: &RepairPlayerItem,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
+    let item_id = parse_id(&repair_player_item.item_id)?;
+    let character_item = match player.character.find_item(&item_id) {
+        Some(it) => it,
+        None => return Err(Error::ItemNotFoundError(item_id.to_string())),
+    };
+
+    let durability = match &character_item.item.durability {
+        Some(it) => it,
+        None => return Err(Error::ItemNotRepairableError(item_id.to_string())),
+    };
+    if !durability.needs_repair() {
+        return Err(Error::ItemNotDamagedError(item_id.to_string()));
+    }
+    let missing = durability.max - durability.current;
+
+    if let Some(fixture_id) = &repair_player_item.fixture_id {
+        let fixture_id = parse_id(fixture_id)?;
+        let fixture_position = match state.current_room().find_fixture(&fixture_id) {
+            Some(it) => it,
+            None => return Err(Error::FixtureNotFoundError(fixture_id.to_string())),
+        };
+
+        if !fixture_position.fixture.can_repair(&character_item.item) {
+            return Err(Error::FixtureCannotRepairItem(fixture_id.to_string()));
+        }
+
+        return Ok(vec![Event::PlayerItemRepaired(PlayerItemRepaired {
+            item_id,
+            amount: missing,
+        })]);
+    }
+
+    if let Some(repair_kit_id) = &repair_player_item.repair_kit_id {
+        let repair_kit_id = parse_id(repair_kit_id)?;
+        let repair_kit = match player.character.find_item(&repair_kit_id) {
+            Some(it) => it,
+            None => return Err(Error::ItemNotFoundError(repair_kit_id.to_string())),
+        };
+
+        let consumable = match &repair_kit.item.consumable {
+            Some(it) => it,
+            None => return Err(Error::ItemNotRepairKitError(repair_kit_id.to_string())),
+        };
+
+        let repair_effect = match &consumable.effect.repair_effect {
+            Some(it) => it,
+            None => return Err(Error::ItemNotRepairKitError(repair_kit_id.to_string())),
+        };
+
+        let (repair_kit_id, mut events) = take_one_from_stack(&repair_kit);
+        events.push(Event::PlayerItemRepaired(PlayerItemRepaired {
+            item_id,
+            amount: repair_effect.amount.min(missing),
+        }));
+        events.push(Event::PlayerItemUsed(PlayerItemUsed {
+            item_id: repair_kit_id,
+        }));
+
+        if consumable.uses - 1 == 0 {
+            events.push(Event::PlayerItemRemoved(PlayerItemRemoved {
+                item_id: repair_kit_id,
+            }));
+        }
+
+        return Ok(events);
+    }
+
+    Err(Error::RepairToolNeededError)
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        actions::RepairPlayerItem,
+        components::{
+            fixtures::FixtureType,
+            items::{
+                CharacterItem, Consumable, ConsumableEffect, ConsumableEffectName, Durability,
+                Item, ItemType, LocationTag, RepairEffect,
+            },
+            rooms::FixturePosition,
+            PlayerCharacter,
+        },
+        errors::Error,
+        events::{apply_events, Event},
+        generators::{
+            fixtures::get_generator, game::game_generator, generator::Generator,
+            items::item_generator_for_level, players::player_generator,
+        },
+    };
+
+    use super::handle;
+
+    fn add_item(player: &mut PlayerCharacter, item: Item) -> Uuid {
+        let item_id = item.id;
+        player.character.inventory.equipment.push(CharacterItem {
+            item,
+            is_hidden: false,
+            equipped_location: LocationTag::Packed,
+            is_multiple: false,
+            at_the_ready: false,
+            quantity: 1,
+        });
+        item_id
+    }
+
+    fn worn_dagger(player: &mut PlayerCharacter) -> Uuid {
+        let mut dagger = item_generator_for_level(&ItemType::Dagger, false, 1).generate();
+        dagger.durability = Some(Durability::new(10));
+        dagger.wear(9);
+        add_item(player, dagger)
+    }
+
+    fn repair_kit(player: &mut PlayerCharacter, amount: i32, uses: i32) -> Uuid {
+        let mut kit = item_generator_for_level(&ItemType::RepairKit, false, 1).generate();
+        kit.consumable = Some(Consumable {
+            uses,
+            effect: ConsumableEffect {
+                name: ConsumableEffectName::RepairKit,
+                learn_spell_effect: None,
+                healing_effect: None,
+                repair_effect: Some(RepairEffect { amount }),
+                poison_effect: None,
+            },
+        });
+        add_item(player, kit)
+    }
+
+    fn repair(item_id: Uuid) -> RepairPlayerItem {
+        RepairPlayerItem {
+            item_id: item_id.to_string(),
+            fixture_id: None,
+            repair_kit_id: None,
+        }
+    }
+
+    #[test]
+    fn only_damaged_items_with_durability_can_be_repaired() {
+        let state = game_generator().generate();
+        let mut player = player_generator(None, None, None).generate();
+
+        let mut dagger = item_generator_for_level(&ItemType::Dagger, false, 1).generate();
+        dagger.durability = Some(Durability::new(10));
+        let dagger_id = add_item(&mut player, dagger);
+        let mut pot = item_generator_for_level(&ItemType::Pot, false, 1).generate();
+        pot.durability = None;
+        let pot_id = add_item(&mut player, pot);
+
+        assert!(matches!(
+            handle(&repair(dagger_id), &state, &player),
+            Err(Error::ItemNotDamagedError(_))
+        ));
+        assert!(matches!(
+            handle(&repair(pot_id), &state, &player),
+            Err(Error::ItemNotRepairableError(_))
+        ));
+
+        let worn_id = worn_dagger(&mut player);
+        assert!(matches!(
+            handle(&repair(worn_id), &state, &player),
+            Err(Error::RepairToolNeededError)
+        ));
+    }
+
+    #[test]
+    fn weapon_racks_fully_repair_weapons() {
+        let mut state = game_generator().generate();
+        let mut player = player_generator(None, None, None).generate();
+        let item_id = worn_dagger(&mut player);
+
+        let rack = get_generator(&FixtureType::WeaponRack, false).generate();
+        let fixture_id = rack.id.to_string();
+        state
+            .current_room_mut()
+            .fixture_positions
+            .push(FixturePosition {
+                group_descriptor: None,
+                fixture: rack,
+                position_descriptor: None,
+            });
+
+        let events = handle(
+            &RepairPlayerItem {
+                fixture_id: Some(fixture_id),
+                ..repair(item_id)
+            },
+            &state,
+            &player,
+        )
+        .unwrap();
+        let (_, player) = apply_events(&events, &state, &player);
+
+        let item = &player.character.find_item(&item_id).unwrap().item;
+        assert!(!item.needs_repair());
+    }
+
+    #[test]
+    fn repair_kits_mend_what_they_can_and_get_used_up() {
+        let state = game_generator().generate();
+        let mut player = player_generator(None, None, None).generate();
+        let item_id = worn_dagger(&mut player);
+        let kit_id = repair_kit(&mut player, 1, 1);
+
+        let events = handle(
+            &RepairPlayerItem {
+                repair_kit_id: Some(kit_id.to_string()),
+                ..repair(item_id)
+            },
+            &state,
+            &player,
+        )
+        .unwrap();
+        assert!(events.iter().any(|event| matches!(
+            event,
+            Event::PlayerItemRepaired(repaired) if repaired.amount == 1
+        )));
+
+        let (_, player) = apply_events(&events, &state, &player);
+        let durability = player
+            .character
+            .find_item(&item_id)
+            .unwrap()
+            .item
+            .durability
+            .clone()
+            .unwrap();
+        assert_eq!(durability.current, 2);
+        assert!(player.character.find_item(&kit_id).is_none());
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{
+    actions::Rest,
+    components::{fixtures::FixtureType, games::GameState, PlayerCharacter},
+    errors::Error,
+    events::{
+        apply_events, Event, PlayerAmbushed, PlayerHealed, PlayerRested, PlayerSpellUsesRestored,
+    },
+    generators::rooms::npcs::wandering_npc_position,
+    utils::rolls::roll_d100,
+};
+
+const REST_TURNS: u32 = 10;
+const DANGER_LEVELS_PER_AMBUSH_CHANCE: u32 = 10;
+
+/// How well the player sleeps depends on what they sleep in. Each gives the
+/// percentage of max health restored and the spell uses each spell gets back,
+/// even a night on the bare floor brings back a little.
+fn rest_quality(fixture_type: Option<&FixtureType>) -> (i32, i32) {
+    match fixture_type {
+        Some(FixtureType::Bed) => (75, 3),
+        Some(FixtureType::Cot) => (50, 2),
+        Some(FixtureType::SleepingRoll) => (40, 2),
+        _ => (25, 1),
+    }
+}
+
+pub fn handle(_: &Rest, state: &GameState, player: &PlayerCharacter) -> Result<Vec<Event>, Error> {
+    let room = state.current_room();
+    if room
+        .npc_positions
+        .iter()
+        .any(|npc_position| !npc_position.npc.character.is_dead())
+    {
+        return Err(Error::RestNotPossibleError);
+    }
+
+    // Sleep in the most comfortable thing in the room.
+    let bed = room
+        .fixture_positions
+        .iter()
+        .map(|fixture_position| &fixture_position.fixture)
+        .filter(|fixture| {
+            matches!(
+                fixture.fixture_type,
+                FixtureType::Bed | FixtureType::Cot | FixtureType::SleepingRoll
+            )
+        })
+        .max_by_key(|fixture| rest_quality(Some(&fixture.fixture_type)));
+    let (health_percent, spell_uses) = rest_quality(bed.map(|fixture| &fixture.fixture_type));
+
+    let mut events: Vec<Event> = Vec::new();
+    let mut current_state = state.clone();
+    let mut current_player = player.clone();
+    let mut turns = 0;
+    let mut interrupted = false;
+    let mut rng = rand::thread_rng();
+    let ambush_chance = 1 + (state.danger_level / DANGER_LEVELS_PER_AMBUSH_CHANCE) as i32;
+
+    // The dungeon keeps going while the player sleeps, poison and bleeding
+    // keep working on them, and anything that wanders in cuts the rest short.
+    while turns < REST_TURNS {
+        turns += 1;
+        let mut tick_events = super::global_effects::handle(&current_state, &current_player);
+        let (effects_state, effects_player) =
+            apply_events(&tick_events, &current_state, &current_player);
+        let mut world_events = super::world_tick::handle(&effects_state, &effects_player);
+        let (tick_state, tick_player) =
+            apply_events(&world_events, &effects_state, &effects_player);
+        tick_events.append(&mut world_events);
+        events.extend(tick_events);
+        current_state = tick_state;
+        current_player = tick_player;
+
+        if current_player.character.is_dead() {
+            interrupted = true;
+            break;
+        }
+
+        if current_state.current_room().first_alive_npc().is_some() {
+            interrupted = true;
+            break;
+        }
+
+        if roll_d100(&mut rng, 1, 0) <= ambush_chance {
+            events.push(Event::PlayerAmbushed(PlayerAmbushed {
+                room_id: state.current_room_id,
+                npc_position: wandering_npc_position(state.danger_level),
+            }));
+            interrupted = true;
+            break;
+        }
+    }
+
+    events.push(Event::PlayerRested(PlayerRested {
+        fixture_id: bed.map(|fixture| fixture.id),
+        turns,
+        interrupted,
+    }));
+
+    if current_player.character.is_dead() {
+        return Ok(events);
+    }
+
+    // A broken up rest only does as much good as the time it lasted, for
+    // health and spells alike.
+    let health = &current_player.character.stats.health;
+    let healing = (health.max * health_percent * turns as i32 / (100 * REST_TURNS as i32))
+        .min(health.max - health.current);
+    if healing > 0 {
+        events.push(Event::PlayerHealed(PlayerHealed {
+            damage_healed: healing,
+        }));
+    }
+
+    let spell_uses = spell_uses * turns as i32 / REST_TURNS as i32;
+    for learned_spell in current_player.character.spell_memory.spells.iter() {
+        let uses = spell_uses.min(learned_spell.max_uses() - learned_spell.spell.uses);
+        if uses > 0 {
+            events.push(Event::PlayerSpellUsesRestored(PlayerSpellUsesRestored {
+                spell_id: learned_spell.id,
+                uses,
+            }));
+        }
+    }
+
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        actions::{Action, Rest},
+        events::Event,
+        generators::{game::game_generator, generator::Generator, players::player_generator},
+        handlers::{handle_action, HandledAction},
+    };
+
+    use super::REST_TURNS;
+
+    #[test]
+    fn resting_lets_only_the_turns_slept_go_by() {
+        let mut state = game_generator().generate();
+        state.current_room_mut().npc_positions.clear();
+        let player = player_generator(None, None, None).generate();
+
+        let HandledAction {
+            events, new_state, ..
+        } = handle_action(&Action::Rest(Rest {}), &state, &player).unwrap();
+        let turns = events
+            .iter()
+            .find_map(|event| match event {
+                Event::PlayerRested(rested) => Some(rested.turns),
+                _ => None,
+            })
+            .unwrap();
+
+        assert!(turns <= REST_TURNS);
+        assert_eq!(new_state.turn, state.turn + turns);
+    }
+
+    #[test]
+    fn an_ambush_cuts_the_rest_short() {
+        let mut state = game_generator().generate();
+        for room in state.world.rooms.iter_mut() {
+            room.npc_positions.clear();
+        }
+        state.danger_level = 1000;
+        let player = player_generator(None, None, None).generate();
+
+        let events = super::handle(&Rest {}, &state, &player).unwrap();
+
+        assert!(events
+            .iter()
+            .any(|event| matches!(event, Event::PlayerAmbushed(_))));
+        assert!(events.iter().any(|event| matches!(
+            event,
+            Event::PlayerRested(rested) if rested.interrupted && rested.turns == 1
+        )));
+        assert!(!events
+            .iter()
+            .any(|event| matches!(event, Event::PlayerSpellUsesRestored(_))));
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    actions::SearchRoom,
+    components::{games::GameState, Perk, PlayerCharacter},
+    events::{Event, ExitHasTrapDiscovered},
+    utils::rolls::roll_d6,
+};
+
+use super::{helpers::discover_hidden_exits, progression::TRAP_DISCOVERED_EXPERIENCE};
+
+const DISCOVER_HIDDEN_EXIT_CHANCE: i32 = 5;
+const DISCOVER_EXIT_TRAP_CHANCE: i32 = 3;
+const THOROUGH_SEARCH_BONUS: i32 = 2;
+
+pub fn handle(search_room: &SearchRoom, state: &GameState, player: &PlayerCharacter) -> Vec<Event> {
+    let mut modifier = player.character.stats.attributes.wits_modifier()
+        + player.progression.perk_count(&Perk::Observant) as i32;
+
+    if search_room.thorough {
+        modifier += THOROUGH_SEARCH_BONUS;
+    }
+
+    let mut events = discover_hidden_exits(state, modifier, DISCOVER_HIDDEN_EXIT_CHANCE);
+    events.append(&mut discover_exit_traps(state, modifier));
+
+    events
+}
+
+/// Checks every exit the player already knows about for traps, a successful
+/// roll tells them whether one is there or not.
+fn discover_exit_traps(state: &GameState, modifier: i32) -> Vec<Event> {
+    let mut rng = rand::thread_rng();
+
+    state
+        .current_room()
+        .exits
+        .iter()
+        .filter(|exit| state.knows_exit(exit) && !state.exit_knowledge(&exit.id).knows_has_trap)
+        .filter(|_| roll_d6(&mut rng, 1, modifier) >= DISCOVER_EXIT_TRAP_CHANCE)
+        .flat_map(|exit| {
+            let mut events = vec![Event::ExitHasTrapDiscovered(ExitHasTrapDiscovered {
+                exit_id: exit.id,
+            })];
+            if state.world.exit_trap(&exit.id).is_some() {
+                events.push(Event::PlayerGainedExperience(TRAP_DISCOVERED_EXPERIENCE));
+            }
+            events
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        actions::SearchRoom,
+        components::{Dice, Trap, TrapType},
+        events::apply_events,
+        generators::{game::game_generator, generator::Generator, players::player_generator},
+    };
+
+    #[test]
+    fn searching_finds_traps_on_exits() {
+        let mut state = game_generator().generate();
+        let player = player_generator(None, None, None).generate();
+        let exit_id = state.current_room().exits[0].id;
+        for exit in state.world.exit_sides_mut(exit_id) {
+            exit.hidden = false;
+            exit.trap = Some(Trap {
+                trap_type: TrapType::FallingBlock,
+                damage: Dice::new(1, 1, 0),
+            });
+        }
+
+        for _ in 0..100 {
+            let events = super::handle(&SearchRoom { thorough: true }, &state, &player);
+            state = apply_events(&events, &state, &player).0;
+        }
+
+        assert!(state.exit_knowledge(&exit_id).knows_has_trap);
+        assert!(state
+            .view_current_room()
+            .exits
+            .iter()
+            .any(|exit| exit.id.eq(&exit_id.to_string()) && exit.has_trap));
+    }
+}
@@ -6,18 +6,25 @@ use crate::{
     utils::ids::parse_id,
 };
 
+use super::helpers::take_one_from_stack;
+
 pub fn handle(
     sell_player_item: &SellPlayerItem,
     player: &PlayerCharacter,
 ) -> Result<Vec<Event>, Error> {
     let item_id = parse_id(&sell_player_item.item_id)?;
-    let _ = match player.character.find_item(&item_id) {
+    let character_item = match player.character.find_item(&item_id) {
         Some(it) => it,
         None => return Err(Error::ItemNotFoundError(item_id.to_string())),
     };
 
-    Ok(vec![
-        Event::PlayerItemRemoved(PlayerItemRemoved { item_id }),
-        Event::PlayerGainedGold(1),
-    ])
+    if character_item.is_at_the_ready() && character_item.item.is_cursed() {
+        return Err(Error::ItemIsCursed(item_id.to_string()));
+    }
+
+    let (item_id, mut events) = take_one_from_stack(&character_item);
+    events.push(Event::PlayerItemRemoved(PlayerItemRemoved { item_id }));
+    events.push(Event::PlayerGainedGold(1));
+
+    Ok(events)
 }
@@ -1,11 +1,16 @@
 use crate::{
     actions::ThrowItemAtNpc,
-    components::{games::GameState, PlayerCharacter},
+    components::{games::GameState, DamageType, PlayerCharacter},
     errors::Error,
     events::{Event, PlayerItemRemoved},
     utils::ids::parse_id,
 };
 
+use super::{
+    damage::calculate_damage,
+    helpers::{damage_npc, take_one_from_stack},
+};
+
 pub fn handle(
     throw_item_at_npc: &ThrowItemAtNpc,
     state: &GameState,
@@ -19,12 +24,12 @@ pub fn handle(
 
     let room = state.current_room();
     let npc_id = parse_id(&throw_item_at_npc.npc_id)?;
-    match room.find_npc(&npc_id) {
+    let npc = match room.find_npc(&npc_id) {
         Some(it) => it,
         None => return Err(Error::NpcNotFoundError(npc_id.to_string())),
     };
 
-    let mut events: Vec<Event> = Vec::new();
+    let (item_id, mut events) = take_one_from_stack(&character_item);
     if let Some(throwable) = &character_item.item.throwable {
         if let Some(oil_effect) = &throwable.effect.oil_splash_effect {
             if oil_effect.covers_all_enemies {
@@ -35,6 +40,21 @@ pub fn handle(
                 events.push(Event::NpcCoveredInOil(npc_id));
             }
         }
+
+        if let Some(firebomb_effect) = &throwable.effect.firebomb_effect {
+            if !npc.character.is_dead() {
+                let mut rng = rand::thread_rng();
+                let calculation = calculate_damage(
+                    &npc.character,
+                    firebomb_effect.damage.roll(&mut rng),
+                    DamageType::Fire,
+                    &[],
+                    false,
+                );
+                let (mut damage_events, _) = damage_npc(state, player, npc, calculation);
+                events.append(&mut damage_events);
+            }
+        }
     } else {
         return Err(Error::ItemNotThrowableError(item_id.to_string()));
     }
@@ -0,0 +1,47 @@
+use crate::{
+    actions::{ExitRoom, TravelToRoom},
+    components::{games::GameState, PlayerCharacter},
+    errors::Error,
+    events::{apply_events, Event},
+    utils::ids::parse_id,
+};
+
+pub fn handle(
+    travel_to_room: &TravelToRoom,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
+    let room_id = parse_id(&travel_to_room.room_id)?;
+    let path = match state.path_to_room(&room_id) {
+        Some(it) if !it.is_empty() => it,
+        _ => return Err(Error::NoPathToRoomError(room_id.to_string())),
+    };
+
+    let mut events: Vec<Event> = Vec::new();
+    let mut current_state = state.clone();
+    let mut current_player = player.clone();
+
+    for exit_id in path.iter() {
+        let hop_events = super::exit_room::handle(
+            &ExitRoom {
+                exit_id: exit_id.to_string(),
+            },
+            &current_state,
+            &current_player,
+        )?;
+        let (hop_state, hop_player) = apply_events(&hop_events, &current_state, &current_player);
+        events.extend(hop_events);
+        current_state = hop_state;
+        current_player = hop_player;
+
+        let arrived = current_state.current_room_id.eq(&room_id);
+        if arrived
+            || current_player.character.is_dead()
+            || current_state.current_room().first_alive_npc().is_some()
+        {
+            break;
+        }
+    }
+
+    Ok(events)
+}
@@ -0,0 +1,113 @@
+use crate::{
+    actions::UnlockWithKey,
+    components::{games::GameState, items::ItemType, PlayerCharacter},
+    errors::Error,
+    events::Event,
+    utils::ids::parse_id,
+};
+
+use super::helpers::find_lock;
+
+pub fn handle(
+    unlock_with_key: &UnlockWithKey,
+    state: &GameState,
+    player: &PlayerCharacter,
+) -> Result<Vec<Event>, Error> {
+    let key_id = parse_id(&unlock_with_key.key_id)?;
+    let key = match player.character.find_item(&key_id) {
+        Some(it) => it,
+        None => return Err(Error::ItemNotFoundError(key_id.to_string())),
+    };
+
+    if key.item.item_type != ItemType::Key {
+        return Err(Error::ItemNotKeyError(key_id.to_string()));
+    }
+
+    let (lock, unlock_event) =
+        find_lock(state, &unlock_with_key.fixture_id, &unlock_with_key.exit_id)?;
+
+    if !key.item.is_key_for(&lock) {
+        return Err(Error::KeyDoesNotFitError(key_id.to_string()));
+    }
+
+    Ok(vec![unlock_event])
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        actions::UnlockWithKey,
+        components::{
+            items::{CharacterItem, ItemType, LocationTag},
+            Lock, LockDifficulty, PlayerCharacter,
+        },
+        errors::Error,
+        events::{apply_events, Event},
+        generators::{
+            game::game_generator, generator::Generator, items::item_generator_for_level,
+            players::player_generator,
+        },
+    };
+
+    use super::handle;
+
+    fn add_item(player: &mut PlayerCharacter, item_type: &ItemType, unlocks: Option<Uuid>) -> Uuid {
+        let mut item = item_generator_for_level(item_type, false, 1).generate();
+        item.unlocks = unlocks;
+        let item_id = item.id;
+        player.character.inventory.equipment.push(CharacterItem {
+            item,
+            is_hidden: false,
+            equipped_location: LocationTag::Packed,
+            is_multiple: false,
+            at_the_ready: false,
+            quantity: 1,
+        });
+        item_id
+    }
+
+    fn unlock(key_id: Uuid, exit_id: Uuid) -> UnlockWithKey {
+        UnlockWithKey {
+            key_id: key_id.to_string(),
+            fixture_id: None,
+            exit_id: Some(exit_id.to_string()),
+        }
+    }
+
+    #[test]
+    fn only_the_right_key_opens_a_locked_exit() {
+        let mut state = game_generator().generate();
+        let mut player = player_generator(None, None, None).generate();
+        let exit_id = state.current_room().exits[0].id;
+        let lock_id = Uuid::new_v4();
+        for exit in state.world.exit_sides_mut(exit_id) {
+            exit.hidden = false;
+            exit.lock = Some(Lock {
+                id: lock_id,
+                difficulty: LockDifficulty::Masterwork,
+                locked: true,
+                unpickable: true,
+            });
+        }
+
+        let dagger_id = add_item(&mut player, &ItemType::Dagger, Some(lock_id));
+        let wrong_key_id = add_item(&mut player, &ItemType::Key, Some(Uuid::new_v4()));
+        let key_id = add_item(&mut player, &ItemType::Key, Some(lock_id));
+
+        assert!(matches!(
+            handle(&unlock(dagger_id, exit_id), &state, &player),
+            Err(Error::ItemNotKeyError(_))
+        ));
+        assert!(matches!(
+            handle(&unlock(wrong_key_id, exit_id), &state, &player),
+            Err(Error::KeyDoesNotFitError(_))
+        ));
+
+        let events = handle(&unlock(key_id, exit_id), &state, &player).unwrap();
+        assert!(matches!(events.as_slice(), [Event::ExitUnlocked(_)]));
+        let (state, _) = apply_events(&events, &state, &player);
+        assert!(state.world.exit_lock(&exit_id).is_none());
+    }
+}
@@ -5,10 +5,15 @@ use crate::{
     actions::UseItemOnPlayer,
     components::{items::ConsumableEffectName, spells::Spell, PlayerCharacter},
     errors::Error,
-    events::{Event, PlayerHealed, PlayerItemRemoved, PlayerItemUsed, PlayerSpellLearned},
+    events::{
+        Event, ItemIdentified, PlayerHealed, PlayerItemRemoved, PlayerItemUsed, PlayerPoisoned,
+        PlayerSpellLearned,
+    },
     utils::ids::parse_id,
 };
 
+use super::helpers::take_one_from_stack;
+
 pub fn handle(
     use_item_on_player: &UseItemOnPlayer,
     player: &PlayerCharacter,
@@ -23,12 +28,22 @@ pub fn handle(
         return Err(Error::ItemNotDirectlyUsableError(item_id.to_string()));
     }
 
-    let consumable = match character_item.item.consumable {
-        Some(it) => it,
+    let consumable = match &character_item.item.consumable {
+        Some(it) => it.clone(),
         None => return Ok(Vec::new()),
     };
 
-    let mut events = match consumable.effect.name {
+    // Using a consumable is one way to find out what it does.
+    let mut events = vec![Event::ItemIdentified(ItemIdentified {
+        item_id: character_item.item.id,
+    })];
+    let (item_id, mut split_events) = take_one_from_stack(&character_item);
+    events.append(&mut split_events);
+
+    events.append(&mut match consumable.effect.name {
+        ConsumableEffectName::RepairKit => {
+            return Err(Error::ItemNotDirectlyUsableError(item_id.to_string()))
+        }
         ConsumableEffectName::LearnSpell => {
             if let Some(learn_spell_effect) = consumable.effect.learn_spell_effect {
                 let spell = Spell {
@@ -47,10 +62,28 @@ pub fn handle(
                 Vec::new()
             }
         }
+        ConsumableEffectName::Poison => {
+            if let Some(poison_effect) = consumable.effect.poison_effect {
+                vec![Event::PlayerPoisoned(PlayerPoisoned {
+                    damage: poison_effect.damage,
+                    duration: poison_effect.duration,
+                })]
+            } else {
+                Vec::new()
+            }
+        }
+        ConsumableEffectName::RemoveCurse => player
+            .character
+            .inventory
+            .equipment
+            .iter()
+            .filter(|character_item| character_item.item.is_cursed())
+            .map(|character_item| Event::PlayerItemCurseLifted(character_item.item.id))
+            .collect(),
         ConsumableEffectName::HealingGrog => {
             if let Some(heal_effect) = consumable.effect.healing_effect {
                 let mut rng = rand::thread_rng();
-                let healing = heal_effect.healing.attack_roll(&mut rng);
+                let healing = heal_effect.healing.roll(&mut rng);
                 let damage_healed = healing
                     .min(player.character.stats.health.max - player.character.stats.health.current);
                 vec![Event::PlayerHealed(PlayerHealed { damage_healed })]
@@ -58,7 +91,7 @@ pub fn handle(
                 Vec::new()
             }
         }
-    };
+    });
 
     events.push(Event::PlayerItemUsed(PlayerItemUsed { item_id }));
 
@@ -68,3 +101,80 @@ pub fn handle(
 
     Ok(events)
 }
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        actions::UseItemOnPlayer,
+        components::{
+            items::{
+                CharacterItem, Consumable, ConsumableEffect, ConsumableEffectName, Curse, Item,
+                ItemType, LocationTag,
+            },
+            PlayerCharacter,
+        },
+        events::apply_events,
+        generators::{
+            game::game_generator, generator::Generator, items::item_generator_for_level,
+            players::player_generator,
+        },
+    };
+
+    use super::handle;
+
+    fn add_item(player: &mut PlayerCharacter, item: Item, at_the_ready: bool) -> Uuid {
+        let item_id = item.id;
+        player.character.inventory.equipment.push(CharacterItem {
+            item,
+            is_hidden: false,
+            equipped_location: LocationTag::Packed,
+            is_multiple: false,
+            at_the_ready,
+            quantity: 1,
+        });
+        item_id
+    }
+
+    #[test]
+    fn remove_curse_scrolls_lift_every_curse_and_are_used_up() {
+        let state = game_generator().generate();
+        let mut player = player_generator(None, None, None).generate();
+        let mut sword = item_generator_for_level(&ItemType::LongSword, true, 1).generate();
+        sword.curse = Some(Curse::Beckoning);
+        sword.curse_revealed = true;
+        let sword_id = add_item(&mut player, sword, true);
+        let mut scroll = item_generator_for_level(&ItemType::Scroll, false, 1).generate();
+        scroll.consumable = Some(Consumable {
+            uses: 1,
+            effect: ConsumableEffect {
+                name: ConsumableEffectName::RemoveCurse,
+                learn_spell_effect: None,
+                healing_effect: None,
+                repair_effect: None,
+                poison_effect: None,
+            },
+        });
+        let scroll_id = add_item(&mut player, scroll, false);
+
+        let events = handle(
+            &UseItemOnPlayer {
+                item_id: scroll_id.to_string(),
+            },
+            &player,
+        )
+        .unwrap();
+        let (_, player) = apply_events(&events, &state, &player);
+
+        let sword = &player.character.find_item(&sword_id).unwrap().item;
+        assert!(!sword.is_cursed());
+        assert!(player
+            .character
+            .inventory
+            .equipment
+            .iter()
+            .all(|character_item| !character_item.item.is_cursed()));
+        assert!(player.character.find_item(&scroll_id).is_none());
+    }
+}
@@ -19,9 +19,23 @@ pub fn handle(look_at_fixture: &LookAtFixture, state: &GameState) -> Result<Vec<
 
     let args = FixtureViewArgs {
         knows_has_hidden_compartment: knowledge.knows_has_hidden_compartment,
+        knows_has_trap: knowledge.knows_has_trap,
     };
 
-    let view = fixture::view(&fixture_position.fixture, &args, state.player_knows_all);
+    let item_args = state.item_view_args(
+        fixture_position
+            .fixture
+            .items
+            .iter()
+            .map(|fixture_item| &fixture_item.item.id),
+    );
+
+    let view = fixture::view(
+        &fixture_position.fixture,
+        &args,
+        &item_args,
+        state.player_knows_all,
+    );
 
     Ok(vec![Event::FixtureViewed(FixtureViewed {
         fixture_view: view,
@@ -1,6 +1,6 @@
 use crate::{
     actions::LookAtNpc,
-    components::{games::GameState, CharacterViewArgs},
+    components::{games::GameState, CharacterViewArgs, NonPlayerViewArgs},
     errors::Error,
     events::{Event, NpcViewed},
     systems::view::non_player,
@@ -16,14 +16,26 @@ pub fn handle(look_at_npc: &LookAtNpc, state: &GameState) -> Result<Vec<Event>,
     };
 
     let knowledge = state.npc_knowledge(&npc_id);
-    let args = CharacterViewArgs {
+    let character_args = CharacterViewArgs {
         knows_health: knowledge.knows_health,
         knows_inventory: knowledge.knows_inventory,
         knows_hidden_in_inventory: knowledge.knows_hidden_in_inventory,
         knows_packed_in_inventory: knowledge.knows_packed_in_inventory,
     };
 
-    let view = non_player::view(npc, &args, state.player_knows_all);
+    let item_args = state.item_view_args(
+        npc.character
+            .inventory
+            .equipment
+            .iter()
+            .map(|character_item| &character_item.item.id),
+    );
+
+    let args = NonPlayerViewArgs {
+        character_args,
+        turn: state.turn,
+    };
+    let view = non_player::view(npc, &args, &item_args, state.player_knows_all);
 
     Ok(vec![Event::NpcViewed(NpcViewed { npc_view: view })])
 }
@@ -0,0 +1,200 @@
+use crate::{
+    components::{games::GameState, LifeModifier, NonPlayer, PlayerCharacter},
+    events::{Event, NpcHealed},
+};
+
+use super::global_effects::npc_effects;
+
+const NATURAL_HEALING: i32 = 1;
+const VAMPIRE_HEALING: i32 = 3;
+
+/// Time doesn't stop in the rooms the player isn't in. Poison and bleeding
+/// keep working on whoever is there, the wounded slowly get their strength
+/// back, the dead rot wherever they fell, and the restless move on.
+pub fn handle(state: &GameState, player: &PlayerCharacter) -> Vec<Event> {
+    // The global effects already take care of anyone in the player's room.
+    let mut events: Vec<Event> = state
+        .world
+        .rooms
+        .iter()
+        .filter(|room| room.id.ne(&state.current_room_id))
+        .flat_map(|room| room.npc_positions.iter())
+        .filter(|npc_position| !npc_position.npc.character.is_dead())
+        .flat_map(|npc_position| tick_npc(state, player, &npc_position.npc))
+        .collect();
+
+    events.append(&mut super::npc_movement::handle(state));
+    events.push(Event::WorldTurnPassed);
+    events
+}
+
+fn tick_npc(state: &GameState, player: &PlayerCharacter, npc: &NonPlayer) -> Vec<Event> {
+    let effects = &npc.character.current_effects;
+    let (mut events, npc_health) = npc_effects(state, player, npc);
+
+    if npc_health <= 0 {
+        return events;
+    }
+
+    if effects.stunned {
+        events.push(Event::NpcStunRecovered(npc.id));
+    }
+
+    // Nobody heals while something is still eating away at them.
+    if effects.poison.is_none() && effects.bleeding.is_none() {
+        let healing = regeneration(npc).min(npc.character.stats.health.max - npc_health);
+        if healing > 0 {
+            events.push(Event::NpcHealed(NpcHealed {
+                npc_id: npc.id,
+                amount: healing,
+            }));
+        }
+    }
+
+    events
+}
+
+/// Skeletons and zombies never knit back together, vampires do it quicker
+/// than anyone.
+fn regeneration(npc: &NonPlayer) -> i32 {
+    match &npc.character.life_modifier {
+        Some(LifeModifier::Vampire) => VAMPIRE_HEALING,
+        Some(LifeModifier::Skeleton | LifeModifier::Zombie) => 0,
+        None => NATURAL_HEALING,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        components::{
+            games::GameState,
+            non_player::{CORPSE_ROTTING_TURNS, CORPSE_SKELETAL_TURNS},
+            rooms::NpcPosition,
+            CorpseDecay, LifeModifier, NonPlayer, NpcMovement, Poison, Species,
+        },
+        events::{apply_events, Event},
+        generators::{
+            game::game_generator, generator::Generator, non_players::npc_generator,
+            players::player_generator,
+        },
+    };
+
+    use super::VAMPIRE_HEALING;
+
+    /// Puts the NPC in a room of its own, away from the player.
+    fn leave_behind(state: &mut GameState, mut npc: NonPlayer) -> Uuid {
+        npc.movement = NpcMovement::Stationary;
+        let npc_id = npc.id;
+        let mut other_room = state.current_room().clone();
+        other_room.id = Uuid::new_v4();
+        other_room.npc_positions = vec![NpcPosition {
+            group_descriptor: None,
+            npc,
+            position_descriptor: None,
+        }];
+        state.world.rooms.push(other_room);
+        npc_id
+    }
+
+    #[test]
+    fn poison_keeps_working_on_npcs_the_player_left_behind() {
+        let mut state = game_generator().generate();
+        let player = player_generator(None, None, None).generate();
+        let mut npc = npc_generator(None).generate();
+        npc.character.species = Species::Ogre;
+        npc.character.life_modifier = None;
+        npc.character.inventory.equipment.clear();
+        npc.character.stats.health.current = 5;
+        npc.character.current_effects.poison = Some(Poison {
+            damage: 2,
+            duration: 10,
+        });
+        let npc_id = leave_behind(&mut state, npc);
+
+        let mut events = super::handle(&state, &player);
+        state = apply_events(&events, &state, &player).0;
+        let npc = &state.world.find_npc_mut(&npc_id).unwrap().npc;
+        assert_eq!(npc.character.get_current_health(), 3);
+
+        for _ in 0..2 {
+            let mut tick_events = super::handle(&state, &player);
+            state = apply_events(&tick_events, &state, &player).0;
+            events.append(&mut tick_events);
+        }
+
+        let npc = &state.world.find_npc_mut(&npc_id).unwrap().npc;
+        assert!(npc.character.is_dead());
+        assert!(npc.died_on_turn.is_some());
+        assert!(events.iter().any(|event| matches!(
+            event,
+            Event::PlayerKilledNpc(killed) if killed.npc_id.eq(&npc_id)
+        )));
+    }
+
+    #[test]
+    fn npcs_left_behind_recover_at_their_own_pace() {
+        let mut state = game_generator().generate();
+        let player = player_generator(None, None, None).generate();
+
+        let mut vampire = npc_generator(None).generate();
+        vampire.character.life_modifier = Some(LifeModifier::Vampire);
+        vampire.character.stats.health.max = 20;
+        vampire.character.stats.health.current = 5;
+        vampire.character.current_effects.stunned = true;
+        let vampire_id = leave_behind(&mut state, vampire);
+
+        let mut skeleton = npc_generator(None).generate();
+        skeleton.character.life_modifier = Some(LifeModifier::Skeleton);
+        skeleton.character.stats.health.max = 20;
+        skeleton.character.stats.health.current = 5;
+        let skeleton_id = leave_behind(&mut state, skeleton);
+
+        let events = super::handle(&state, &player);
+        assert!(events.iter().any(|event| matches!(
+            event,
+            Event::NpcStunRecovered(npc_id) if npc_id.eq(&vampire_id)
+        )));
+        assert!(events.iter().any(|event| matches!(
+            event,
+            Event::NpcHealed(healed)
+                if healed.npc_id.eq(&vampire_id) && healed.amount == VAMPIRE_HEALING
+        )));
+        assert!(!events.iter().any(|event| matches!(
+            event,
+            Event::NpcHealed(healed) if healed.npc_id.eq(&skeleton_id)
+        )));
+    }
+
+    #[test]
+    fn bodies_rot_as_the_turns_go_by() {
+        let mut state = game_generator().generate();
+        let player = player_generator(None, None, None).generate();
+        let mut npc = npc_generator(None).generate();
+        npc.kill();
+        npc.died_on_turn = Some(state.turn);
+        let npc_id = leave_behind(&mut state, npc);
+
+        let decay = |state: &mut GameState| {
+            let turn = state.turn;
+            state
+                .world
+                .find_npc_mut(&npc_id)
+                .unwrap()
+                .npc
+                .corpse_decay(turn)
+        };
+        assert_eq!(decay(&mut state), Some(CorpseDecay::Fresh));
+
+        for turn in 1..=CORPSE_SKELETAL_TURNS {
+            let events = super::handle(&state, &player);
+            state = apply_events(&events, &state, &player).0;
+            if turn == CORPSE_ROTTING_TURNS {
+                assert_eq!(decay(&mut state), Some(CorpseDecay::Rotting));
+            }
+        }
+        assert_eq!(decay(&mut state), Some(CorpseDecay::Skeletal));
+    }
+}
@@ -1,22 +1,41 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
 use crate::components::{
+    items::ItemViewArgs,
     spells::{LearnedSpellView, SpellMemoryView, SpellView},
     stats::StatsView,
     EffectsView, {Character, CharacterView, CharacterViewArgs},
 };
 
-pub fn view(character: &Character, args: &CharacterViewArgs, knows_all: bool) -> CharacterView {
+pub fn view(
+    character: &Character,
+    args: &CharacterViewArgs,
+    item_args: &HashMap<Uuid, ItemViewArgs>,
+    knows_all: bool,
+) -> CharacterView {
     let (health, health_known) = if args.knows_health || knows_all {
         (Some(character.stats.health.clone()), true)
     } else {
         (None, false)
     };
 
+    // Sizing someone up well enough to judge their health also shows
+    // how strong, quick and sharp they are.
+    let (attributes, attributes_known) = if args.knows_health || knows_all {
+        (Some(character.stats.attributes.clone()), true)
+    } else {
+        (None, false)
+    };
+
     let (inventory, inventory_known) = if args.knows_inventory || knows_all {
         (
             Some(super::inventory::view(
                 &character.inventory,
                 args.knows_hidden_in_inventory,
                 args.knows_packed_in_inventory,
+                item_args,
                 knows_all,
             )),
             true,
@@ -38,6 +57,11 @@ pub fn view(character: &Character, args: &CharacterViewArgs, knows_all: bool) ->
                             name: learned_spell.spell.name.clone(),
                             attack: learned_spell.spell.attack.clone(),
                             knows_attack: true,
+                            damage: learned_spell
+                                .spell
+                                .attack
+                                .as_ref()
+                                .map(|attack| attack.dice.range()),
                             defense: learned_spell.spell.defense.clone(),
                             knows_defense: true,
                             uses: learned_spell.spell.uses,
@@ -60,6 +84,8 @@ pub fn view(character: &Character, args: &CharacterViewArgs, knows_all: bool) ->
             health,
             health_known,
             height: character.stats.height.clone(),
+            attributes,
+            attributes_known,
         },
         species: character.species.clone(),
         life_modifier: character.life_modifier.clone(),
@@ -76,6 +102,8 @@ pub fn view(character: &Character, args: &CharacterViewArgs, knows_all: bool) ->
             knows_has_resurrection_aura: true,
             poison: character.current_effects.poison.clone(),
             covered_in_oil: character.current_effects.covered_in_oil,
+            bleeding: character.current_effects.bleeding.clone(),
+            stunned: character.current_effects.stunned,
         },
     }
 }
@@ -1,8 +1,13 @@
-use crate::components::items::{CharacterItem, CharacterItemView};
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::components::items::{CharacterItem, CharacterItemView, ItemViewArgs};
 
 pub fn view(
     character_item: &CharacterItem,
     knows_hidden: bool,
+    item_args: &HashMap<Uuid, ItemViewArgs>,
     knows_all: bool,
 ) -> CharacterItemView {
     let full_item_hidden = character_item.equipped_location.hides_full_item();
@@ -20,12 +25,18 @@ pub fn view(
             (true, Some(character_item.equipped_location.clone()))
         };
 
+    let args = item_args
+        .get(&character_item.item.id)
+        .cloned()
+        .unwrap_or_default();
+
     CharacterItemView {
-        item: super::item::view(&character_item.item, !full_item_hidden, knows_all),
+        item: super::item::view(&character_item.item, !full_item_hidden, &args, knows_all),
         is_hidden,
         knows_equipped_location,
         is_multiple: character_item.is_multiple,
         at_the_ready: character_item.at_the_ready,
+        quantity: character_item.quantity,
         equipped_location,
     }
 }
@@ -1,6 +1,6 @@
 use crate::components::rooms::{Exit, ExitView};
 
-pub fn view(exit: &Exit, has_visited_connected_room: bool) -> ExitView {
+pub fn view(exit: &Exit, has_visited_connected_room: bool, knows_if_trap: bool) -> ExitView {
     ExitView {
         has_visited_connected_room,
         id: exit.id.to_string(),
@@ -9,5 +9,10 @@ pub fn view(exit: &Exit, has_visited_connected_room: bool) -> ExitView {
         material: exit.material.clone(),
         descriptors: exit.descriptors.to_vec(),
         size: exit.size.clone(),
+        is_locked: exit.is_locked(),
+        lock_difficulty: exit.lock.as_ref().map(|lock| lock.difficulty.clone()),
+        is_hidden: exit.hidden,
+        has_trap: knows_if_trap && exit.trap.is_some(),
+        knows_if_trap,
     }
 }
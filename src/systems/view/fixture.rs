@@ -1,16 +1,30 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
 use crate::components::{
     fixtures::{Fixture, FixtureView, FixtureViewArgs},
-    items::FixtureItemView,
+    items::{FixtureItemView, ItemViewArgs},
 };
 
-pub fn view(fixture: &Fixture, args: &FixtureViewArgs, knows_all: bool) -> FixtureView {
+pub fn view(
+    fixture: &Fixture,
+    args: &FixtureViewArgs,
+    item_args: &HashMap<Uuid, ItemViewArgs>,
+    knows_all: bool,
+) -> FixtureView {
     let items: Vec<FixtureItemView> = fixture
         .items
         .iter()
         .filter_map(|fixture_item| {
+            let item_args = item_args
+                .get(&fixture_item.item.id)
+                .cloned()
+                .unwrap_or_default();
+
             if fixture_item.is_inside && fixture.open {
                 Some(FixtureItemView {
-                    item: super::item::view(&fixture_item.item, true, knows_all),
+                    item: super::item::view(&fixture_item.item, true, &item_args, knows_all),
                     is_in_hidden_compartment: Some(false),
                     is_in_hidden_compartment_known: true,
                     is_inside: true,
@@ -19,7 +33,7 @@ pub fn view(fixture: &Fixture, args: &FixtureViewArgs, knows_all: bool) -> Fixtu
                 None
             } else if fixture_item.is_in_hidden_compartment && fixture.hidden_compartment_open {
                 Some(FixtureItemView {
-                    item: super::item::view(&fixture_item.item, true, knows_all),
+                    item: super::item::view(&fixture_item.item, true, &item_args, knows_all),
                     is_in_hidden_compartment: Some(true),
                     is_in_hidden_compartment_known: true,
                     is_inside: false,
@@ -28,7 +42,7 @@ pub fn view(fixture: &Fixture, args: &FixtureViewArgs, knows_all: bool) -> Fixtu
                 None
             } else {
                 Some(FixtureItemView {
-                    item: super::item::view(&fixture_item.item, true, knows_all),
+                    item: super::item::view(&fixture_item.item, true, &item_args, knows_all),
                     is_in_hidden_compartment: Some(false),
                     is_in_hidden_compartment_known: true,
                     is_inside: false,
@@ -46,6 +60,9 @@ pub fn view(fixture: &Fixture, args: &FixtureViewArgs, knows_all: bool) -> Fixtu
         (false, false)
     };
 
+    let knows_if_trap = args.knows_has_trap || knows_all;
+    let has_trap = knows_if_trap && fixture.trap.is_some();
+
     FixtureView {
         id: fixture.id.to_string(),
         name: fixture.name.clone(),
@@ -59,5 +76,9 @@ pub fn view(fixture: &Fixture, args: &FixtureViewArgs, knows_all: bool) -> Fixtu
         open: fixture.open,
         can_be_opened: fixture.can_be_opened,
         hidden_compartment_open,
+        is_locked: fixture.is_locked(),
+        lock_difficulty: fixture.lock.as_ref().map(|lock| lock.difficulty.clone()),
+        has_trap,
+        knows_if_trap,
     }
 }
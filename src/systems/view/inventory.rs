@@ -1,9 +1,14 @@
-use crate::components::{Inventory, InventoryView};
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::components::{items::ItemViewArgs, Inventory, InventoryView};
 
 pub fn view(
     inventory: &Inventory,
     knows_hidden: bool,
     knows_packed: bool,
+    item_args: &HashMap<Uuid, ItemViewArgs>,
     knows_all: bool,
 ) -> InventoryView {
     let equipped_items = inventory
@@ -15,6 +20,7 @@ pub fn view(
                 Some(super::character_item::view(
                     character_item,
                     knows_hidden,
+                    item_args,
                     true,
                 ))
             } else {
@@ -28,7 +34,12 @@ pub fn view(
         .filter(|character_item| character_item.is_packed())
         .filter_map(|character_item| {
             if knows_packed || knows_all {
-                Some(super::character_item::view(character_item, true, true))
+                Some(super::character_item::view(
+                    character_item,
+                    true,
+                    item_args,
+                    true,
+                ))
             } else {
                 None
             }
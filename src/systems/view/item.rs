@@ -1,8 +1,19 @@
 use crate::components::items::{
-    ConsumableView, ThrowableView, {Item, ItemView},
+    ConsumableView, ItemViewArgs, ThrowableView, {Item, ItemView},
 };
 
-pub fn view(item: &Item, sees_full_item: bool, knows_all: bool) -> ItemView {
+/// Attack and defense are plain to whoever holds the item, but what a
+/// consumable does, its enchantments, its curse and whether it is an artifact
+/// stay hidden until the item has been identified.
+pub fn view(item: &Item, sees_full_item: bool, args: &ItemViewArgs, knows_all: bool) -> ItemView {
+    let identified = args.identified;
+
+    let name = if identified {
+        item.name.clone()
+    } else {
+        item.unidentified_name().or_else(|| item.name.clone())
+    };
+
     let (descriptors, descriptors_known) = if sees_full_item || knows_all {
         (item.descriptors.clone(), true)
     } else {
@@ -21,13 +32,15 @@ pub fn view(item: &Item, sees_full_item: bool, knows_all: bool) -> ItemView {
         (None, false)
     };
 
+    let damage = attack.as_ref().map(|attack| attack.dice.range());
+
     let (defense, defense_known) = if knows_all {
         (item.defense.clone(), true)
     } else {
         (None, false)
     };
 
-    let (consumable, knows_consumable) = if knows_all {
+    let (consumable, knows_consumable) = if identified {
         if let Some(c) = &item.consumable {
             (
                 Some(ConsumableView {
@@ -45,6 +58,29 @@ pub fn view(item: &Item, sees_full_item: bool, knows_all: bool) -> ItemView {
         (None, false)
     };
 
+    let (enchantments, enchantments_known) = if identified {
+        (item.enchantments.clone(), true)
+    } else {
+        (Vec::new(), false)
+    };
+
+    let (curse, curse_known) = if identified || item.curse_revealed {
+        (item.curse.clone(), true)
+    } else {
+        (None, false)
+    };
+
+    let (artifact, lore) = if identified {
+        (
+            item.artifact.clone(),
+            item.artifact
+                .as_ref()
+                .map(|artifact| artifact.lore().to_string()),
+        )
+    } else {
+        (None, None)
+    };
+
     let throwable = item.throwable.as_ref().map(|throwable| ThrowableView {
         uses: throwable.uses,
         effect: throwable.effect.clone(),
@@ -52,20 +88,54 @@ pub fn view(item: &Item, sees_full_item: bool, knows_all: bool) -> ItemView {
 
     ItemView {
         id: item.id.to_string(),
-        name: item.name.clone(),
+        name,
         item_type: item.item_type.clone(),
         tags: item.tags.clone(),
         is_equippable: item.is_equippable(),
+        weight: item.weight(),
         descriptors,
         descriptors_known,
         material,
         material_known,
         attack,
         attack_known,
+        damage,
         defense,
         defense_known,
         consumable,
         knows_consumable,
         throwable,
+        durability: item.durability.clone(),
+        enchantments,
+        enchantments_known,
+        artifact,
+        lore,
+        curse,
+        curse_known,
+        identified,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        components::items::{Enchantment, ItemType, ItemViewArgs},
+        generators::{generator::Generator, items::item_generator_for_level},
+    };
+
+    use super::view;
+
+    #[test]
+    fn enchantments_only_show_once_identified() {
+        let mut item = item_generator_for_level(&ItemType::LongSword, false, 1).generate();
+        item.enchantments = vec![Enchantment::Flaming];
+
+        let unidentified = view(&item, true, &ItemViewArgs { identified: false }, true);
+        assert!(unidentified.enchantments.is_empty());
+        assert!(!unidentified.enchantments_known);
+
+        let identified = view(&item, true, &ItemViewArgs { identified: true }, true);
+        assert_eq!(identified.enchantments, vec![Enchantment::Flaming]);
+        assert!(identified.enchantments_known);
     }
 }
@@ -8,4 +8,7 @@ pub mod item;
 pub mod non_player;
 pub mod npc_position;
 pub mod player;
+pub mod progression;
+pub mod puzzle;
 pub mod room;
+pub mod world_map;
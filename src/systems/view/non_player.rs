@@ -1,19 +1,33 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
 use crate::components::{
-    CharacterViewArgs, {NonPlayer, NonPlayerView},
+    items::ItemViewArgs,
+    NonPlayerViewArgs, {NonPlayer, NonPlayerView},
 };
 
 pub fn view(
     non_player: &NonPlayer,
-    character_args: &CharacterViewArgs,
+    args: &NonPlayerViewArgs,
+    item_args: &HashMap<Uuid, ItemViewArgs>,
     knows_all: bool,
 ) -> NonPlayerView {
     let can_be_looted =
-        (character_args.knows_health || knows_all) && non_player.character.is_dead();
+        (args.character_args.knows_health || knows_all) && non_player.character.is_dead();
 
     NonPlayerView {
         id: non_player.id.to_string(),
         can_be_looted,
         name: non_player.name.clone(),
-        character: super::character::view(&non_player.character, character_args, knows_all),
+        boss: non_player.boss_kind(),
+        boss_phase: non_player.boss.as_ref().map(|boss| boss.phase.clone()),
+        corpse_decay: non_player.corpse_decay(args.turn),
+        character: super::character::view(
+            &non_player.character,
+            &args.character_args,
+            item_args,
+            knows_all,
+        ),
     }
 }
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use uuid::Uuid;
 
 use crate::components::{
+    items::ItemViewArgs,
     rooms::{NpcPosition, NpcPositionView},
     NonPlayerViewArgs,
 };
@@ -10,13 +11,14 @@ use crate::components::{
 pub fn view(
     npc_position: &NpcPosition,
     non_player_args: &HashMap<Uuid, NonPlayerViewArgs>,
+    item_args: &HashMap<Uuid, ItemViewArgs>,
     knows_all: bool,
 ) -> NpcPositionView {
     let args = non_player_args
         .get(&npc_position.npc.id)
         .cloned()
         .unwrap_or_default();
-    let npc = super::non_player::view(&npc_position.npc, &args.character_args, knows_all);
+    let npc = super::non_player::view(&npc_position.npc, &args, item_args, knows_all);
     NpcPositionView {
         group_descriptor: npc_position.group_descriptor.clone(),
         npc,
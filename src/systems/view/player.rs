@@ -1,20 +1,36 @@
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
 use crate::components::{
+    items::ItemViewArgs,
     CharacterViewArgs, {PlayerCharacter, PlayerCharacterView},
 };
 
-pub fn check(player_character: PlayerCharacter) -> PlayerCharacterView {
+/// Views the player, showing their items only as far as the player has
+/// identified them.
+pub fn check_with_item_args(
+    player_character: PlayerCharacter,
+    item_args: &HashMap<Uuid, ItemViewArgs>,
+) -> PlayerCharacterView {
     let args = CharacterViewArgs {
         knows_health: true,
         knows_inventory: true,
         knows_hidden_in_inventory: true,
         knows_packed_in_inventory: true,
     };
-    let character = super::character::view(&player_character.character, &args, true);
+    let character = super::character::view(&player_character.character, &args, item_args, true);
+    let progression = super::progression::view(&player_character.progression);
 
     PlayerCharacterView {
         character,
         id: player_character.id.to_string(),
         gold: player_character.gold,
         name: player_character.name,
+        progression,
+        carried_weight: player_character.character.carried_weight(),
+        carrying_capacity: player_character.character.carrying_capacity(),
+        max_carried_weight: player_character.character.max_carried_weight(),
+        is_encumbered: player_character.character.is_encumbered(),
     }
 }
@@ -0,0 +1,16 @@
+use crate::components::{Progression, ProgressionView};
+
+pub fn view(progression: &Progression) -> ProgressionView {
+    ProgressionView {
+        level: progression.level,
+        experience: progression.experience,
+        next_level_experience: progression.experience_for_next_level(),
+        perks: progression.perks.clone(),
+        perk_choices: progression
+            .pending_perk_choices
+            .first()
+            .cloned()
+            .unwrap_or_default(),
+        num_pending_perk_choices: progression.pending_perk_choices.len(),
+    }
+}
@@ -0,0 +1,26 @@
+use crate::components::rooms::{Puzzle, PuzzlePieceView, PuzzleView};
+
+pub fn view(puzzle: &Puzzle, knows_all: bool) -> PuzzleView {
+    let pieces: Vec<PuzzlePieceView> = puzzle
+        .pieces
+        .iter()
+        .map(|piece| PuzzlePieceView {
+            fixture_id: piece.fixture_id.to_string(),
+            interaction: piece.interaction.clone(),
+            position: piece.position,
+            num_positions: piece.interaction.num_positions(),
+            target: if knows_all || puzzle.solved {
+                Some(piece.target)
+            } else {
+                None
+            },
+        })
+        .collect();
+
+    PuzzleView {
+        id: puzzle.id.to_string(),
+        pieces,
+        pieces_in_place: puzzle.pieces_in_place(),
+        solved: puzzle.solved,
+    }
+}
@@ -4,6 +4,8 @@ use uuid::Uuid;
 
 use crate::components::{
     fixtures::FixtureViewArgs,
+    games::ExitKnowledge,
+    items::ItemViewArgs,
     rooms::{ExitView, FixturePositionView, NpcPositionView, Room, RoomView},
     NonPlayerViewArgs,
 };
@@ -12,33 +14,45 @@ pub fn view(
     room: &Room,
     non_player_args: HashMap<Uuid, NonPlayerViewArgs>,
     fixture_args: HashMap<Uuid, FixtureViewArgs>,
+    item_args: HashMap<Uuid, ItemViewArgs>,
     exit_visitations: HashMap<Uuid, bool>,
+    exit_knowledge: HashMap<Uuid, ExitKnowledge>,
     knows_all: bool,
 ) -> RoomView {
     let fixture_positions: Vec<FixturePositionView> = room
         .fixture_positions
         .iter()
         .map(|fixture_position| {
-            super::fixture_position::view(fixture_position, &fixture_args, knows_all)
+            super::fixture_position::view(fixture_position, &fixture_args, &item_args, knows_all)
         })
-        .into_iter()
         .collect();
     let npc_positions: Vec<NpcPositionView> = room
         .npc_positions
         .iter()
-        .map(|npc_position| super::npc_position::view(npc_position, &non_player_args, knows_all))
-        .into_iter()
+        .map(|npc_position| {
+            super::npc_position::view(npc_position, &non_player_args, &item_args, knows_all)
+        })
         .collect();
 
     let exits: Vec<ExitView> = room
         .exits
         .iter()
         .map(|exit| {
+            (
+                exit,
+                exit_knowledge.get(&exit.id).cloned().unwrap_or_default(),
+            )
+        })
+        .filter(|(exit, knowledge)| !exit.hidden || knows_all || knowledge.discovered)
+        .map(|(exit, knowledge)| {
             let has_visited_connected_room =
                 exit_visitations.get(&exit.id).cloned().unwrap_or_default();
-            super::exit::view(exit, has_visited_connected_room)
+            super::exit::view(
+                exit,
+                has_visited_connected_room,
+                knows_all || knowledge.knows_has_trap,
+            )
         })
-        .into_iter()
         .collect();
 
     RoomView {
@@ -51,5 +65,10 @@ pub fn view(
         npc_positions,
         flavour: room.flavour.clone(),
         exits,
+        depth: room.depth,
+        puzzle: room
+            .puzzle
+            .as_ref()
+            .map(|puzzle| super::puzzle::view(puzzle, knows_all)),
     }
 }
@@ -0,0 +1,66 @@
+use uuid::Uuid;
+
+use crate::components::worlds::{MapConnection, MapRoom, World, WorldMap};
+
+/// Only the rooms the player has seen and the exits they know about end up on
+/// the map, unless they know everything.
+pub fn view(
+    world: &World,
+    current_room_id: Uuid,
+    rooms_seen: &[Uuid],
+    known_exits: &[Uuid],
+    knows_all: bool,
+) -> WorldMap {
+    let connections: Vec<(Uuid, Uuid, Option<Uuid>)> = world
+        .exit_graph
+        .iter()
+        .filter(|exit_map| knows_all || known_exits.contains(&exit_map.exit_id))
+        .filter_map(|exit_map| {
+            let (from, to) = match (exit_map.left_room_id, exit_map.right_room_id) {
+                (Some(left), right) if knows_all || rooms_seen.contains(&left) => (left, right),
+                (left, Some(right)) if knows_all || rooms_seen.contains(&right) => (right, left),
+                _ => return None,
+            };
+            Some((exit_map.exit_id, from, to))
+        })
+        .collect();
+
+    let rooms: Vec<MapRoom> = world
+        .rooms
+        .iter()
+        .filter_map(|room| {
+            let visited = rooms_seen.contains(&room.id);
+            let seen = visited
+                || connections
+                    .iter()
+                    .any(|(_, _, to)| to.as_ref() == Some(&room.id));
+
+            if !seen && !knows_all {
+                return None;
+            }
+
+            Some(MapRoom {
+                id: room.id.to_string(),
+                name: room.name.clone(),
+                room_type: room.room_type.clone(),
+                depth: room.depth,
+                is_current: room.id.eq(&current_room_id),
+                visited,
+                seen,
+            })
+        })
+        .collect();
+
+    WorldMap {
+        current_room_id: current_room_id.to_string(),
+        rooms,
+        connections: connections
+            .into_iter()
+            .map(|(exit_id, from, to)| MapConnection {
+                exit_id: exit_id.to_string(),
+                from_room_id: from.to_string(),
+                to_room_id: to.map(|id| id.to_string()),
+            })
+            .collect(),
+    }
+}